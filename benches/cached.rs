@@ -0,0 +1,32 @@
+//! Displaying a huge ordinal directly re-renders every digit, while a `CachedOrdinal`
+//! only copies the stored text.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use num_bigint::BigUint;
+use ordinal_type::Ordinal;
+use std::fmt::Write;
+use std::hint::black_box;
+
+fn display(c: &mut Criterion) {
+    let ordinal = Ordinal(BigUint::from(7u8).pow(6000));
+    let cached = ordinal.clone().cached();
+    let mut out = String::with_capacity(cached.as_str().len());
+
+    let mut group = c.benchmark_group("display 5000 digits");
+    group.bench_function("Ordinal<BigUint>", |b| {
+        b.iter(|| {
+            out.clear();
+            write!(out, "{}", black_box(&ordinal)).unwrap();
+        })
+    });
+    group.bench_function("CachedOrdinal<BigUint>", |b| {
+        b.iter(|| {
+            out.clear();
+            write!(out, "{}", black_box(&cached)).unwrap();
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, display);
+criterion_main!(benches);
@@ -0,0 +1,67 @@
+//! `write_to` against formatting the digits with `core::fmt`. Run it with and without
+//! `--features fast-fmt` to compare itoa with the default path.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use ordinal_type::Ordinal;
+use std::fmt::Write;
+use std::hint::black_box;
+
+fn values(count: usize) -> Vec<u64> {
+    let mut seed = 0x2545_f491_4f6c_dd1du64;
+    (0..count)
+        .map(|_| {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            seed
+        })
+        .collect()
+}
+
+fn write(c: &mut Criterion) {
+    let values = values(10_000);
+    let mut out = String::with_capacity(64);
+
+    let mut group = c.benchmark_group("u32");
+    group.bench_function("core::fmt", |b| {
+        b.iter(|| {
+            for &value in &values {
+                out.clear();
+                let ordinal = Ordinal(black_box(value as u32));
+                write!(out, "{}{}", ordinal.0, ordinal.suffix()).unwrap();
+            }
+        })
+    });
+    group.bench_function("write_to", |b| {
+        b.iter(|| {
+            for &value in &values {
+                out.clear();
+                Ordinal(black_box(value as u32)).write_to(&mut out).unwrap();
+            }
+        })
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("i64");
+    group.bench_function("core::fmt", |b| {
+        b.iter(|| {
+            for &value in &values {
+                out.clear();
+                let ordinal = Ordinal(black_box(value as i64));
+                write!(out, "{}{}", ordinal.0, ordinal.suffix()).unwrap();
+            }
+        })
+    });
+    group.bench_function("write_to", |b| {
+        b.iter(|| {
+            for &value in &values {
+                out.clear();
+                Ordinal(black_box(value as i64)).write_to(&mut out).unwrap();
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, write);
+criterion_main!(benches);
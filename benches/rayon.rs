@@ -0,0 +1,50 @@
+//! `par_format_join` on pools of 1 to 8 threads against joining serially, to show how
+//! batch formatting scales. Needs `--features rayon`.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use ordinal_type::{par_format_join, Ordinal};
+use std::fmt::Write;
+use std::hint::black_box;
+
+fn values(count: usize) -> Vec<u64> {
+    let mut seed = 0x2545_f491_4f6c_dd1du64;
+    (0..count)
+        .map(|_| {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            seed
+        })
+        .collect()
+}
+
+fn join(c: &mut Criterion) {
+    let values = values(1_000_000);
+
+    let mut group = c.benchmark_group("join");
+    group.bench_function("serial", |b| {
+        b.iter(|| {
+            let mut out = String::new();
+            for (i, &value) in black_box(&values).iter().enumerate() {
+                if i > 0 {
+                    out.push('\n');
+                }
+                write!(out, "{}", Ordinal(value)).unwrap();
+            }
+            out
+        })
+    });
+    for threads in [1, 2, 4, 8] {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .unwrap();
+        group.bench_with_input(BenchmarkId::new("par", threads), &values, |b, values| {
+            b.iter(|| pool.install(|| par_format_join(black_box(values), "\n")))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, join);
+criterion_main!(benches);
@@ -0,0 +1,20 @@
+//! Parsing spelled-out English ordinals. Whatever parses must spell back to words that
+//! parse to the same value.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use ordinal_type::Ordinal;
+
+fuzz_target!(|text: &str| {
+    if let Ok(ordinal) = Ordinal::<u128>::from_words(text) {
+        let words = ordinal.to_words().expect("every u128 has a word form");
+        assert_eq!(Ok(ordinal), Ordinal::from_words(&words), "{:?}", text);
+        assert_eq!(
+            Ordinal::<u8>::from_words(text)
+                .ok()
+                .map(|o| u128::from(o.0)),
+            u8::try_from(ordinal.0).ok().map(u128::from)
+        );
+    }
+});
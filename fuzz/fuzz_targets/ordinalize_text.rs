@@ -0,0 +1,22 @@
+//! Rewriting numbers in text. Only two-letter suffixes may be added, and rewriting the
+//! output again must change nothing.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use ordinal_type::{ordinalize_text, strip_ordinals, OrdinalizeOptions};
+
+fuzz_target!(|data: (&str, &str, Option<u128>)| {
+    let (text, word, max) = data;
+    let mut options = OrdinalizeOptions::new().after_words([word]);
+    if let Some(max) = max {
+        options = options.max(max);
+    }
+    for options in [OrdinalizeOptions::new(), options] {
+        let out = ordinalize_text(text, &options);
+        assert_eq!(0, (out.len() - text.len()) % 2);
+        assert_eq!(out, ordinalize_text(&out, &options));
+        // Every added suffix matches its number, so stripping takes it off again.
+        assert_eq!(strip_ordinals(text), strip_ordinals(&out));
+    }
+});
@@ -0,0 +1,55 @@
+//! Strict parsing through `FromStr` and `Ordinal::from_str_radix`. Whatever parses must
+//! format back to the input, up to the normalization the parsers document.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use ordinal_type::Ordinal;
+
+/// Returns `text` as `Display` would write the ordinal it parses to: trimmed, without a
+/// `+` sign, leading zeros or the sign of zero, and with a lowercase suffix.
+fn normalize(text: &str) -> String {
+    let text = text.trim();
+    let (sign, rest) = match text.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", text.strip_prefix('+').unwrap_or(text)),
+    };
+    let digits = rest.trim_start_matches('0');
+    let (digits, sign) = match digits.starts_with(|c: char| c.is_ascii_digit()) {
+        true => (digits, sign),
+        false => (&rest[rest.len() - digits.len() - 1..], ""),
+    };
+    format!("{}{}", sign, digits.to_ascii_lowercase())
+}
+
+macro_rules! check_parse {
+    ($text:expr, $($ty:ty),*) => {$(
+        if let Ok(ordinal) = $text.parse::<Ordinal<$ty>>() {
+            let formatted = ordinal.to_string();
+            assert_eq!(normalize($text), formatted, "{:?}", $text);
+            assert_eq!(Ok(ordinal), formatted.parse());
+        }
+    )*};
+}
+
+macro_rules! check_radix {
+    ($text:expr, $radix:expr, $($ty:ty),*) => {$(
+        if let Ok(ordinal) = Ordinal::<$ty>::from_str_radix($text, $radix) {
+            for prefix in [false, true] {
+                let formatted = ordinal.to_string_radix($radix, prefix);
+                assert_eq!(Ok(ordinal), Ordinal::from_str_radix(&formatted, $radix));
+            }
+        }
+    )*};
+}
+
+fuzz_target!(|data: &[u8]| {
+    let Some((&radix, text)) = data.split_first() else {
+        return;
+    };
+    let Ok(text) = std::str::from_utf8(text) else {
+        return;
+    };
+    check_parse!(text, u8, i8, u64, i64, i128, u128);
+    check_radix!(text, u32::from(radix) % 35 + 2, u8, i64, i128, u128);
+});
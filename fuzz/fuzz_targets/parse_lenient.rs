@@ -0,0 +1,36 @@
+//! Finding and stripping suffixes in text, strictly and leniently. Every match with
+//! digits must cover a number and a suffix, and stripping must remove at least those.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use ordinal_type::{
+    find_ordinals, strip_ordinals, strip_ordinals_with, Ordinal, OrdinalForm, StripOptions,
+};
+
+fuzz_target!(|text: &str| {
+    let mut removed = 0;
+    for m in find_ordinals(text) {
+        assert_eq!(m.as_str(), &text[m.range()]);
+        match m.form() {
+            OrdinalForm::Words => {
+                assert_eq!(Ok(m.ordinal()), Ordinal::from_words(m.as_str()));
+                continue;
+            }
+            OrdinalForm::Superscript => {
+                assert!(m.ordinal().superscript().to_string().ends_with(m.suffix()));
+            }
+            _ => assert!(m.suffix().eq_ignore_ascii_case(m.ordinal().suffix())),
+        }
+        removed += m.suffix().len();
+        assert_eq!(format!("{}{}", m.digits(), m.suffix()), m.as_str());
+        assert_eq!(Ok(m.ordinal().0), m.digits().parse::<i128>());
+    }
+    // Stripping also takes suffixes off numbers too long for `find_ordinals`.
+    let strict = strip_ordinals(text);
+    assert!(strict.len() <= text.len() - removed);
+    assert_eq!(strict, strip_ordinals(&strict));
+
+    let lenient = strip_ordinals_with(text, &StripOptions::new().lenient(true));
+    assert!(lenient.len() <= strict.len());
+});
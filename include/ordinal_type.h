@@ -0,0 +1,43 @@
+#ifndef ORDINAL_TYPE_H
+#define ORDINAL_TYPE_H
+
+/* Generated by cbindgen from src/ffi.rs. Don't edit by hand. */
+
+#include <stddef.h>
+#include <stdint.h>
+
+// The buffer can't hold the ordinal and its terminating NUL.
+#define ORDINAL_ERROR_BUFFER_TOO_SMALL -1
+
+// A pointer argument was null.
+#define ORDINAL_ERROR_NULL_POINTER -2
+
+// The locale tag isn't UTF-8 or names no supported locale.
+#define ORDINAL_ERROR_UNSUPPORTED_LOCALE -3
+
+// The function panicked. This is a bug in the crate.
+#define ORDINAL_ERROR_PANIC -4
+
+// Returns the English suffix of `n`, like `"nd"` for 22, as a NUL-terminated string
+// with static storage. Never returns null and never needs freeing.
+const char *ordinal_suffix_i64(int64_t n);
+
+// Writes `n` as an English ordinal, like `"-22nd"`, and a terminating NUL to `buf`.
+// Returns the number of bytes written before the NUL, or a negative `ORDINAL_ERROR_*`
+// code. 23 bytes always suffice.
+//
+// # Safety
+//
+// `buf` must be null or valid for writes of `len` bytes.
+intptr_t ordinal_format_i64(int64_t n, char *buf, size_t len);
+
+// Like [`ordinal_format_i64`], but in the locale of `locale`, a NUL-terminated UTF-8
+// tag like `"fr"` or `"pt-BR"`, as `Locale::from_tag` reads it.
+//
+// # Safety
+//
+// `locale` must be null or point to a NUL-terminated string, and `buf` must be null or
+// valid for writes of `len` bytes.
+intptr_t ordinal_format_locale_i64(int64_t n, const char *locale, char *buf, size_t len);
+
+#endif  /* ORDINAL_TYPE_H */
@@ -0,0 +1,281 @@
+//! `ordinal_format!`, which rewrites a format string so placeholders ending in `ord`
+//! format their argument as an ordinal, and hands everything else to `format!`.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::{Error, Expr, Ident, LitStr, Token};
+
+/// The marker that ends the spec of an ordinal placeholder, as in `{place:>6ord}`.
+const MARKER: &str = "ord";
+
+pub(crate) struct FormatInput {
+    format: LitStr,
+    positional: Vec<Expr>,
+    named: Vec<(Ident, Expr)>,
+}
+
+impl Parse for FormatInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let format = input.parse()?;
+        let mut positional = Vec::new();
+        let mut named = Vec::new();
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+            if input.peek(Ident) && input.peek2(Token![=]) && !input.peek2(Token![==]) {
+                let name = input.parse()?;
+                input.parse::<Token![=]>()?;
+                named.push((name, input.parse()?));
+            } else if named.is_empty() {
+                positional.push(input.parse()?);
+            } else {
+                return Err(input.error("positional arguments must come before named ones"));
+            }
+        }
+        Ok(FormatInput {
+            format,
+            positional,
+            named,
+        })
+    }
+}
+
+/// An argument a placeholder refers to.
+enum Argument {
+    Positional(usize),
+    Named(usize),
+    /// A variable captured from the surrounding scope, as in `{place}`.
+    Captured(String),
+}
+
+struct Rewriter<'a> {
+    input: &'a FormatInput,
+    next_positional: usize,
+    /// Whether each positional and then each named argument is formatted normally.
+    plain: Vec<bool>,
+    /// Whether each positional and then each named argument is formatted as an ordinal.
+    ordinal: Vec<bool>,
+    /// The arguments of the ordinal placeholders, in order.
+    ordinals: Vec<Argument>,
+}
+
+impl Rewriter<'_> {
+    fn resolve(&self, name: &str) -> Result<Argument, String> {
+        if let Ok(index) = name.parse::<usize>() {
+            if index < self.input.positional.len() {
+                Ok(Argument::Positional(index))
+            } else {
+                Err(format!(
+                    "invalid reference to positional argument {}",
+                    index
+                ))
+            }
+        } else if let Some(index) = self.input.named.iter().position(|(n, _)| n == name) {
+            Ok(Argument::Named(index))
+        } else {
+            Ok(Argument::Captured(name.to_string()))
+        }
+    }
+
+    fn next(&mut self) -> Result<Argument, String> {
+        let index = self.next_positional;
+        self.next_positional += 1;
+        self.resolve(&index.to_string())
+    }
+
+    /// Returns the name an argument is passed to `format!` under, marking it used.
+    fn name(&mut self, argument: &Argument, ordinal: bool) -> String {
+        let (slot, name) = match argument {
+            Argument::Positional(index) => (*index, format!("__arg{}", index)),
+            Argument::Named(index) => (
+                self.input.positional.len() + index,
+                self.input.named[*index].0.to_string(),
+            ),
+            Argument::Captured(name) => return name.clone(),
+        };
+        if ordinal {
+            self.ordinal[slot] = true;
+        } else {
+            self.plain[slot] = true;
+        }
+        name
+    }
+
+    /// Rewrites the `N$`, `name$` and `.*` references to arguments in a spec.
+    fn spec(&mut self, spec: &str) -> Result<String, String> {
+        let mut out = String::new();
+        let mut rest = spec;
+        while let Some(c) = rest.chars().next() {
+            if let Some(after) = rest.strip_prefix(".*") {
+                let argument = self.next()?;
+                out.push('.');
+                out.push_str(&self.name(&argument, false));
+                out.push('$');
+                rest = after;
+                continue;
+            }
+            let len = rest
+                .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len());
+            if len == 0 {
+                out.push(c);
+                rest = &rest[c.len_utf8()..];
+                continue;
+            }
+            let (word, after) = rest.split_at(len);
+            match after.strip_prefix('$') {
+                Some(after) => {
+                    // `01$` is the `0` flag followed by a width from argument 1.
+                    let word = match word.strip_prefix('0') {
+                        Some(index)
+                            if !index.is_empty() && index.bytes().all(|b| b.is_ascii_digit()) =>
+                        {
+                            out.push('0');
+                            index
+                        }
+                        _ => word,
+                    };
+                    let argument = self.resolve(word)?;
+                    out.push_str(&self.name(&argument, false));
+                    out.push('$');
+                    rest = after;
+                }
+                None => {
+                    out.push_str(word);
+                    rest = after;
+                }
+            }
+        }
+        Ok(out)
+    }
+
+    fn placeholder(&mut self, body: &str) -> Result<String, String> {
+        let (argument, spec) = body.split_once(':').unwrap_or((body, ""));
+        let (spec, ordinal) = match spec.strip_suffix(MARKER) {
+            Some(spec) => (spec, true),
+            None => (spec, false),
+        };
+        // A `.*` precision takes its positional argument before the value does.
+        let spec = self.spec(spec)?;
+        let argument = if argument.is_empty() {
+            self.next()?
+        } else {
+            self.resolve(argument)?
+        };
+        let name = if ordinal {
+            let name = format!("__ordinal{}", self.ordinals.len());
+            self.name(&argument, true);
+            self.ordinals.push(argument);
+            name
+        } else {
+            self.name(&argument, false)
+        };
+        Ok(if spec.is_empty() {
+            format!("{{{}}}", name)
+        } else {
+            format!("{{{}:{}}}", name, spec)
+        })
+    }
+
+    fn format(&mut self, format: &str) -> Result<String, String> {
+        let mut out = String::new();
+        let mut chars = format.char_indices().peekable();
+        while let Some((start, c)) = chars.next() {
+            match c {
+                '{' if chars.peek().map(|&(_, c)| c) == Some('{') => {
+                    chars.next();
+                    out.push_str("{{");
+                }
+                '{' => {
+                    let end = format[start..]
+                        .find('}')
+                        .ok_or("unterminated placeholder, use `{{` for a literal `{`")?;
+                    let body = &format[start + 1..start + end];
+                    out.push_str(&self.placeholder(body)?);
+                    while chars.peek().is_some_and(|&(i, _)| i <= start + end) {
+                        chars.next();
+                    }
+                }
+                '}' if chars.peek().map(|&(_, c)| c) == Some('}') => {
+                    chars.next();
+                    out.push_str("}}");
+                }
+                '}' => return Err("unmatched `}`, use `}}` for a literal `}`".to_string()),
+                c => out.push(c),
+            }
+        }
+        Ok(out)
+    }
+}
+
+pub(crate) fn expand(input: FormatInput) -> syn::Result<TokenStream> {
+    let slots = input.positional.len() + input.named.len();
+    let mut rewriter = Rewriter {
+        input: &input,
+        next_positional: 0,
+        plain: vec![false; slots],
+        ordinal: vec![false; slots],
+        ordinals: Vec::new(),
+    };
+    let format = rewriter
+        .format(&input.format.value())
+        .map_err(|message| Error::new(input.format.span(), message))?;
+    let Rewriter {
+        plain,
+        ordinal,
+        ordinals,
+        ..
+    } = rewriter;
+
+    let arguments: Vec<_> = input
+        .positional
+        .iter()
+        .chain(input.named.iter().map(|(_, expr)| expr))
+        .collect();
+    let bindings: Vec<_> = (0..slots)
+        .map(|slot| format_ident!("__arg{}", slot))
+        .collect();
+    if let Some(unused) = (0..slots).find(|&slot| !plain[slot] && !ordinal[slot]) {
+        return Err(Error::new_spanned(arguments[unused], "argument never used"));
+    }
+
+    let mut passed = Vec::new();
+    for (slot, binding) in bindings.iter().enumerate() {
+        if !plain[slot] {
+            continue;
+        }
+        let name = match slot.checked_sub(input.positional.len()) {
+            Some(index) => input.named[index].0.clone(),
+            None => binding.clone(),
+        };
+        passed.push(quote!(#name = *#binding));
+    }
+    for (index, argument) in ordinals.iter().enumerate() {
+        let name = format_ident!("__ordinal{}", index);
+        let value = match argument {
+            Argument::Positional(slot) => {
+                let binding = &bindings[*slot];
+                quote!(#binding)
+            }
+            Argument::Named(index) => {
+                let binding = &bindings[input.positional.len() + index];
+                quote!(#binding)
+            }
+            Argument::Captured(captured) => {
+                let captured = Ident::new(captured, input.format.span());
+                quote!(&#captured)
+            }
+        };
+        passed.push(quote!(#name = ::ordinal_type::__private::ordinal_string(#value)));
+    }
+
+    let format = LitStr::new(&format, input.format.span());
+    Ok(quote! {
+        match (#(&(#arguments),)*) {
+            (#(#bindings,)*) => ::ordinal_type::__private::format!(#format #(, #passed)*),
+        }
+    })
+}
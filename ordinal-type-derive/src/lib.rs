@@ -0,0 +1,109 @@
+//! Macros for [`ordinal-type`](https://docs.rs/ordinal-type). Use them through the
+//! `derive` feature of `ordinal-type`, which re-exports them.
+
+mod format;
+
+use proc_macro::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::spanned::Spanned;
+use syn::{parse_macro_input, Data, DeriveInput, Error};
+
+/// Derives `variant_ordinal` and `variant_ordinal_string` for an enum, giving each
+/// variant its 1-based position in declaration order.
+///
+/// Once a variant has an explicit discriminant, that discriminant is its ordinal, and
+/// the variants after it count up from there, like the discriminants themselves do.
+/// Discriminants must then be non-negative, which is checked at compile time. Variants
+/// may have fields, which are ignored.
+///
+/// ```rust
+/// use ordinal_type::{Ordinal, VariantOrdinal};
+///
+/// #[derive(VariantOrdinal)]
+/// enum Round {
+///     Quarterfinal,
+///     Semifinal,
+///     Final { best_of: u8 },
+/// }
+///
+/// assert_eq!(Round::Semifinal.variant_ordinal(), Ordinal(2));
+/// assert_eq!(Round::Final { best_of: 5 }.variant_ordinal_string(), "3rd");
+/// ```
+#[proc_macro_derive(VariantOrdinal)]
+pub fn derive_variant_ordinal(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let Data::Enum(data) = &input.data else {
+        return Error::new_spanned(
+            &input.ident,
+            "`VariantOrdinal` can only be derived for enums",
+        )
+        .to_compile_error()
+        .into();
+    };
+
+    let mut base = quote!(0usize);
+    let mut offset = 1usize;
+    let mut checks = Vec::new();
+    let arms = data.variants.iter().map(|variant| {
+        if let Some((_, discriminant)) = &variant.discriminant {
+            // A negative discriminant would wrap around in the cast below.
+            checks.push(quote_spanned! {discriminant.span()=>
+                #[allow(unused_comparisons, clippy::assertions_on_constants)]
+                const _: () = ::core::assert!(
+                    (#discriminant) >= 0,
+                    "`VariantOrdinal` discriminants must be non-negative",
+                );
+            });
+            // Through `i128` so an unsuffixed literal isn't inferred as `usize`.
+            base = quote!((#discriminant) as i128 as usize);
+            offset = 0;
+        }
+        let ident = &variant.ident;
+        let arm = quote!(Self::#ident { .. } => #base + #offset,);
+        offset += 1;
+        arm
+    });
+    let arms: Vec<_> = arms.collect();
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    quote! {
+        #(#checks)*
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Returns the position of this variant, 1-based in declaration order or
+            /// following explicit discriminants.
+            pub fn variant_ordinal(&self) -> ::ordinal_type::Ordinal<usize> {
+                ::ordinal_type::Ordinal(match *self {
+                    #(#arms)*
+                })
+            }
+
+            /// Returns the position of this variant as an ordinal string, like `2nd`.
+            pub fn variant_ordinal_string(&self) -> ::ordinal_type::__private::String {
+                ::ordinal_type::__private::ToString::to_string(&self.variant_ordinal())
+            }
+        }
+    }
+    .into()
+}
+
+/// Like `format!`, but a placeholder whose spec ends in `ord` formats its argument, an
+/// integer, as an ordinal. Fill, alignment and width before the `ord` apply to the whole
+/// ordinal, suffix included. Other placeholders are left to `format!`.
+///
+/// ```rust
+/// use ordinal_type::ordinal_format;
+///
+/// let place = 2;
+/// let text = ordinal_format!("You came {place:ord}, beating {} players", 40);
+/// assert_eq!(text, "You came 2nd, beating 40 players");
+/// assert_eq!(ordinal_format!("[{:>6ord}] [{0}]", 23), "[  23rd] [23]");
+/// ```
+#[proc_macro]
+pub fn ordinal_format(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as format::FormatInput);
+    format::expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
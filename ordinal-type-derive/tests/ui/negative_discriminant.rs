@@ -0,0 +1,19 @@
+use ordinal_type::VariantOrdinal;
+
+#[derive(VariantOrdinal)]
+enum Offset {
+    Before = -1,
+    At,
+    After,
+}
+
+const LOWEST: i8 = -128;
+
+#[derive(VariantOrdinal)]
+#[repr(i8)]
+enum Level {
+    Bottom = LOWEST,
+    Top = 127,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use ordinal_type::VariantOrdinal;
+
+#[derive(VariantOrdinal)]
+struct Round {
+    number: u8,
+}
+
+#[derive(VariantOrdinal)]
+union Bits {
+    byte: u8,
+}
+
+fn main() {}
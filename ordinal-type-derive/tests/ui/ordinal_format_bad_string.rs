@@ -0,0 +1,7 @@
+use ordinal_type::ordinal_format;
+
+fn main() {
+    let _ = ordinal_format!("{:ord", 1);
+    let _ = ordinal_format!("{:ord} {}", 1);
+    let _ = ordinal_format!("{:ord}", 1, 2);
+}
@@ -0,0 +1,6 @@
+use ordinal_type::ordinal_format;
+
+fn main() {
+    let _ = ordinal_format!("{:ord}", "first");
+    let _ = ordinal_format!("{place:ord}", place = 1.5);
+}
@@ -0,0 +1,58 @@
+//! `arbitrary::Arbitrary` for `Ordinal<T>`, delegating to `T`. The locale and
+//! formatting option types derive it, so whole formatter configurations can be fuzzed.
+
+use crate::Ordinal;
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+impl<'a, T: Arbitrary<'a>> Arbitrary<'a> for Ordinal<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        T::arbitrary(u).map(Ordinal)
+    }
+
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+        T::arbitrary_take_rest(u).map(Ordinal)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        T::size_hint(depth)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Locale, Ordinal, OrdinalFormatter, OrdinalOptions};
+    use arbitrary::{Arbitrary, Unstructured};
+    use rand_chacha::rand_core::{RngCore, SeedableRng};
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_arbitrary_delegates() {
+        let bytes = [0x15, 0x00, 0x00, 0x00];
+        let ordinal = Ordinal::<u32>::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+        let primitive = u32::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+        assert_eq!(Ordinal(primitive), ordinal);
+        assert_eq!(u32::size_hint(0), Ordinal::<u32>::size_hint(0));
+        assert_eq!(i128::size_hint(0), Ordinal::<i128>::size_hint(0));
+    }
+
+    #[test]
+    fn test_arbitrary_formatting_does_not_panic() {
+        let mut rng = ChaCha8Rng::seed_from_u64(7);
+        let mut bytes = vec![0u8; 4096];
+        for _ in 0..200 {
+            rng.fill_bytes(&mut bytes);
+            let mut u = Unstructured::new(&bytes);
+            // Values are built from whatever bytes remain, so stop short of the end
+            // where too few are left for them to consume any.
+            while u.len() >= 64 {
+                let formatter = OrdinalFormatter::arbitrary(&mut u).unwrap();
+                let ordinal = Ordinal::<i64>::arbitrary(&mut u).unwrap();
+                let locale = Locale::arbitrary(&mut u).unwrap();
+                let options = OrdinalOptions::arbitrary(&mut u).unwrap();
+                formatter.format(&ordinal);
+                let _ = ordinal.to_words_locale(locale, &options);
+                ordinal.plural_category(locale);
+            }
+        }
+    }
+}
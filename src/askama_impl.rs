@@ -0,0 +1,185 @@
+//! Askama template filters for ordinals.
+//!
+//! Askama looks filters up in a `filters` module in scope of the template, so re-export
+//! these from yours:
+//!
+//! ```rust
+//! use askama::Template;
+//!
+//! mod filters {
+//!     pub use ordinal_type::askama::filters::*;
+//! }
+//!
+//! #[derive(Template)]
+//! #[template(source = "{{ rank|ordinal }} of {{ total }}", ext = "txt")]
+//! struct Standing {
+//!     rank: u32,
+//!     total: u32,
+//! }
+//!
+//! let standing = Standing { rank: 2, total: 10 };
+//! assert_eq!(standing.render().unwrap(), "2nd of 10");
+//! ```
+
+/// The filters, to be re-exported with `pub use ordinal_type::askama::filters::*;`.
+pub mod filters {
+    use crate::{Ordinal, OrdinalInteger};
+    use ::askama::filters::Safe;
+    use ::askama::Values;
+
+    /// Integers a filter accepts, however many references Askama passes them behind.
+    pub trait OrdinalValue {
+        /// The integer type behind the references.
+        type Integer: OrdinalInteger;
+
+        /// Returns the value wrapped in an `Ordinal`.
+        fn to_ordinal(&self) -> Ordinal<Self::Integer>;
+    }
+
+    impl<T: OrdinalValue + ?Sized> OrdinalValue for &T {
+        type Integer = T::Integer;
+
+        fn to_ordinal(&self) -> Ordinal<Self::Integer> {
+            (**self).to_ordinal()
+        }
+    }
+
+    impl<T> OrdinalValue for Ordinal<T>
+    where
+        T: OrdinalInteger,
+    {
+        type Integer = T;
+
+        fn to_ordinal(&self) -> Ordinal<T> {
+            self.clone()
+        }
+    }
+
+    macro_rules! impl_ordinal_value {
+        ($($t:ty),*) => {
+            $(
+                impl OrdinalValue for $t {
+                    type Integer = $t;
+
+                    fn to_ordinal(&self) -> Ordinal<$t> {
+                        Ordinal(*self)
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_ordinal_value!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+    /// Formats the value as an English ordinal: `{{ 2|ordinal }}` renders `2nd`.
+    #[::askama::filter_fn]
+    pub fn ordinal<T: OrdinalValue>(value: T, _: &dyn Values) -> ::askama::Result<String> {
+        Ok(value.to_ordinal().to_string())
+    }
+
+    /// Spells the value out in English: `{{ 21|ordinal_words }}` renders `twenty-first`.
+    /// Fails for negative values and values that don't fit in a `u128`.
+    #[::askama::filter_fn]
+    pub fn ordinal_words<T: OrdinalValue>(value: T, _: &dyn Values) -> ::askama::Result<String> {
+        value
+            .to_ordinal()
+            .to_words()
+            .map_err(|err| ::askama::Error::Custom(Box::new(err)))
+    }
+
+    /// Formats the value with its suffix in a `<sup>` element:
+    /// `{{ 2|ordinal_superscript }}` renders `2<sup>nd</sup>`. The output is marked safe,
+    /// so it isn't escaped in HTML templates.
+    #[::askama::filter_fn]
+    pub fn ordinal_superscript<T: OrdinalValue>(
+        value: T,
+        _: &dyn Values,
+    ) -> ::askama::Result<Safe<String>> {
+        Ok(Safe(value.to_ordinal().html().to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Ordinal;
+    use askama::Template;
+
+    mod filters {
+        pub use crate::askama::filters::*;
+    }
+
+    #[derive(Template)]
+    #[template(source = "{{ rank|ordinal }}", ext = "txt")]
+    struct Rank {
+        rank: u32,
+    }
+
+    #[derive(Template)]
+    #[template(
+        source = "{{ rank|ordinal_words }}, {{ place|ordinal }}, {{ (rank + 1)|ordinal }}",
+        ext = "txt"
+    )]
+    struct Mixed<'a> {
+        rank: i64,
+        place: &'a Ordinal<u8>,
+    }
+
+    #[derive(Template)]
+    #[template(
+        source = "<p>{{ rank|ordinal_superscript }} {{ note }}</p>",
+        ext = "html"
+    )]
+    struct Superscript {
+        rank: u16,
+        note: &'static str,
+    }
+
+    #[derive(Template)]
+    #[template(
+        source = "{% for rank in ranks %}{{ rank|ordinal }} {% endfor %}",
+        ext = "txt"
+    )]
+    struct Loop {
+        ranks: Vec<u64>,
+    }
+
+    #[test]
+    fn test_askama_ordinal() {
+        assert_eq!(Rank { rank: 1 }.render().unwrap(), "1st");
+        assert_eq!(Rank { rank: 112 }.render().unwrap(), "112th");
+    }
+
+    #[test]
+    fn test_askama_references_and_expressions() {
+        let mixed = Mixed {
+            rank: 21,
+            place: &Ordinal(3),
+        };
+        assert_eq!(mixed.render().unwrap(), "twenty-first, 3rd, 22nd");
+        let loop_ = Loop {
+            ranks: vec![1, 2, 3, 4],
+        };
+        assert_eq!(loop_.render().unwrap(), "1st 2nd 3rd 4th ");
+    }
+
+    #[test]
+    fn test_askama_superscript_is_safe() {
+        let superscript = Superscript {
+            rank: 22,
+            note: "<b>",
+        };
+        assert_eq!(
+            superscript.render().unwrap(),
+            "<p>22<sup>nd</sup> &#60;b&#62;</p>"
+        );
+    }
+
+    #[test]
+    fn test_askama_words_error() {
+        let mixed = Mixed {
+            rank: -1,
+            place: &Ordinal(3),
+        };
+        assert!(mixed.render().is_err());
+    }
+}
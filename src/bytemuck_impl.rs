@@ -0,0 +1,58 @@
+//! bytemuck marker traits for `Ordinal`, so slices and buffers of ordinals can be cast
+//! like their integers.
+//!
+//! ```rust
+//! use ordinal_type::Ordinal;
+//!
+//! let bytes = [1u8, 0, 0, 0, 2, 0, 0, 0];
+//! let ordinals: &[Ordinal<u32>] = bytemuck::cast_slice(&bytes);
+//! assert_eq!(ordinals.len(), 2);
+//! ```
+
+use crate::Ordinal;
+use bytemuck::{Pod, TransparentWrapper, Zeroable};
+
+// SAFETY: `Ordinal<T>` is `repr(transparent)` over `T`, so it's valid exactly when `T` is.
+unsafe impl<T: Zeroable> Zeroable for Ordinal<T> {}
+
+// SAFETY: as above, and `Ordinal<T>` is `Copy` whenever `T` is.
+unsafe impl<T: Pod> Pod for Ordinal<T> {}
+
+// SAFETY: `Ordinal<T>` is `repr(transparent)` over its only field, `T`.
+unsafe impl<T> TransparentWrapper<T> for Ordinal<T> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::Ordinal;
+    use bytemuck::TransparentWrapper;
+
+    #[test]
+    fn test_cast_slices() {
+        let ranks: Vec<u32> = (1..=100).collect();
+        let ordinals: &[Ordinal<u32>] = bytemuck::cast_slice(&ranks);
+        assert_eq!("1st", ordinals[0].to_string());
+        assert_eq!("100th", ordinals[99].to_string());
+        let back: &[u32] = bytemuck::cast_slice(ordinals);
+        assert_eq!(&ranks[..], back);
+        assert_eq!(Ordinal::wrap_slice(&ranks), ordinals);
+
+        let bytes: &[u8] = bytemuck::cast_slice(ordinals);
+        assert_eq!(400, bytes.len());
+
+        let zeroed: Ordinal<i64> = bytemuck::Zeroable::zeroed();
+        assert_eq!(Ordinal(0), zeroed);
+    }
+
+    #[test]
+    fn test_transparent_wrapper() {
+        let ranks = [4u16, 5, 6];
+        let ordinals: &[Ordinal<u16>] = Ordinal::wrap_slice(&ranks);
+        let wrapped: &[Ordinal<u16>] = TransparentWrapper::wrap_slice(&ranks[..]);
+        assert_eq!(ordinals, wrapped);
+        assert_eq!(
+            &ranks[..],
+            <Ordinal<u16> as TransparentWrapper<u16>>::peel_slice(wrapped)
+        );
+        assert_eq!(&Ordinal(7), Ordinal::wrap_ref(&7u16));
+    }
+}
@@ -0,0 +1,117 @@
+use crate::{Ordinal, OrdinalInteger};
+use alloc::boxed::Box;
+use alloc::string::ToString;
+use core::fmt::{self, Display, Formatter};
+
+/// An `Ordinal` rendered once up front, for values that are displayed over and over.
+///
+/// Formatting a `BigUint` with thousands of digits is costly, so this stores the text
+/// and makes `Display` and [`CachedOrdinal::as_str`] a plain copy of it. The value can
+/// be read but not changed, so the text can't go stale.
+/// ```rust
+/// use ordinal_type::Ordinal;
+///
+/// let cached = Ordinal(1_000_002u64).cached();
+/// assert_eq!(cached.as_str(), "1000002nd");
+/// assert_eq!(cached.suffix(), "nd");
+/// assert_eq!(cached.ordinal(), &Ordinal(1_000_002));
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct CachedOrdinal<T> {
+    ordinal: Ordinal<T>,
+    text: Box<str>,
+    digits: usize,
+}
+
+impl<T: OrdinalInteger> CachedOrdinal<T> {
+    /// Renders the ordinal and stores the text. This allocates once.
+    pub fn new(ordinal: Ordinal<T>) -> Self {
+        let digits = ordinal.0.to_string();
+        let len = digits.len();
+        let mut text = digits;
+        text.push_str(ordinal.suffix());
+        CachedOrdinal {
+            ordinal,
+            text: text.into_boxed_str(),
+            digits: len,
+        }
+    }
+}
+
+impl<T> CachedOrdinal<T> {
+    /// Returns the rendered ordinal, like `1000002nd`.
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the number without the suffix, like `1000002`.
+    pub fn digits(&self) -> &str {
+        &self.text[..self.digits]
+    }
+
+    /// Returns the suffix, like `nd`.
+    pub fn suffix(&self) -> &str {
+        &self.text[self.digits..]
+    }
+
+    /// Returns the ordinal the text was rendered from.
+    pub fn ordinal(&self) -> &Ordinal<T> {
+        &self.ordinal
+    }
+
+    /// Returns the ordinal, dropping the text.
+    pub fn into_inner(self) -> Ordinal<T> {
+        self.ordinal
+    }
+}
+
+impl<T: OrdinalInteger> From<Ordinal<T>> for CachedOrdinal<T> {
+    fn from(ordinal: Ordinal<T>) -> Self {
+        CachedOrdinal::new(ordinal)
+    }
+}
+
+impl<T> AsRef<str> for CachedOrdinal<T> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<T> Display for CachedOrdinal<T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CachedOrdinal, Ordinal};
+
+    #[test]
+    fn test_cached_matches_display() {
+        for i in -1000i64..=1000 {
+            let ordinal = Ordinal(i);
+            let cached = ordinal.cached();
+            assert_eq!(ordinal.to_string(), cached.as_str());
+            assert_eq!(ordinal.to_string(), cached.to_string());
+            assert_eq!(ordinal.suffix(), cached.suffix());
+            assert_eq!(i.to_string(), cached.digits());
+            assert_eq!(ordinal, cached.into_inner());
+        }
+        let max = CachedOrdinal::from(Ordinal(u128::MAX));
+        assert_eq!(Ordinal(u128::MAX).to_string(), max.as_ref());
+    }
+
+    #[cfg(feature = "num")]
+    #[test]
+    fn test_cached_big() {
+        use num_bigint::BigUint;
+
+        let big = Ordinal(BigUint::from(7u8).pow(6000) + 5u8);
+        let cached = big.clone().cached();
+        assert_eq!(big.to_string(), cached.as_str());
+        assert_eq!(big.suffix(), cached.suffix());
+        assert_eq!(&big, cached.ordinal());
+        assert_eq!(format!("[{}]", big), format!("[{}]", cached));
+    }
+}
@@ -0,0 +1,58 @@
+//! Formatting into a `CompactString`, which keeps up to 24 bytes inline on 64-bit
+//! targets. That covers every `u64` and `i64` ordinal, so they format without allocating.
+//! With the `rayon` feature too, `par_format_all_compact` is the batch version.
+
+use crate::{Ordinal, OrdinalInteger};
+use compact_str::CompactString;
+
+impl<T: OrdinalInteger> Ordinal<T> {
+    /// Formats the ordinal number into a `CompactString`, like `to_string` does into a
+    /// `String`. This only allocates if the text is longer than the inline capacity.
+    /// ```rust
+    /// use ordinal_type::Ordinal;
+    ///
+    /// let text = Ordinal(i64::MIN).to_compact_string();
+    /// assert_eq!(text, "-9223372036854775808th");
+    /// assert!(!text.is_heap_allocated());
+    /// ```
+    pub fn to_compact_string(&self) -> CompactString {
+        let mut text = CompactString::default();
+        self.write_to(&mut text)
+            .expect("writing to a CompactString can't fail");
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Ordinal;
+
+    macro_rules! assert_compact {
+        ($($t:ty),*) => {
+            $(
+                for value in [<$t>::MIN, <$t>::MAX, 0, 1, 2, 3, 11, 12, 13, 21, 102] {
+                    let ordinal = Ordinal(value);
+                    assert_eq!(ordinal.to_string(), ordinal.to_compact_string());
+                }
+            )*
+        };
+    }
+
+    #[test]
+    fn test_compact_matches_to_string() {
+        assert_compact!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+    }
+
+    #[test]
+    fn test_compact_stays_inline() {
+        for value in [0, 1, 22, 1_000_003, u64::MAX] {
+            assert!(!Ordinal(value).to_compact_string().is_heap_allocated());
+        }
+        for value in [i64::MIN, -13, i64::MAX] {
+            assert!(!Ordinal(value).to_compact_string().is_heap_allocated());
+        }
+        let long = Ordinal(u128::MAX).to_compact_string();
+        assert!(long.is_heap_allocated());
+        assert_eq!(long, Ordinal(u128::MAX).to_string());
+    }
+}
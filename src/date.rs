@@ -0,0 +1,260 @@
+//! ISO 8601 ordinal dates, where a date is a year and its day of the year: `2024-123`.
+//! The conversions don't depend on chrono; with the `chrono` feature they also
+//! convert to and from `NaiveDate`.
+//!
+//! ```rust
+//! use ordinal_type::{date, Ordinal};
+//!
+//! assert_eq!(date::from_year_day(2024, Ordinal(60)), Ok((2024, 2, 29)));
+//! assert_eq!(date::to_year_day(2023, 3, 1), Ordinal(60));
+//! assert_eq!(date::format_year_day(2024, Ordinal(5)), "2024-005");
+//! assert_eq!(date::parse_year_day("2024-123"), Ok((2024, Ordinal(123))));
+//! ```
+
+use crate::Ordinal;
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::fmt::{self, Display, Formatter};
+
+/// Error returned for a day of the year or calendar date that doesn't exist.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum OrdinalDateError {
+    /// The day is 0 or past the end of the year, such as day 366 of 2023.
+    DayOutOfRange { year: i32, day: u16 },
+    /// The text isn't of the form `YYYY-DDD`.
+    Malformed { text: String },
+}
+
+impl Display for OrdinalDateError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            OrdinalDateError::DayOutOfRange { year, day } => write!(
+                f,
+                "day {} is out of range for {}, which has {} days",
+                day,
+                year,
+                days_in_year(*year)
+            ),
+            OrdinalDateError::Malformed { text } => {
+                write!(
+                    f,
+                    "expected an ordinal date like 2024-123, found `{}`",
+                    text
+                )
+            }
+        }
+    }
+}
+
+impl core::error::Error for OrdinalDateError {}
+
+/// Cumulative days before each month in a common year.
+const DAYS_BEFORE_MONTH: [u16; 12] = [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334];
+
+/// Returns whether `year` is a leap year in the proleptic Gregorian calendar.
+pub fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// Returns the number of days in `year`, 365 or 366.
+pub fn days_in_year(year: i32) -> u16 {
+    if is_leap_year(year) {
+        366
+    } else {
+        365
+    }
+}
+
+fn days_before_month(year: i32, month: u8) -> u16 {
+    let leap = u16::from(month > 2 && is_leap_year(year));
+    DAYS_BEFORE_MONTH[usize::from(month - 1)] + leap
+}
+
+fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Converts a day of the year to a `(year, month, day)` calendar date.
+pub fn from_year_day(year: i32, day: Ordinal<u16>) -> Result<(i32, u8, u8), OrdinalDateError> {
+    let Ordinal(n) = day;
+    if n == 0 || n > days_in_year(year) {
+        return Err(OrdinalDateError::DayOutOfRange { year, day: n });
+    }
+    let month = (1..=12)
+        .rev()
+        .find(|&month| days_before_month(year, month) < n)
+        .expect("day 1 falls in January");
+    Ok((year, month, (n - days_before_month(year, month)) as u8))
+}
+
+/// Converts a calendar date to its day of the year.
+///
+/// # Panics
+///
+/// Panics if `month` isn't 1 to 12 or `day` isn't a day of that month.
+pub fn to_year_day(year: i32, month: u8, day: u8) -> Ordinal<u16> {
+    assert!((1..=12).contains(&month), "invalid month {}", month);
+    assert!(
+        (1..=days_in_month(year, month)).contains(&day),
+        "invalid day {} of month {}",
+        day,
+        month
+    );
+    Ordinal(days_before_month(year, month) + u16::from(day))
+}
+
+/// Formats an ordinal date as `YYYY-DDD`, such as `2024-005`. A negative year is
+/// padded after its sign, like `-0005-001`, so [`parse_year_day`] reads it back.
+pub fn format_year_day(year: i32, day: Ordinal<u16>) -> String {
+    let sign = if year < 0 { "-" } else { "" };
+    format!("{}{:04}-{:03}", sign, year.unsigned_abs(), day.0)
+}
+
+/// Parses an ordinal date of the form `YYYY-DDD`, validating the day against the year.
+pub fn parse_year_day(text: &str) -> Result<(i32, Ordinal<u16>), OrdinalDateError> {
+    let malformed = || OrdinalDateError::Malformed {
+        text: text.to_string(),
+    };
+    let (year, day) = text.rsplit_once('-').ok_or_else(malformed)?;
+    let digits = |part: &str| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit());
+    let unsigned_year = year.strip_prefix(['-', '+']).unwrap_or(year);
+    if day.len() != 3 || !digits(day) || unsigned_year.len() < 4 || !digits(unsigned_year) {
+        return Err(malformed());
+    }
+    let year = year.parse().map_err(|_| malformed())?;
+    let day = day.parse().map_err(|_| malformed())?;
+    from_year_day(year, Ordinal(day))?;
+    Ok((year, Ordinal(day)))
+}
+
+/// Converts a day of the year to a chrono `NaiveDate`.
+#[cfg(feature = "chrono")]
+pub fn to_naive_date(year: i32, day: Ordinal<u16>) -> Result<chrono::NaiveDate, OrdinalDateError> {
+    from_year_day(year, day)?;
+    // chrono only covers about 262 000 years either side of year 0.
+    chrono::NaiveDate::from_yo_opt(year, day.0.into())
+        .ok_or(OrdinalDateError::DayOutOfRange { year, day: day.0 })
+}
+
+/// Returns the year and day of the year of a chrono `NaiveDate`.
+#[cfg(feature = "chrono")]
+pub fn from_naive_date(date: chrono::NaiveDate) -> (i32, Ordinal<u16>) {
+    use chrono::Datelike;
+
+    (date.year(), Ordinal(date.ordinal() as u16))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::date::{self, OrdinalDateError};
+    use crate::Ordinal;
+
+    #[test]
+    fn test_from_year_day() {
+        let cases = [
+            (2024, 1, (1, 1)),
+            (2024, 31, (1, 31)),
+            (2024, 59, (2, 28)),
+            (2024, 60, (2, 29)),
+            (2024, 61, (3, 1)),
+            (2024, 366, (12, 31)),
+            (2023, 59, (2, 28)),
+            (2023, 60, (3, 1)),
+            (2023, 365, (12, 31)),
+            (2000, 366, (12, 31)),
+        ];
+        for (year, day, (month, day_of_month)) in cases {
+            assert_eq!(
+                Ok((year, month, day_of_month)),
+                date::from_year_day(year, Ordinal(day))
+            );
+            assert_eq!(Ordinal(day), date::to_year_day(year, month, day_of_month));
+        }
+    }
+
+    #[test]
+    fn test_from_year_day_out_of_range() {
+        for (year, day) in [(2023, 366), (1900, 366), (2024, 367), (2024, 0)] {
+            assert_eq!(
+                Err(OrdinalDateError::DayOutOfRange { year, day }),
+                date::from_year_day(year, Ordinal(day))
+            );
+        }
+        assert_eq!(
+            "day 366 is out of range for 2023, which has 365 days",
+            date::from_year_day(2023, Ordinal(366))
+                .unwrap_err()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test_round_trip_every_day() {
+        for year in [1900, 2000, 2023, 2024] {
+            for day in 1..=date::days_in_year(year) {
+                let (_, month, day_of_month) = date::from_year_day(year, Ordinal(day)).unwrap();
+                assert_eq!(Ordinal(day), date::to_year_day(year, month, day_of_month));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid day 29 of month 2")]
+    fn test_to_year_day_invalid() {
+        date::to_year_day(2023, 2, 29);
+    }
+
+    #[test]
+    fn test_format_and_parse() {
+        assert_eq!("2024-123", date::format_year_day(2024, Ordinal(123)));
+        assert_eq!("2023-001", date::format_year_day(2023, Ordinal(1)));
+        assert_eq!(Ok((2024, Ordinal(123))), date::parse_year_day("2024-123"));
+        assert_eq!(Ok((-44, Ordinal(74))), date::parse_year_day("-0044-074"));
+        assert_eq!("-0005-001", date::format_year_day(-5, Ordinal(1)));
+        for (year, day) in [(-5, 1), (-44, 74), (-12_344, 366), (i32::MIN, 1), (0, 60)] {
+            let text = date::format_year_day(year, Ordinal(day));
+            assert_eq!(Ok((year, Ordinal(day))), date::parse_year_day(&text));
+        }
+        assert_eq!(
+            Err(OrdinalDateError::DayOutOfRange {
+                year: 2023,
+                day: 366
+            }),
+            date::parse_year_day("2023-366")
+        );
+        for text in [
+            "",
+            "2024",
+            "2024-12",
+            "2024-1234",
+            "24-123",
+            "-005-001",
+            "2024-12a",
+            "2024/123",
+        ] {
+            assert_eq!(
+                Err(OrdinalDateError::Malformed {
+                    text: text.to_string()
+                }),
+                date::parse_year_day(text)
+            );
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_chrono_interop() {
+        use chrono::NaiveDate;
+
+        let leap_day = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+        assert_eq!((2024, Ordinal(60)), date::from_naive_date(leap_day));
+        assert_eq!(Ok(leap_day), date::to_naive_date(2024, Ordinal(60)));
+        assert!(date::to_naive_date(2023, Ordinal(366)).is_err());
+    }
+}
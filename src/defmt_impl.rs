@@ -0,0 +1,67 @@
+//! `defmt::Format` for `Ordinal` over the primitive integers. The number is sent as
+//! an integer and the suffix as an interned string, both chosen on the device
+//! without allocating, so the host does the formatting as usual with defmt.
+
+use crate::locale::english;
+use crate::Ordinal;
+use defmt::{intern, Format, Formatter, Str};
+
+fn suffix(last_two: u8) -> Str {
+    match english::suffix(last_two) {
+        "st" => intern!("st"),
+        "nd" => intern!("nd"),
+        "rd" => intern!("rd"),
+        _ => intern!("th"),
+    }
+}
+
+macro_rules! impl_format_for_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl Format for Ordinal<$t> {
+                fn format(&self, f: Formatter) {
+                    defmt::write!(f, "{}{=istr}", self.0, suffix((self.0 % 100) as u8))
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_format_for_signed {
+    ($($t:ty),*) => {
+        $(
+            impl Format for Ordinal<$t> {
+                fn format(&self, f: Formatter) {
+                    let last_two = (self.0.unsigned_abs() % 100) as u8;
+                    defmt::write!(f, "{}{=istr}", self.0, suffix(last_two))
+                }
+            }
+        )*
+    };
+}
+
+impl_format_for_unsigned!(u8, u16, u32, u64, u128, usize);
+impl_format_for_signed!(i8, i16, i32, i64, i128, isize);
+
+#[cfg(test)]
+mod tests {
+    use crate::Ordinal;
+
+    fn assert_format<T: defmt::Format>() {}
+
+    #[test]
+    fn test_defmt_format_impls() {
+        assert_format::<Ordinal<u8>>();
+        assert_format::<Ordinal<u16>>();
+        assert_format::<Ordinal<u32>>();
+        assert_format::<Ordinal<u64>>();
+        assert_format::<Ordinal<u128>>();
+        assert_format::<Ordinal<usize>>();
+        assert_format::<Ordinal<i8>>();
+        assert_format::<Ordinal<i16>>();
+        assert_format::<Ordinal<i32>>();
+        assert_format::<Ordinal<i64>>();
+        assert_format::<Ordinal<i128>>();
+        assert_format::<Ordinal<isize>>();
+    }
+}
@@ -0,0 +1,127 @@
+//! Diesel `ToSql` and `FromSql` for `Ordinal<i16>`, `Ordinal<i32>` and `Ordinal<i64>`
+//! as `SmallInt`, `Integer` and `BigInt`, on every backend that supports the bare integer.
+//! `AsExpression` and `FromSqlRow` are derived on `Ordinal` itself.
+
+use crate::Ordinal;
+use diesel::backend::Backend;
+use diesel::deserialize::{self, FromSql};
+use diesel::serialize::{self, Output, ToSql};
+use diesel::sql_types::{BigInt, Integer, SmallInt};
+
+macro_rules! impl_sql_for_ordinals {
+    ($($t:ty => $sql:ty),*) => {
+        $(
+            impl<DB> ToSql<$sql, DB> for Ordinal<$t>
+            where
+                DB: Backend,
+                $t: ToSql<$sql, DB>,
+            {
+                fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+                    self.0.to_sql(out)
+                }
+            }
+
+            impl<DB> FromSql<$sql, DB> for Ordinal<$t>
+            where
+                DB: Backend,
+                $t: FromSql<$sql, DB>,
+            {
+                fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+                    <$t>::from_sql(bytes).map(Ordinal)
+                }
+            }
+        )*
+    };
+}
+
+impl_sql_for_ordinals!(i16 => SmallInt, i32 => Integer, i64 => BigInt);
+
+#[cfg(test)]
+mod tests {
+    use crate::Ordinal;
+    use diesel::prelude::*;
+    use diesel::sql_query;
+    use diesel::sqlite::SqliteConnection;
+
+    diesel::table! {
+        standings (id) {
+            id -> Integer,
+            name -> Text,
+            place -> Integer,
+            previous -> Nullable<Integer>,
+            total -> BigInt,
+        }
+    }
+
+    #[derive(Queryable, Insertable, PartialEq, Debug)]
+    #[diesel(table_name = standings)]
+    struct Standing {
+        id: i32,
+        name: String,
+        place: Ordinal<i32>,
+        previous: Option<Ordinal<i32>>,
+        total: Ordinal<i64>,
+    }
+
+    #[test]
+    fn test_diesel_backends() {
+        use diesel::deserialize::FromSql;
+        use diesel::pg::Pg;
+        use diesel::serialize::ToSql;
+        use diesel::sql_types::{BigInt, Integer, SmallInt};
+        use diesel::sqlite::Sqlite;
+
+        fn assert_sql<ST, DB: diesel::backend::Backend, T: ToSql<ST, DB> + FromSql<ST, DB>>() {}
+        assert_sql::<SmallInt, Pg, Ordinal<i16>>();
+        assert_sql::<Integer, Pg, Ordinal<i32>>();
+        assert_sql::<BigInt, Pg, Ordinal<i64>>();
+        assert_sql::<SmallInt, Sqlite, Ordinal<i16>>();
+        assert_sql::<Integer, Sqlite, Ordinal<i32>>();
+        assert_sql::<BigInt, Sqlite, Ordinal<i64>>();
+    }
+
+    #[test]
+    fn test_diesel_sqlite_round_trip() {
+        let mut conn = SqliteConnection::establish(":memory:").unwrap();
+        sql_query(
+            "CREATE TABLE standings (id INTEGER PRIMARY KEY NOT NULL, name TEXT NOT NULL, \
+             place INTEGER NOT NULL, previous INTEGER, total BIGINT NOT NULL)",
+        )
+        .execute(&mut conn)
+        .unwrap();
+
+        let rows = vec![
+            Standing {
+                id: 1,
+                name: "ann".to_string(),
+                place: Ordinal(1),
+                previous: Some(Ordinal(3)),
+                total: Ordinal(10_000_000_000),
+            },
+            Standing {
+                id: 2,
+                name: "bob".to_string(),
+                place: Ordinal(22),
+                previous: None,
+                total: Ordinal(-4),
+            },
+        ];
+        diesel::insert_into(standings::table)
+            .values(&rows)
+            .execute(&mut conn)
+            .unwrap();
+
+        let loaded: Vec<Standing> = standings::table
+            .order(standings::id)
+            .load(&mut conn)
+            .unwrap();
+        assert_eq!(rows, loaded);
+
+        let name: String = standings::table
+            .filter(standings::place.eq(Ordinal(22)))
+            .select(standings::name)
+            .first(&mut conn)
+            .unwrap();
+        assert_eq!("bob", name);
+    }
+}
@@ -0,0 +1,149 @@
+//! `Display` adaptors that render other forms of an ordinal straight into a formatter,
+//! for writing into an existing buffer or a logger without building a `String` first.
+
+use crate::locale::english;
+use crate::{Ordinal, OrdinalInteger};
+use core::fmt::{self, Display, Formatter};
+
+/// The spelled-out English form of an ordinal, from [`Ordinal::words`].
+#[derive(Clone, Copy, Debug)]
+pub struct Words<'a, T>(&'a Ordinal<T>);
+
+/// An ordinal with its suffix in Unicode superscript letters, from [`Ordinal::superscript`].
+#[derive(Clone, Copy, Debug)]
+pub struct Superscript<'a, T>(&'a Ordinal<T>);
+
+/// An ordinal with its suffix in an HTML `<sup>` element, from [`Ordinal::html`].
+#[derive(Clone, Copy, Debug)]
+pub struct Html<'a, T>(&'a Ordinal<T>);
+
+impl<T: OrdinalInteger> Ordinal<T> {
+    /// Returns a `Display` adaptor that spells out the ordinal in English, writing the
+    /// same text as [`Ordinal::to_words`] without allocating for primitive integers.
+    /// Values without an English word form, which are negative or wider than a `u128`,
+    /// are written as digits and a suffix instead, like `Display`.
+    /// ```rust
+    /// use ordinal_type::Ordinal;
+    ///
+    /// assert_eq!(format!("{}", Ordinal(22).words()), "twenty-second");
+    /// assert_eq!(format!("{}", Ordinal(-3).words()), "-3rd");
+    /// ```
+    pub fn words(&self) -> Words<'_, T> {
+        Words(self)
+    }
+
+    /// Returns a `Display` adaptor that writes the suffix in Unicode superscript letters,
+    /// like `22ⁿᵈ`, for plain text where markup isn't available.
+    /// ```rust
+    /// use ordinal_type::Ordinal;
+    ///
+    /// assert_eq!(Ordinal(22).superscript().to_string(), "22ⁿᵈ");
+    /// assert_eq!(Ordinal(-11).superscript().to_string(), "-11ᵗʰ");
+    /// ```
+    pub fn superscript(&self) -> Superscript<'_, T> {
+        Superscript(self)
+    }
+
+    /// Returns a `Display` adaptor that writes the suffix in a `<sup>` element, like
+    /// `22<sup>nd</sup>`. Digits and suffixes never need escaping, so the output is
+    /// safe to embed in HTML as is.
+    /// ```rust
+    /// use ordinal_type::Ordinal;
+    ///
+    /// assert_eq!(Ordinal(22).html().to_string(), "22<sup>nd</sup>");
+    /// ```
+    pub fn html(&self) -> Html<'_, T> {
+        Html(self)
+    }
+}
+
+impl<T: OrdinalInteger> Display for Words<'_, T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.0 .0.to_u128_checked() {
+            Some(n) => english::write_words(n, f),
+            None => self.0.write_to(f),
+        }
+    }
+}
+
+impl<T: OrdinalInteger> Display for Superscript<'_, T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.0 .0.write_digits(f)?;
+        f.write_str(superscript(self.0.suffix()))
+    }
+}
+
+/// Returns the superscript form of an English suffix, like `ˢᵗ` for `st`.
+pub(crate) fn superscript(suffix: &str) -> &'static str {
+    match suffix {
+        "st" => "ˢᵗ",
+        "nd" => "ⁿᵈ",
+        "rd" => "ʳᵈ",
+        _ => "ᵗʰ",
+    }
+}
+
+impl<T: OrdinalInteger> Display for Html<'_, T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.0 .0.write_digits(f)?;
+        write!(f, "<sup>{}</sup>", self.0.suffix())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Ordinal;
+    use rand_chacha::rand_core::{RngCore, SeedableRng};
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_words_matches_to_words() {
+        let mut rng = ChaCha8Rng::seed_from_u64(7);
+        let sample = (0u128..=2000)
+            .chain((0..20_000).map(|_| {
+                // Spread over every scale up to the undecillions.
+                let wide = u128::from(rng.next_u64()) << 64 | u128::from(rng.next_u64());
+                wide >> (rng.next_u32() % 128)
+            }))
+            .chain([u64::MAX.into(), u128::MAX - 1, u128::MAX]);
+        for n in sample {
+            let ordinal = Ordinal(n);
+            assert_eq!(ordinal.to_words().unwrap(), ordinal.words().to_string());
+        }
+        for n in [0i8, 1, 12, 100, i8::MAX] {
+            let ordinal = Ordinal(n);
+            assert_eq!(ordinal.to_words().unwrap(), format!("{}", ordinal.words()));
+        }
+    }
+
+    #[test]
+    fn test_words_without_word_form() {
+        assert!(Ordinal(-22).to_words().is_err());
+        assert_eq!("-22nd", Ordinal(-22).words().to_string());
+        assert_eq!(
+            "-170141183460469231731687303715884105728th",
+            Ordinal(i128::MIN).words().to_string()
+        );
+    }
+
+    #[test]
+    fn test_words_ignores_width() {
+        // Like `Display` for `Ordinal`, the adaptors ignore width and fill.
+        assert_eq!("first", format!("{:>10}", Ordinal(1).words()));
+    }
+
+    #[test]
+    fn test_superscript_and_html() {
+        let cases = [
+            (1, "1ˢᵗ", "1<sup>st</sup>"),
+            (2, "2ⁿᵈ", "2<sup>nd</sup>"),
+            (3, "3ʳᵈ", "3<sup>rd</sup>"),
+            (11, "11ᵗʰ", "11<sup>th</sup>"),
+            (-101, "-101ˢᵗ", "-101<sup>st</sup>"),
+        ];
+        for (n, superscript, html) in cases {
+            assert_eq!(superscript, Ordinal(n).superscript().to_string());
+            assert_eq!(html, Ordinal(n).html().to_string());
+        }
+    }
+}
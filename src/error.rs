@@ -0,0 +1,209 @@
+//! The crate-wide [`Error`], which every fallible function's error converts into.
+
+use crate::date::OrdinalDateError;
+#[cfg(feature = "primitive-types")]
+use crate::WideOverflow;
+use crate::{EmptyData, LocaleUnsupported, ParseOrdinalError};
+use core::fmt::{self, Display, Formatter};
+
+/// Any error returned by this crate, so code calling several fallible functions can
+/// use `?` on all of them.
+///
+/// `Display` and [`std::error::Error::source`](core::error::Error::source) are those of the wrapped error, so
+/// wrapping one adds no layer to an error report.
+/// ```rust
+/// use ordinal_type::{Error, Locale, Ordinal};
+///
+/// fn describe(text: &str) -> Result<String, Error> {
+///     let ordinal: Ordinal<u32> = text.parse()?;
+///     Ok(ordinal.to_words_locale(Locale::English, &Default::default())?)
+/// }
+///
+/// assert_eq!(describe("22nd").unwrap(), "twenty-second");
+/// assert_eq!(describe("22").unwrap_err().to_string(), "missing ordinal suffix after `22`");
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+    /// Text that isn't an ordinal, from parsing.
+    Parse(ParseOrdinalError),
+    /// A locale without a word form for the value.
+    Locale(LocaleUnsupported),
+    /// A day of the year that doesn't exist, or text that isn't an ordinal date.
+    Date(OrdinalDateError),
+    /// A percentile rank asked of no observations.
+    EmptyData(EmptyData),
+    /// A `U256` or `U512` ordinal too wide for the requested primitive.
+    #[cfg(feature = "primitive-types")]
+    WideOverflow(WideOverflow),
+    /// A write that failed, such as [`crate::Ordinal::format_into`] running out of room.
+    Format(fmt::Error),
+}
+
+impl Error {
+    fn inner(&self) -> &(dyn core::error::Error + 'static) {
+        match self {
+            Error::Parse(error) => error,
+            Error::Locale(error) => error,
+            Error::Date(error) => error,
+            Error::EmptyData(error) => error,
+            #[cfg(feature = "primitive-types")]
+            Error::WideOverflow(error) => error,
+            Error::Format(error) => error,
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self.inner(), f)
+    }
+}
+
+impl core::error::Error for Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        self.inner().source()
+    }
+}
+
+macro_rules! impl_from {
+    ($($(#[$attr:meta])* $variant:ident($error:ty)),*) => {
+        $(
+            $(#[$attr])*
+            impl From<$error> for Error {
+                fn from(error: $error) -> Self {
+                    Error::$variant(error)
+                }
+            }
+        )*
+    };
+}
+
+impl_from!(
+    Parse(ParseOrdinalError),
+    Locale(LocaleUnsupported),
+    Date(OrdinalDateError),
+    EmptyData(EmptyData),
+    #[cfg(feature = "primitive-types")]
+    WideOverflow(WideOverflow),
+    Format(fmt::Error)
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::date::{self, OrdinalDateError};
+    use crate::{
+        percentile_label, percentile_rank, EmptyData, Error, Locale, Ordinal, OrdinalOptions,
+        ParseOrdinalError, PercentileMethod,
+    };
+    use std::error::Error as _;
+
+    /// Calls every public fallible function with input it accepts, through `?`.
+    #[test]
+    fn test_every_error_converts() -> Result<(), Error> {
+        let ordinal: Ordinal<u8> = "22nd".parse()?;
+        Ordinal::<u8>::from_words("twenty-second")?;
+        Ordinal::<u8>::from_str_radix("0x16nd", 16)?;
+        ordinal.to_words()?;
+        ordinal.to_words_locale(Locale::French, &OrdinalOptions::default())?;
+        ordinal.format_into(&mut [0; 8])?;
+        date::from_year_day(2024, Ordinal(60))?;
+        date::parse_year_day("2024-060")?;
+        #[cfg(feature = "chrono")]
+        date::to_naive_date(2024, Ordinal(60))?;
+        percentile_rank(&[1, 2, 3], &2, PercentileMethod::Exclusive)?;
+        percentile_label(&[1, 2, 3], &2, PercentileMethod::Exclusive)?;
+        #[cfg(feature = "primitive-types")]
+        {
+            use crate::WideOrdinal;
+            use primitive_types::U256;
+
+            Ordinal(U256::from(22)).try_to_u64()?;
+            Ordinal(U256::from(22)).try_to_u128()?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_error_display() {
+        let cases: Vec<(Error, &str)> = vec![
+            (
+                "256th".parse::<Ordinal<u8>>().unwrap_err().into(),
+                "invalid number `256`",
+            ),
+            (
+                "11st".parse::<Ordinal<u8>>().unwrap_err().into(),
+                "wrong suffix `st` in `11st`, expected `th`",
+            ),
+            (
+                Ordinal::<u8>::from_words("first second")
+                    .unwrap_err()
+                    .into(),
+                "`first second` is not a spelled-out English ordinal",
+            ),
+            (
+                Ordinal::<u8>::from_str_radix("0x100th", 16)
+                    .unwrap_err()
+                    .into(),
+                "`0x100th` is out of range for the type",
+            ),
+            (
+                Ordinal(-3)
+                    .to_words_locale(Locale::English, &OrdinalOptions::default())
+                    .unwrap_err()
+                    .into(),
+                "English has no word form for -3",
+            ),
+            (
+                date::from_year_day(2023, Ordinal(366)).unwrap_err().into(),
+                "day 366 is out of range for 2023, which has 365 days",
+            ),
+            (
+                date::parse_year_day("2024/123").unwrap_err().into(),
+                "expected an ordinal date like 2024-123, found `2024/123`",
+            ),
+            (
+                percentile_rank(&[], &1, PercentileMethod::Exclusive)
+                    .unwrap_err()
+                    .into(),
+                "no observations to rank against",
+            ),
+            (
+                Ordinal(1_234_567)
+                    .format_into(&mut [0; 4])
+                    .unwrap_err()
+                    .into(),
+                "an error occurred when formatting an argument",
+            ),
+        ];
+        for (error, message) in cases {
+            assert_eq!(message, error.to_string());
+        }
+    }
+
+    #[test]
+    fn test_error_variants_and_source() {
+        let error = Error::from("".parse::<Ordinal<u8>>().unwrap_err());
+        assert_eq!(Error::Parse(ParseOrdinalError::Empty), error);
+        assert!(error.source().is_none());
+
+        let error = Error::from(EmptyData);
+        assert_eq!(Error::EmptyData(EmptyData), error);
+        assert!(error.source().is_none());
+
+        let malformed = OrdinalDateError::Malformed {
+            text: "x".to_string(),
+        };
+        assert_eq!(Error::Date(malformed.clone()), Error::from(malformed));
+
+        // Boxed, the wrapper still reports the leaf message once.
+        let boxed: Box<dyn std::error::Error> =
+            Box::new(Error::from("2st".parse::<Ordinal<u8>>().unwrap_err()));
+        assert_eq!(
+            "wrong suffix `st` in `2st`, expected `nd`",
+            boxed.to_string()
+        );
+        assert!(boxed.source().is_none());
+        assert!(boxed.downcast_ref::<Error>().is_some());
+    }
+}
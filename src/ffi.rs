@@ -0,0 +1,218 @@
+//! `extern "C"` functions, for formatting ordinals from C and C++. They're declared in
+//! `include/ordinal_type.h`, which is generated from this module with
+//! `cbindgen --config cbindgen.toml --output include/ordinal_type.h src/ffi.rs`.
+//!
+//! Cargo can't switch crate types per feature, so build the shared or static library
+//! with `cargo rustc`:
+//!
+//! ```sh
+//! cargo rustc --release --lib --features ffi --crate-type cdylib
+//! ```
+//!
+//! No function panics across the boundary. A panic inside one is caught and reported
+//! as [`ORDINAL_ERROR_PANIC`].
+//!
+//! ```c
+//! #include "ordinal_type.h"
+//!
+//! char buf[32];
+//! intptr_t len = ordinal_format_i64(-22, buf, sizeof buf);  // 5, buf is "-22nd"
+//! const char *suffix = ordinal_suffix_i64(11);              // "th"
+//! ```
+
+use crate::{Locale, Ordinal};
+use std::ffi::{c_char, CStr};
+use std::panic::{self, UnwindSafe};
+
+/// Named for C, so cbindgen declares lengths as `size_t`.
+#[allow(non_camel_case_types)]
+type size_t = usize;
+
+/// The buffer can't hold the ordinal and its terminating NUL.
+pub const ORDINAL_ERROR_BUFFER_TOO_SMALL: isize = -1;
+/// A pointer argument was null.
+pub const ORDINAL_ERROR_NULL_POINTER: isize = -2;
+/// The locale tag isn't UTF-8 or names no supported locale.
+pub const ORDINAL_ERROR_UNSUPPORTED_LOCALE: isize = -3;
+/// The function panicked. This is a bug in the crate.
+pub const ORDINAL_ERROR_PANIC: isize = -4;
+
+/// Runs `f`, turning a panic into [`ORDINAL_ERROR_PANIC`].
+fn guard(f: impl FnOnce() -> isize + UnwindSafe) -> isize {
+    panic::catch_unwind(f).unwrap_or(ORDINAL_ERROR_PANIC)
+}
+
+/// Copies `text` and a NUL into `buf`, returning the length of `text`. Leaves an empty
+/// string in `buf`, if it has room for one, when `text` doesn't fit.
+///
+/// # Safety
+///
+/// `buf` must be null or valid for writes of `len` bytes.
+unsafe fn write_c_str(text: &str, buf: *mut c_char, len: size_t) -> isize {
+    if buf.is_null() {
+        return ORDINAL_ERROR_NULL_POINTER;
+    }
+    // SAFETY: the caller guarantees `buf` is valid for `len` bytes, and it isn't null.
+    let out = unsafe { std::slice::from_raw_parts_mut(buf.cast::<u8>(), len) };
+    match out.get_mut(..text.len() + 1) {
+        Some(out) => {
+            out[..text.len()].copy_from_slice(text.as_bytes());
+            out[text.len()] = 0;
+            text.len() as isize
+        }
+        None => {
+            if let Some(first) = out.first_mut() {
+                *first = 0;
+            }
+            ORDINAL_ERROR_BUFFER_TOO_SMALL
+        }
+    }
+}
+
+/// Returns the English suffix of `n`, like `"nd"` for 22, as a NUL-terminated string
+/// with static storage. Never returns null and never needs freeing.
+#[no_mangle]
+pub extern "C" fn ordinal_suffix_i64(n: i64) -> *const c_char {
+    let suffix = match Ordinal(n).suffix() {
+        "st" => c"st",
+        "nd" => c"nd",
+        "rd" => c"rd",
+        _ => c"th",
+    };
+    suffix.as_ptr()
+}
+
+/// Writes `n` as an English ordinal, like `"-22nd"`, and a terminating NUL to `buf`.
+/// Returns the number of bytes written before the NUL, or a negative `ORDINAL_ERROR_*`
+/// code. 23 bytes always suffice.
+///
+/// # Safety
+///
+/// `buf` must be null or valid for writes of `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn ordinal_format_i64(n: i64, buf: *mut c_char, len: size_t) -> isize {
+    guard(|| {
+        let mut text = [0; 24];
+        let text = Ordinal(n)
+            .format_into(&mut text)
+            .expect("every i64 ordinal fits in 24 bytes");
+        // SAFETY: forwarded from the caller.
+        unsafe { write_c_str(text, buf, len) }
+    })
+}
+
+/// Like [`ordinal_format_i64`], but in the locale of `locale`, a NUL-terminated UTF-8
+/// tag like `"fr"` or `"pt-BR"`, as `Locale::from_tag` reads it.
+///
+/// # Safety
+///
+/// `locale` must be null or point to a NUL-terminated string, and `buf` must be null or
+/// valid for writes of `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn ordinal_format_locale_i64(
+    n: i64,
+    locale: *const c_char,
+    buf: *mut c_char,
+    len: size_t,
+) -> isize {
+    guard(|| {
+        if locale.is_null() {
+            return ORDINAL_ERROR_NULL_POINTER;
+        }
+        // SAFETY: the caller guarantees `locale` is a NUL-terminated string.
+        let tag = unsafe { CStr::from_ptr(locale) };
+        let Some(locale) = tag.to_str().ok().and_then(Locale::from_tag) else {
+            return ORDINAL_ERROR_UNSUPPORTED_LOCALE;
+        };
+        let text = Ordinal(n).to_string_with_locale(locale);
+        // SAFETY: forwarded from the caller.
+        unsafe { write_c_str(&text, buf, len) }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ptr;
+
+    fn format(n: i64, len: usize) -> (isize, Vec<u8>) {
+        let mut buf = vec![0x7f; len];
+        let written = unsafe { ordinal_format_i64(n, buf.as_mut_ptr().cast(), len) };
+        (written, buf)
+    }
+
+    #[test]
+    fn test_suffix_is_static_c_str() {
+        for (n, suffix) in [(1, "st"), (-22, "nd"), (113, "th"), (i64::MIN, "th")] {
+            let ptr = ordinal_suffix_i64(n);
+            assert!(!ptr.is_null());
+            assert_eq!(suffix, unsafe { CStr::from_ptr(ptr) }.to_str().unwrap());
+        }
+    }
+
+    #[test]
+    fn test_format_negative() {
+        let (written, buf) = format(-22, 8);
+        assert_eq!(5, written);
+        assert_eq!(b"-22nd\0", &buf[..6]);
+        let (written, buf) = format(i64::MIN, 23);
+        assert_eq!(22, written);
+        assert_eq!(b"-9223372036854775808th\0", &buf[..]);
+    }
+
+    #[test]
+    fn test_buffer_too_small() {
+        // "-22nd" needs six bytes with its NUL.
+        let (written, buf) = format(-22, 5);
+        assert_eq!(ORDINAL_ERROR_BUFFER_TOO_SMALL, written);
+        assert_eq!(0, buf[0]);
+        assert_eq!((5, b"-22nd\0".to_vec()), format(-22, 6));
+        assert_eq!(ORDINAL_ERROR_BUFFER_TOO_SMALL, format(1, 0).0);
+        assert_eq!(ORDINAL_ERROR_NULL_POINTER, unsafe {
+            ordinal_format_i64(1, ptr::null_mut(), 8)
+        });
+    }
+
+    #[test]
+    fn test_format_locale() {
+        let mut buf = [0 as c_char; 16];
+        let format = |tag: &CStr, buf: &mut [c_char]| unsafe {
+            ordinal_format_locale_i64(1, tag.as_ptr(), buf.as_mut_ptr(), buf.len())
+        };
+        assert_eq!(3, format(c"fr-FR", &mut buf));
+        assert_eq!(
+            "1er",
+            unsafe { CStr::from_ptr(buf.as_ptr()) }.to_str().unwrap()
+        );
+        assert_eq!(ORDINAL_ERROR_UNSUPPORTED_LOCALE, format(c"xx", &mut buf));
+        assert_eq!(ORDINAL_ERROR_UNSUPPORTED_LOCALE, format(c"\xff", &mut buf));
+        assert_eq!(ORDINAL_ERROR_BUFFER_TOO_SMALL, format(c"fr", &mut buf[..3]));
+        assert_eq!(ORDINAL_ERROR_NULL_POINTER, unsafe {
+            ordinal_format_locale_i64(1, ptr::null(), buf.as_mut_ptr(), buf.len())
+        });
+    }
+
+    #[test]
+    fn test_panic_is_caught() {
+        assert_eq!(ORDINAL_ERROR_PANIC, guard(|| panic!("boom")));
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn test_header_is_current() {
+        let dir = env!("CARGO_MANIFEST_DIR");
+        let mut generated = Vec::new();
+        cbindgen::Builder::new()
+            .with_config(cbindgen::Config::from_file(format!("{}/cbindgen.toml", dir)).unwrap())
+            .with_src(format!("{}/src/ffi.rs", dir))
+            .generate()
+            .expect("cbindgen reads src/ffi.rs")
+            .write(&mut generated);
+        let committed = std::fs::read(format!("{}/include/ordinal_type.h", dir)).unwrap();
+        assert!(
+            generated == committed,
+            "include/ordinal_type.h is stale, regenerate it with cbindgen:\n{}",
+            String::from_utf8_lossy(&generated)
+        );
+    }
+}
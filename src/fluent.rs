@@ -0,0 +1,86 @@
+//! An `ORDINAL` function for [Fluent](https://projectfluent.org) messages, behind the
+//! `fluent` feature:
+//!
+//! ```ftl
+//! finished = You finished { ORDINAL($place) }
+//! ```
+
+use crate::{Locale, Ordinal};
+use fluent_bundle::bundle::FluentBundle;
+use fluent_bundle::{FluentArgs, FluentError, FluentValue};
+
+/// Formats the first positional argument as an ordinal in `locale`, with the signature
+/// of a Fluent function once the locale is bound. Returns [`FluentValue::Error`] unless
+/// the argument is an integer.
+pub fn ordinal<'a>(
+    locale: Locale,
+    positional: &[FluentValue<'a>],
+    _: &FluentArgs,
+) -> FluentValue<'a> {
+    let value = match positional.first() {
+        Some(FluentValue::Number(number)) if number.value.fract() == 0.0 => {
+            Some(number.value as i128)
+        }
+        Some(FluentValue::String(string)) => string.parse().ok(),
+        _ => None,
+    };
+    match value {
+        Some(value) => FluentValue::String(Ordinal(value).to_string_with_locale(locale).into()),
+        None => FluentValue::Error,
+    }
+}
+
+/// Registers [`ordinal`] as `ORDINAL` on the bundle, in the locale that
+/// [`Locale::resolve_tag`] gives for the bundle's first language.
+pub fn register<R, M>(bundle: &mut FluentBundle<R, M>) -> Result<(), FluentError> {
+    let locale = bundle.locales.first().map_or(Locale::English, |language| {
+        Locale::resolve_tag(&language.to_string())
+    });
+    bundle.add_function("ORDINAL", move |positional, named| {
+        ordinal(locale, positional, named)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::register;
+    use fluent_bundle::{FluentArgs, FluentBundle, FluentResource};
+
+    fn finished(language: &str, source: &str, place: i64) -> String {
+        let resource = FluentResource::try_new(source.to_string()).unwrap();
+        let mut bundle = FluentBundle::new(vec![language.parse().unwrap()]);
+        bundle.set_use_isolating(false);
+        bundle.add_resource(resource).unwrap();
+        register(&mut bundle).unwrap();
+
+        let mut args = FluentArgs::new();
+        args.set("place", place);
+        let message = bundle.get_message("finished").unwrap();
+        let mut errors = vec![];
+        let value = bundle
+            .format_pattern(message.value().unwrap(), Some(&args), &mut errors)
+            .to_string();
+        assert!(errors.is_empty(), "{:?}", errors);
+        value
+    }
+
+    #[test]
+    fn test_fluent_ordinal() {
+        assert_eq!(
+            "You finished 3rd",
+            finished("en", "finished = You finished { ORDINAL($place) }", 3)
+        );
+        assert_eq!(
+            "Vous avez terminé 3e",
+            finished("fr", "finished = Vous avez terminé { ORDINAL($place) }", 3)
+        );
+        assert_eq!(
+            "Vous avez terminé 1er",
+            finished(
+                "fr-CA",
+                "finished = Vous avez terminé { ORDINAL($place) }",
+                1
+            )
+        );
+    }
+}
@@ -0,0 +1,273 @@
+//! `OrdinalFormatter`, a reusable locale, options and style guide for formatting ordinals.
+
+use crate::locale::{Gender, Locale, Number, OrdinalOptions};
+use crate::{Ordinal, OrdinalInteger};
+use alloc::format;
+use alloc::string::String;
+
+/// An editorial style guide's rule for which ordinals to spell out.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum StylePreset {
+    /// Numerals throughout, like `3rd`.
+    #[default]
+    None,
+    /// AP style: `first` through `ninth`, then `10th` and up.
+    Ap,
+    /// Chicago style for the humanities: `first` through `one hundredth`, then `101st`
+    /// and up.
+    ChicagoHumanities,
+}
+
+impl StylePreset {
+    /// Returns the smallest value, by absolute value, written in numerals.
+    pub fn spell_out_below(self) -> u128 {
+        match self {
+            StylePreset::None => 0,
+            StylePreset::Ap => 10,
+            StylePreset::ChicagoHumanities => 101,
+        }
+    }
+}
+
+/// A reusable locale and set of options for formatting many ordinals the same way.
+/// ```rust
+/// use ordinal_type::{Gender, Locale, Ordinal, OrdinalFormatter};
+///
+/// let formatter = OrdinalFormatter::new(Locale::French).gender(Gender::Feminine);
+/// assert_eq!(formatter.format(&Ordinal(1)), "1re");
+/// assert_eq!(formatter.format(&Ordinal(2)), "2e");
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct OrdinalFormatter {
+    locale: Locale,
+    options: OrdinalOptions,
+    spell_out_below: u128,
+    capitalize: bool,
+}
+
+impl OrdinalFormatter {
+    /// Returns a formatter for the locale with the default options.
+    pub fn new(locale: Locale) -> Self {
+        OrdinalFormatter {
+            locale,
+            options: OrdinalOptions::default(),
+            spell_out_below: 0,
+            capitalize: false,
+        }
+    }
+
+    /// Replaces the options.
+    pub fn options(mut self, options: OrdinalOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Sets the grammatical gender, keeping the other options.
+    pub fn gender(mut self, gender: Gender) -> Self {
+        self.options = self.options.gender(gender);
+        self
+    }
+
+    /// Spells out ordinals the way a style guide does. See [`StylePreset`].
+    /// ```rust
+    /// use ordinal_type::{Locale, Ordinal, OrdinalFormatter, StylePreset};
+    ///
+    /// let formatter = OrdinalFormatter::new(Locale::English).style(StylePreset::Ap);
+    /// assert_eq!(formatter.format(&Ordinal(3)), "third");
+    /// assert_eq!(formatter.format(&Ordinal(21)), "21st");
+    /// ```
+    pub fn style(self, preset: StylePreset) -> Self {
+        self.spell_out_below(preset.spell_out_below())
+    }
+
+    /// Spells out ordinals whose absolute value is below `threshold`, so 0 becomes
+    /// `zeroth` for any threshold above it. Values the locale has no words for stay
+    /// numerals. In English, a negative value is spelled with `minus`, as in
+    /// `minus third`; other locales keep numerals for negative values.
+    pub fn spell_out_below(mut self, threshold: u128) -> Self {
+        self.spell_out_below = threshold;
+        self
+    }
+
+    /// Capitalizes the first letter, for ordinals that start a sentence.
+    /// ```rust
+    /// use ordinal_type::{Locale, Ordinal, OrdinalFormatter, StylePreset};
+    ///
+    /// let formatter = OrdinalFormatter::new(Locale::English)
+    ///     .style(StylePreset::Ap)
+    ///     .capitalize(true);
+    /// assert_eq!(formatter.format(&Ordinal(1)), "First");
+    /// assert_eq!(formatter.format(&Ordinal(11)), "11th");
+    /// ```
+    pub fn capitalize(mut self, capitalize: bool) -> Self {
+        self.capitalize = capitalize;
+        self
+    }
+
+    /// Returns the locale.
+    pub fn locale(&self) -> Locale {
+        self.locale
+    }
+
+    /// Formats the ordinal number.
+    pub fn format<T>(&self, ordinal: &Ordinal<T>) -> String
+    where
+        T: OrdinalInteger,
+    {
+        let number = Number::new(&ordinal.0);
+        let text = self
+            .spelled_out(&number)
+            .unwrap_or_else(|| self.locale.format(&number, &self.options));
+        if !self.capitalize {
+            return text;
+        }
+        let mut chars = text.chars();
+        match chars.next() {
+            Some(first) => first.to_uppercase().chain(chars).collect(),
+            None => text,
+        }
+    }
+
+    /// Returns the words for a value below the spell-out threshold.
+    fn spelled_out(&self, number: &Number) -> Option<String> {
+        let magnitude = number.magnitude().filter(|&m| m < self.spell_out_below)?;
+        if !number.is_negative() {
+            return self.locale.words(number, &self.options).ok();
+        }
+        if self.locale.resolve() != Locale::English {
+            return None;
+        }
+        let words = self
+            .locale
+            .words(&Number::new(&magnitude), &self.options)
+            .ok()?;
+        Some(format!("minus {}", words))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Gender, Locale, Ordinal, OrdinalFormatter, OrdinalOptions, StylePreset};
+
+    #[test]
+    fn test_style_presets() {
+        let ap = OrdinalFormatter::new(Locale::English).style(StylePreset::Ap);
+        let chicago = OrdinalFormatter::new(Locale::English).style(StylePreset::ChicagoHumanities);
+        let none = OrdinalFormatter::new(Locale::English).style(StylePreset::None);
+        let cases = [
+            (0, "zeroth", "zeroth", "0th"),
+            (1, "first", "first", "1st"),
+            (9, "ninth", "ninth", "9th"),
+            (10, "10th", "tenth", "10th"),
+            (21, "21st", "twenty-first", "21st"),
+            (99, "99th", "ninety-ninth", "99th"),
+            (100, "100th", "one hundredth", "100th"),
+            (101, "101st", "101st", "101st"),
+            (-3, "minus third", "minus third", "-3rd"),
+            (-10, "-10th", "minus tenth", "-10th"),
+            (-101, "-101st", "-101st", "-101st"),
+        ];
+        for (value, ap_text, chicago_text, none_text) in cases {
+            assert_eq!(ap_text, ap.format(&Ordinal(value)));
+            assert_eq!(chicago_text, chicago.format(&Ordinal(value)));
+            assert_eq!(none_text, none.format(&Ordinal(value)));
+        }
+        assert_eq!(
+            OrdinalFormatter::new(Locale::English),
+            none,
+            "StylePreset::None is the default"
+        );
+    }
+
+    #[test]
+    fn test_spell_out_below_custom() {
+        let formatter = OrdinalFormatter::new(Locale::English).spell_out_below(100);
+        assert_eq!("ninety-ninth", formatter.format(&Ordinal(99u8)));
+        assert_eq!("100th", formatter.format(&Ordinal(100u8)));
+        assert_eq!("minus ninety-ninth", formatter.format(&Ordinal(-99i64)));
+        assert_eq!("-100th", formatter.format(&Ordinal(-100i64)));
+        assert_eq!("1000th", formatter.format(&Ordinal(1000)));
+
+        let huge = OrdinalFormatter::new(Locale::English).spell_out_below(u128::MAX);
+        assert_eq!("minus first", huge.format(&Ordinal(i128::MIN + i128::MAX)));
+        assert_eq!(
+            Ordinal(u128::MAX).to_string(),
+            huge.format(&Ordinal(u128::MAX))
+        );
+    }
+
+    #[test]
+    fn test_style_with_capitalize_and_locales() {
+        let sentence = OrdinalFormatter::new(Locale::English)
+            .style(StylePreset::Ap)
+            .capitalize(true);
+        assert_eq!("Third", sentence.format(&Ordinal(3)));
+        assert_eq!("Zeroth", sentence.format(&Ordinal(0)));
+        assert_eq!("Minus second", sentence.format(&Ordinal(-2)));
+        assert_eq!("21st", sentence.format(&Ordinal(21)));
+
+        let german = OrdinalFormatter::new(Locale::German)
+            .style(StylePreset::Ap)
+            .gender(Gender::Feminine);
+        assert_eq!("dritte", german.format(&Ordinal(3)));
+        assert_eq!("21.", german.format(&Ordinal(21)));
+        assert_eq!("-3.", german.format(&Ordinal(-3)));
+
+        // Locales without word forms keep their numerals.
+        let dutch = OrdinalFormatter::new(Locale::Dutch).style(StylePreset::Ap);
+        assert_eq!(
+            Ordinal(3).to_string_with_locale(Locale::Dutch),
+            dutch.format(&Ordinal(3))
+        );
+    }
+
+    #[test]
+    fn test_gender_switches_across_locales() {
+        let expected: [(Locale, [&str; 3]); 3] = [
+            (Locale::French, ["1er", "1re", "1er"]),
+            (Locale::Russian, ["1-й", "1-я", "1-е"]),
+            (Locale::Greek, ["1ος", "1η", "1ο"]),
+        ];
+        let genders = [Gender::Masculine, Gender::Feminine, Gender::Neuter];
+
+        for (locale, forms) in expected {
+            for (gender, form) in genders.iter().zip(forms) {
+                let options = OrdinalOptions::new().gender(*gender);
+                assert_eq!(
+                    form,
+                    Ordinal(1).to_string_with_locale_opts(locale, &options)
+                );
+                assert_eq!(
+                    form,
+                    OrdinalFormatter::new(locale)
+                        .gender(*gender)
+                        .format(&Ordinal(1))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_gender_ignored() {
+        for gender in [
+            Gender::Masculine,
+            Gender::Feminine,
+            Gender::Neuter,
+            Gender::Common,
+        ] {
+            let options = OrdinalOptions::new().gender(gender);
+            assert_eq!(
+                "21st",
+                Ordinal(21).to_string_with_locale_opts(Locale::English, &options)
+            );
+            assert_eq!(
+                "21.",
+                OrdinalFormatter::new(Locale::Finnish)
+                    .options(options)
+                    .format(&Ordinal(21))
+            );
+        }
+    }
+}
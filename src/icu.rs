@@ -0,0 +1,190 @@
+//! Evaluation of ICU MessageFormat `selectordinal` branches, for driving existing
+//! message catalogs without a full MessageFormat engine:
+//! `{n, selectordinal, one {#st} two {#nd} few {#rd} other {#th}}`.
+
+use crate::{Locale, Number, Ordinal, OrdinalCategory, OrdinalInteger, OrdinalOptions};
+use std::collections::HashMap;
+
+/// Picks the branch for the ordinal's category in `locale`, falling back to `other`
+/// like ICU does, and replaces each `#` in it with the number, grouped with the
+/// separator set by [`OrdinalOptions::grouping`]. Returns `None` if neither branch exists.
+/// ```rust
+/// use ordinal_type::icu::apply_selectordinal;
+/// use ordinal_type::{Locale, Ordinal, OrdinalCategory, OrdinalOptions};
+/// use std::collections::HashMap;
+///
+/// let branches = HashMap::from([
+///     (OrdinalCategory::One, "#st".to_string()),
+///     (OrdinalCategory::Two, "#nd".to_string()),
+///     (OrdinalCategory::Few, "#rd".to_string()),
+///     (OrdinalCategory::Other, "#th".to_string()),
+/// ]);
+/// let options = OrdinalOptions::new();
+/// let message = apply_selectordinal(&Ordinal(22), Locale::English, &options, &branches);
+/// assert_eq!(message.as_deref(), Some("22nd"));
+/// ```
+pub fn apply_selectordinal<T>(
+    ordinal: &Ordinal<T>,
+    locale: Locale,
+    options: &OrdinalOptions,
+    branches: &HashMap<OrdinalCategory, String>,
+) -> Option<String>
+where
+    T: OrdinalInteger,
+{
+    let branch = branches
+        .get(&ordinal.plural_category(locale))
+        .or_else(|| branches.get(&OrdinalCategory::Other))?;
+    let number = Number::new(&ordinal.0);
+    let formatted = match options.grouping {
+        Some(separator) => group(&number, separator),
+        None => number.to_string(),
+    };
+    Some(branch.replace('#', &formatted))
+}
+
+fn group(number: &Number, separator: char) -> String {
+    let digits = number.digits();
+    let mut grouped = String::new();
+    if number.is_negative() {
+        grouped.push('-');
+    }
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push(separator);
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::apply_selectordinal;
+    use crate::{Locale, Ordinal, OrdinalCategory, OrdinalOptions};
+    use std::collections::HashMap;
+
+    fn branches(branches: &[(OrdinalCategory, &str)]) -> HashMap<OrdinalCategory, String> {
+        branches
+            .iter()
+            .map(|(category, branch)| (*category, branch.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_selectordinal_english() {
+        let english = branches(&[
+            (OrdinalCategory::One, "#st"),
+            (OrdinalCategory::Two, "#nd"),
+            (OrdinalCategory::Few, "#rd"),
+            (OrdinalCategory::Other, "#th"),
+        ]);
+        let options = OrdinalOptions::new();
+
+        for i in -200..=200 {
+            assert_eq!(
+                Some(Ordinal(i).to_string()),
+                apply_selectordinal(&Ordinal(i), Locale::English, &options, &english)
+            );
+        }
+        assert_eq!(
+            Some("1,234,567th".to_string()),
+            apply_selectordinal(
+                &Ordinal(1_234_567),
+                Locale::English,
+                &options.clone().grouping(','),
+                &english
+            )
+        );
+        assert_eq!(
+            Some("-1 001st".to_string()),
+            apply_selectordinal(
+                &Ordinal(-1001),
+                Locale::English,
+                &options.grouping(' '),
+                &english
+            )
+        );
+    }
+
+    #[test]
+    fn test_selectordinal_welsh() {
+        let welsh = branches(&[
+            (OrdinalCategory::Zero, "zero #"),
+            (OrdinalCategory::One, "one #"),
+            (OrdinalCategory::Two, "two #"),
+            (OrdinalCategory::Few, "few #"),
+            (OrdinalCategory::Many, "many #"),
+            (OrdinalCategory::Other, "other #"),
+        ]);
+        let options = OrdinalOptions::new();
+        let expected = [
+            (0, "zero 0"),
+            (1, "one 1"),
+            (2, "two 2"),
+            (3, "few 3"),
+            (4, "few 4"),
+            (5, "many 5"),
+            (6, "many 6"),
+            (7, "zero 7"),
+            (10, "other 10"),
+        ];
+
+        for (n, message) in expected {
+            assert_eq!(
+                Some(message.to_string()),
+                apply_selectordinal(&Ordinal(n), Locale::Welsh, &options, &welsh)
+            );
+        }
+    }
+
+    #[test]
+    fn test_selectordinal_french() {
+        let french = branches(&[
+            (OrdinalCategory::One, "#er"),
+            (OrdinalCategory::Other, "#e"),
+        ]);
+        let options = OrdinalOptions::new();
+
+        for n in [0, 1, 2, 11, 21, 101] {
+            assert_eq!(
+                Some(Ordinal(n).to_string_with_locale(Locale::French)),
+                apply_selectordinal(&Ordinal(n), Locale::French, &options, &french)
+            );
+        }
+    }
+
+    #[test]
+    fn test_selectordinal_catalan() {
+        let catalan = branches(&[
+            (OrdinalCategory::One, "#r"),
+            (OrdinalCategory::Two, "#n"),
+            (OrdinalCategory::Few, "#t"),
+            (OrdinalCategory::Other, "#è"),
+        ]);
+        let options = OrdinalOptions::new();
+
+        for n in 0..=25 {
+            assert_eq!(
+                Some(Ordinal(n).to_string_with_locale(Locale::Catalan)),
+                apply_selectordinal(&Ordinal(n), Locale::Catalan, &options, &catalan)
+            );
+        }
+    }
+
+    #[test]
+    fn test_selectordinal_missing_branch() {
+        let options = OrdinalOptions::new();
+        let other_only = branches(&[(OrdinalCategory::Other, "#.")]);
+        assert_eq!(
+            Some("1.".to_string()),
+            apply_selectordinal(&Ordinal(1), Locale::English, &options, &other_only)
+        );
+
+        let no_other = branches(&[(OrdinalCategory::One, "#st")]);
+        assert_eq!(
+            None,
+            apply_selectordinal(&Ordinal(2), Locale::English, &options, &no_other)
+        );
+    }
+}
@@ -0,0 +1,289 @@
+//! The integer types an `Ordinal` can hold.
+//!
+//! The primitive integers are supported directly. With the default-on `num` feature,
+//! both traits are instead implemented for every type with the matching `num` traits,
+//! which covers the primitives as well as `BigInt` and `BigUint`.
+
+use alloc::vec::Vec;
+use core::fmt::{self, Display};
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Integers an `Ordinal` can format.
+///
+/// Implemented for the primitive integers and, with the `num` feature, for every
+/// `num_integer::Integer` that is also `Display`, `ToPrimitive` and `Clone`.
+/// This trait is sealed.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't be formatted as an ordinal",
+    label = "not an integer `Ordinal` can format",
+    note = "`Ordinal` formats the primitive integers, and with the `num` feature any `num_integer::Integer`"
+)]
+pub trait OrdinalInteger: Display + Clone + private::Sealed {
+    /// Returns the value of the last two decimal digits, ignoring the sign.
+    fn last_two(&self) -> u8;
+
+    /// Writes the decimal digits, with a `-` in front of negative values, exactly as
+    /// `Display` does. With the `fast-fmt` feature, values that fit in 128 bits are
+    /// written with itoa.
+    fn write_digits<W: fmt::Write + ?Sized>(&self, out: &mut W) -> fmt::Result {
+        write!(out, "{}", self)
+    }
+
+    /// Returns whether the value is below zero.
+    fn is_negative(&self) -> bool;
+
+    /// Returns the value if it's non-negative and fits in a `u128`.
+    fn to_u128_checked(&self) -> Option<u128>;
+
+    /// Returns the digits of the absolute value in `radix`, from 2 to 36, least
+    /// significant first. Zero has the single digit 0.
+    fn radix_digits(&self, radix: u32) -> Vec<u8>;
+}
+
+/// Returns the digits of `value` in `radix`, least significant first.
+fn u128_radix_digits(mut value: u128, radix: u32) -> Vec<u8> {
+    let radix = u128::from(radix);
+    let mut digits = Vec::new();
+    loop {
+        digits.push((value % radix) as u8);
+        value /= radix;
+        if value == 0 {
+            return digits;
+        }
+    }
+}
+
+/// Integers an ordinal can be parsed into, where the value isn't read from a string.
+///
+/// Implemented for the primitive integers and, with the `num` feature, for every
+/// `num_traits::FromPrimitive`. This trait is sealed.
+pub trait FromInteger: Sized + private::Sealed {
+    /// Returns the value, or `None` if it doesn't fit.
+    fn from_u128(value: u128) -> Option<Self>;
+
+    /// Returns the value, or `None` if it doesn't fit.
+    fn from_i128(value: i128) -> Option<Self>;
+
+    /// Returns the value of a whole float, or `None` if it doesn't fit.
+    fn from_f64(value: f64) -> Option<Self>;
+}
+
+/// Whether `value` is finite and whole, without `f64::fract`, which needs std. Every
+/// float from `2^52` up is whole.
+#[cfg(any(not(feature = "num"), feature = "serde"))]
+pub(crate) fn is_whole(value: f64) -> bool {
+    const TWO_POW_52: f64 = 4_503_599_627_370_496.0;
+    value.is_finite()
+        && (!(-TWO_POW_52..TWO_POW_52).contains(&value) || value as i64 as f64 == value)
+}
+
+#[cfg(feature = "num")]
+mod blanket {
+    use super::{private::Sealed, u128_radix_digits, FromInteger, OrdinalInteger};
+    use crate::locale::Number;
+    use alloc::vec::Vec;
+    use core::fmt::Display;
+    use num_integer::Integer;
+    use num_traits::{FromPrimitive, Num, ToPrimitive};
+
+    impl<T> Sealed for T {}
+
+    impl<T> OrdinalInteger for T
+    where
+        T: Integer + Display + ToPrimitive + Clone,
+    {
+        /// Values that fit in an `i128` or a `u128` are reduced arithmetically without
+        /// allocating. Only wider values fall back to rendering the digits into a `String`.
+        fn last_two(&self) -> u8 {
+            if let Some(value) = self.to_u128() {
+                (value % 100) as u8
+            } else if let Some(value) = self.to_i128() {
+                (value.unsigned_abs() % 100) as u8
+            } else {
+                Number::new(self).last_two()
+            }
+        }
+
+        #[cfg(feature = "fast-fmt")]
+        fn write_digits<W: core::fmt::Write + ?Sized>(&self, out: &mut W) -> core::fmt::Result {
+            let mut buffer = itoa::Buffer::new();
+            if let Some(value) = self.to_u64() {
+                out.write_str(buffer.format(value))
+            } else if let Some(value) = self.to_i64() {
+                out.write_str(buffer.format(value))
+            } else if let Some(value) = self.to_u128() {
+                out.write_str(buffer.format(value))
+            } else if let Some(value) = self.to_i128() {
+                out.write_str(buffer.format(value))
+            } else {
+                write!(out, "{}", self)
+            }
+        }
+
+        fn is_negative(&self) -> bool {
+            *self < T::zero()
+        }
+
+        fn to_u128_checked(&self) -> Option<u128> {
+            self.to_u128()
+        }
+
+        /// Values that fit in an `i128` or a `u128` are converted with `u128` arithmetic,
+        /// and only wider ones are divided down in `T`.
+        fn radix_digits(&self, radix: u32) -> Vec<u8> {
+            if let Some(value) = self.to_u128() {
+                return u128_radix_digits(value, radix);
+            } else if let Some(value) = self.to_i128() {
+                return u128_radix_digits(value.unsigned_abs(), radix);
+            }
+            let radix = <T as Num>::from_str_radix("10", radix)
+                .ok()
+                .expect("radix from 2 to 36");
+            let mut digits = Vec::new();
+            let mut value = self.clone();
+            loop {
+                let (quotient, digit) = value.div_rem(&radix);
+                digits.push(digit.to_i64().map_or(0, |digit| digit.unsigned_abs() as u8));
+                if quotient.is_zero() {
+                    return digits;
+                }
+                value = quotient;
+            }
+        }
+    }
+
+    impl<T: FromPrimitive> FromInteger for T {
+        fn from_u128(value: u128) -> Option<Self> {
+            <T as FromPrimitive>::from_u128(value)
+        }
+
+        fn from_i128(value: i128) -> Option<Self> {
+            <T as FromPrimitive>::from_i128(value)
+        }
+
+        fn from_f64(value: f64) -> Option<Self> {
+            <T as FromPrimitive>::from_f64(value)
+        }
+    }
+}
+
+#[cfg(not(feature = "num"))]
+mod primitive {
+    use super::{is_whole, private::Sealed, u128_radix_digits, FromInteger, OrdinalInteger};
+    use alloc::vec::Vec;
+
+    /// `2^128`, the first whole float past `u128::MAX`.
+    const TWO_POW_128: f64 = 340_282_366_920_938_463_463_374_607_431_768_211_456.0;
+
+    macro_rules! impl_integer {
+        (|$value:ident| $magnitude:expr, |$sign:pat_param| $negative:expr; $($t:ty),*) => {
+            $(
+                impl Sealed for $t {}
+
+                impl OrdinalInteger for $t {
+                    fn last_two(&self) -> u8 {
+                        let $value = *self;
+                        ($magnitude % 100) as u8
+                    }
+
+                    fn is_negative(&self) -> bool {
+                        let $sign = *self;
+                        $negative
+                    }
+
+                    fn to_u128_checked(&self) -> Option<u128> {
+                        u128::try_from(*self).ok()
+                    }
+
+                    fn radix_digits(&self, radix: u32) -> Vec<u8> {
+                        let $value = *self;
+                        u128_radix_digits($magnitude as u128, radix)
+                    }
+
+                    #[cfg(feature = "fast-fmt")]
+                    fn write_digits<W: core::fmt::Write + ?Sized>(
+                        &self,
+                        out: &mut W,
+                    ) -> core::fmt::Result {
+                        out.write_str(itoa::Buffer::new().format(*self))
+                    }
+                }
+
+                impl FromInteger for $t {
+                    fn from_u128(value: u128) -> Option<Self> {
+                        <$t>::try_from(value).ok()
+                    }
+
+                    fn from_i128(value: i128) -> Option<Self> {
+                        <$t>::try_from(value).ok()
+                    }
+
+                    fn from_f64(value: f64) -> Option<Self> {
+                        if !is_whole(value) || !(-TWO_POW_128..TWO_POW_128).contains(&value) {
+                            None
+                        } else if value < 0.0 {
+                            // Floats below `i128::MIN` saturate to it, so check the round trip.
+                            let whole = value as i128;
+                            (whole as f64 == value).then(|| Self::from_i128(whole)).flatten()
+                        } else {
+                            Self::from_u128(value as u128)
+                        }
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_integer!(|value| value, |_| false; u8, u16, u32, u64, u128, usize);
+    impl_integer!(|value| value.unsigned_abs(), |value| value < 0; i8, i16, i32, i64, i128, isize);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Ordinal, OrdinalInteger};
+    use rand_chacha::rand_core::{RngCore, SeedableRng};
+    use rand_chacha::ChaCha8Rng;
+
+    fn assert_same_digits<T: OrdinalInteger>(value: T) {
+        let mut digits = String::new();
+        value.write_digits(&mut digits).unwrap();
+        assert_eq!(value.to_string(), digits);
+        let mut ordinal = String::new();
+        Ordinal(value.clone()).write_to(&mut ordinal).unwrap();
+        assert_eq!(
+            format!("{}{}", value, Ordinal(value.clone()).suffix()),
+            ordinal
+        );
+    }
+
+    #[test]
+    fn test_write_digits_matches_display() {
+        let mut rng = ChaCha8Rng::seed_from_u64(7);
+        for _ in 0..100_000 {
+            let seed = rng.next_u64();
+            let wide = (seed as u128) << 64 | rng.next_u64() as u128;
+            assert_same_digits(seed as u32);
+            assert_same_digits(seed as i64);
+            assert_same_digits(seed as i8);
+            assert_same_digits(seed as usize);
+            assert_same_digits(wide);
+            assert_same_digits(wide as i128);
+        }
+        assert_same_digits(i64::MIN);
+        assert_same_digits(i128::MIN);
+        assert_same_digits(u128::MAX);
+    }
+
+    #[cfg(feature = "num")]
+    #[test]
+    fn test_write_digits_big() {
+        use num_bigint::{BigInt, BigUint};
+
+        assert_same_digits(BigUint::from(u64::MAX) + 1u8);
+        assert_same_digits(-BigInt::from(u128::MAX) * 1000);
+        assert_same_digits(BigInt::from(-42));
+    }
+}
@@ -0,0 +1,126 @@
+//! `Sum` and `Product` for `Ordinal`, delegating to the inner type, so overflow behaves
+//! exactly as it does when summing the integers themselves.
+
+use crate::Ordinal;
+use core::iter::{Product, Sum};
+use core::ops::Div;
+
+impl<T: Sum> Sum for Ordinal<T> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Ordinal(iter.map(|ordinal| ordinal.0).sum())
+    }
+}
+
+impl<'a, T: Sum<&'a T> + 'a> Sum<&'a Ordinal<T>> for Ordinal<T> {
+    fn sum<I: Iterator<Item = &'a Ordinal<T>>>(iter: I) -> Self {
+        Ordinal(iter.map(|ordinal| &ordinal.0).sum())
+    }
+}
+
+impl<T: Product> Product for Ordinal<T> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Ordinal(iter.map(|ordinal| ordinal.0).product())
+    }
+}
+
+impl<'a, T: Product<&'a T> + 'a> Product<&'a Ordinal<T>> for Ordinal<T> {
+    fn product<I: Iterator<Item = &'a Ordinal<T>>>(iter: I) -> Self {
+        Ordinal(iter.map(|ordinal| &ordinal.0).product())
+    }
+}
+
+impl<T> Ordinal<T>
+where
+    T: Sum + Div<Output = T> + TryFrom<usize>,
+{
+    /// Returns the mean of the ordinals, or `None` if there are none or their count
+    /// doesn't fit in `T`. For integers the mean is truncated towards zero, as integer
+    /// division is, and the sum overflows as summing the integers would.
+    /// ```rust
+    /// use ordinal_type::Ordinal;
+    ///
+    /// let places = [Ordinal(1u32), Ordinal(2), Ordinal(4)];
+    /// assert_eq!(Ordinal::mean(places), Some(Ordinal(2)));
+    /// assert_eq!(Ordinal::<u32>::mean([]), None);
+    /// ```
+    pub fn mean<I: IntoIterator<Item = Ordinal<T>>>(ordinals: I) -> Option<Ordinal<T>> {
+        let mut count = 0usize;
+        let sum: T = ordinals
+            .into_iter()
+            .inspect(|_| count += 1)
+            .map(|ordinal| ordinal.0)
+            .sum();
+        if count == 0 {
+            return None;
+        }
+        Some(Ordinal(sum / T::try_from(count).ok()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Ordinal;
+
+    #[test]
+    fn test_sum_and_product() {
+        let ranks = [Ordinal(1u64), Ordinal(2), Ordinal(4)];
+        assert_eq!(ranks.iter().copied().sum::<Ordinal<u64>>(), Ordinal(7));
+        assert_eq!(ranks.iter().sum::<Ordinal<u64>>(), Ordinal(7));
+        assert_eq!(ranks.iter().copied().product::<Ordinal<u64>>(), Ordinal(8));
+        assert_eq!(ranks.iter().product::<Ordinal<u64>>(), Ordinal(8));
+        assert_eq!(ranks.iter().sum::<Ordinal<u64>>().to_string(), "7th");
+    }
+
+    #[test]
+    fn test_empty() {
+        let empty: [Ordinal<i32>; 0] = [];
+        assert_eq!(empty.iter().sum::<Ordinal<i32>>(), Ordinal(0));
+        assert_eq!(empty.iter().product::<Ordinal<i32>>(), Ordinal(1));
+        assert_eq!(Ordinal::mean(empty), None);
+    }
+
+    #[test]
+    fn test_mean_truncates() {
+        assert_eq!(Ordinal::mean([Ordinal(1), Ordinal(2)]), Some(Ordinal(1)));
+        assert_eq!(Ordinal::mean([Ordinal(-1), Ordinal(-2)]), Some(Ordinal(-1)));
+        assert_eq!(Ordinal::mean([Ordinal(7i8)]), Some(Ordinal(7)));
+        // 256 ordinals can't be counted in a `u8`.
+        assert_eq!(Ordinal::mean([Ordinal(0u8); 256]), None);
+    }
+
+    #[test]
+    fn test_overflow_matches_integers() {
+        let integers = std::panic::catch_unwind(|| [200u8, 100].iter().sum::<u8>());
+        let ordinals =
+            std::panic::catch_unwind(|| [Ordinal(200u8), Ordinal(100)].iter().sum::<Ordinal<u8>>());
+        assert_eq!(integers.is_err(), ordinals.is_err());
+        let wrapped = [
+            Ordinal(std::num::Wrapping(200u8)),
+            Ordinal(std::num::Wrapping(100)),
+        ];
+        assert_eq!(
+            wrapped.iter().sum::<Ordinal<_>>(),
+            Ordinal(std::num::Wrapping(44))
+        );
+    }
+
+    #[cfg(feature = "num")]
+    #[test]
+    fn test_big() {
+        use num_bigint::BigInt;
+
+        let big = [
+            Ordinal(BigInt::from(u128::MAX)),
+            Ordinal(BigInt::from(u128::MAX)),
+        ];
+        assert_eq!(
+            big.iter().sum::<Ordinal<BigInt>>(),
+            Ordinal(BigInt::from(u128::MAX) * 2)
+        );
+        assert_eq!(
+            big.iter().product::<Ordinal<BigInt>>().0,
+            BigInt::from(u128::MAX).pow(2)
+        );
+        assert_eq!(Ordinal::mean(big), Some(Ordinal(BigInt::from(u128::MAX))));
+    }
+}
@@ -24,26 +24,213 @@
 //! assert_eq!(ordinal.to_string(), "1st");
 //! ```
 //!
+//! ## Allocation
+//!
+//! For every primitive integer, [`Ordinal::suffix`], `Display`, [`Ordinal::write_to`],
+//! [`Ordinal::format_into`] and the [`Ordinal::words`], [`Ordinal::superscript`] and
+//! [`Ordinal::html`] adaptors never allocate, nor does [`Ordinal::parse_partial`] when it
+//! succeeds. Only inner values wider than 128 bits, like a large `BigInt`, are rendered
+//! through a `String` to find their last two digits.
+//!
+//! Methods that return a `String`, like [`Ordinal::to_string_with_locale`], allocate of
+//! course. Any other method that allocates says so in its docs.
+//!
+//! ```rust
+//! use ordinal_type::Ordinal;
+//!
+//! let mut buf = [0; 24];
+//! assert_eq!(Ordinal(u64::MAX).format_into(&mut buf).unwrap(), "18446744073709551615th");
+//! ```
+//!
+//! ## Layout
+//!
+//! `Ordinal<T>` is `#[repr(transparent)]`, so it has exactly the size, alignment and ABI
+//! of `T`. This is part of the public API and won't change in a minor release.
+//! [`Ordinal::wrap_slice`] and [`Ordinal::peel_slice`] rely on it to view a `&[T]` as a
+//! `&[Ordinal<T>]` and back without copying, and the `bytemuck` feature implements
+//! `Zeroable`, `Pod` and `TransparentWrapper` on top of it.
+//!
+//! ```rust
+//! use ordinal_type::Ordinal;
+//!
+//! let ranks = [1u32, 2, 3];
+//! assert_eq!(Ordinal::wrap_slice(&ranks)[1].to_string(), "2nd");
+//! ```
+//!
+//! ## `no_std`
+//!
+//! Without the default `std` feature the crate is `no_std` and only needs `alloc`, for
+//! targets like Cortex-M. Formatting, words, locales and parsing all work there; the
+//! `icu` module, `Locale::from_env` and the integrations with std-only crates don't.
+//! The `defmt` and `ufmt` features are meant for such targets, and `serde` works there too.
+//!
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
+extern crate alloc;
+
+// Lets the `::ordinal_type` paths the derive macros emit resolve in this crate's tests.
+#[cfg(all(test, feature = "derive"))]
+extern crate self as ordinal_type;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impl;
+#[cfg(feature = "askama")]
+#[path = "askama_impl.rs"]
+pub mod askama;
+#[cfg(feature = "bytemuck")]
+mod bytemuck_impl;
+mod cached;
+#[cfg(feature = "compact_str")]
+mod compact_str_impl;
+pub mod date;
+#[cfg(feature = "defmt")]
+mod defmt_impl;
+#[cfg(feature = "diesel")]
+mod diesel_impl;
+mod display;
+mod error;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "fluent")]
+pub mod fluent;
+mod formatter;
+#[cfg(feature = "std")]
+pub mod icu;
+mod integer;
+mod iter;
+pub mod locale;
+mod parse;
+#[cfg(feature = "postgres")]
+mod postgres;
+#[cfg(feature = "primitive-types")]
+mod primitive_types_impl;
+#[cfg(feature = "proptest")]
+mod proptest_impl;
+#[cfg(feature = "python")]
+pub mod python;
+mod radix;
+#[cfg(feature = "rand")]
+mod rand_impl;
+mod ranking;
+#[cfg(feature = "rayon")]
+mod rayon_impl;
+#[cfg(feature = "rusqlite")]
+mod rusqlite_impl;
+#[cfg(feature = "schemars")]
+mod schemars_impl;
+#[cfg(feature = "serde")]
+pub mod serde_flexible;
+#[cfg(feature = "serde")]
+mod serde_impl;
+#[cfg(feature = "serde")]
+pub mod serde_str;
+#[cfg(feature = "serde")]
+pub mod serde_words;
+mod slice;
+#[cfg(feature = "sqlx")]
+mod sqlx_impl;
+mod table;
+mod text;
+#[cfg(feature = "ufmt")]
+mod ufmt_impl;
+#[cfg(feature = "utoipa")]
+mod utoipa_impl;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+#[cfg(feature = "chrono")]
+pub mod weekday;
+
+pub use cached::CachedOrdinal;
+pub use display::{Html, Superscript, Words};
+pub use error::Error;
+pub use formatter::{OrdinalFormatter, StylePreset};
+pub use integer::{FromInteger, OrdinalInteger};
+pub use locale::{
+    Case, Digits, Gender, GrammaticalNumber, Locale, LocaleUnsupported, OrdinalCategory,
+    OrdinalOptions, Script,
+};
+#[cfg(feature = "derive")]
+pub use ordinal_type_derive::{ordinal_format, VariantOrdinal};
+pub use parse::ParseOrdinalError;
+#[cfg(feature = "primitive-types")]
+pub use primitive_types_impl::{WideDisplay, WideOrdinal, WideOverflow};
+#[cfg(feature = "proptest")]
+pub use proptest_impl::{ordinal_in, OrdinalStrategy};
+#[cfg(feature = "rand")]
+pub use rand_impl::UniformOrdinal;
+pub use ranking::{
+    percentile_label, percentile_rank, rank_asc, rank_by_key, rank_dense_asc, rank_dense_by_key,
+    rank_dense_desc, rank_desc, rank_fractional, rank_with, EmptyData, FractionalRank,
+    PercentileMethod, RankingMethod,
+};
+#[cfg(all(feature = "rayon", feature = "compact_str"))]
+pub use rayon_impl::par_format_all_compact;
+#[cfg(feature = "rayon")]
+pub use rayon_impl::{par_format_all, par_format_join, par_format_join_with};
+pub use text::{
+    find_ordinals, ordinalize_text, strip_ordinals, strip_ordinals_with, OrdinalForm, OrdinalMatch,
+    OrdinalizeOptions, StripOptions,
+};
+
+use alloc::string::String;
+use core::fmt::{self, Display, Formatter};
+use locale::Number;
+#[cfg(feature = "num")]
 use num_integer::Integer;
+#[cfg(feature = "num")]
 use num_traits::ToPrimitive;
-use std::fmt::{self, Display, Formatter};
 
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::SmallInt),
+    diesel(sql_type = diesel::sql_types::Integer),
+    diesel(sql_type = diesel::sql_types::BigInt)
+)]
+#[repr(transparent)]
 pub struct Ordinal<T>(pub T);
 
+/// `Ordinal` only implements `Serialize` with the `serde` feature enabled:
+///
+#[cfg_attr(not(feature = "serde"), doc = "```compile_fail")]
+#[cfg_attr(feature = "serde", doc = "```")]
+/// fn assert_serialize<T: serde::Serialize>() {}
+/// assert_serialize::<ordinal_type::Ordinal<u32>>();
+/// ```
+#[cfg(doctest)]
+struct SerdeFeature;
+
+/// Without the default `num` feature, `Ordinal` only formats the primitive integers:
+///
+/// ```rust
+/// assert_eq!(ordinal_type::Ordinal(-12i8).to_string(), "-12th");
+/// assert_eq!(ordinal_type::Ordinal(u128::MAX).suffix(), "th");
+/// ```
+///
+/// With it, any `num_integer::Integer` like `BigInt` works too:
+///
+#[cfg_attr(not(feature = "num"), doc = "```compile_fail")]
+#[cfg_attr(feature = "num", doc = "```")]
+/// let big = num_bigint::BigInt::from(u128::MAX) * 10;
+/// assert_eq!(ordinal_type::Ordinal(big).to_string(), "3402823669209384634633746074317682114550th");
+/// ```
+#[cfg(doctest)]
+struct NumFeature;
+
 impl<T> Display for Ordinal<T>
 where
-    T: Integer + Display + ToPrimitive + Clone,
+    T: OrdinalInteger,
 {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "{}{}", self.0, self.suffix())
+        self.write_to(f)
     }
 }
 
 impl<T> Ordinal<T>
 where
-    T: Integer + Display + ToPrimitive + Clone,
+    T: OrdinalInteger,
 {
     /// Returns the suffix of the ordinal number.
     /// For example, `1` returns `"st"`, `2` returns `"nd"`, `3` returns `"rd"`, and `4` returns `"th"`.
@@ -55,23 +242,128 @@ where
     /// assert_eq!(ordinal.suffix(), "st");
     /// ```
     pub fn suffix(&self) -> &'static str {
-        let last_digits: String = self.0.to_string();
-        if last_digits.ends_with('1') && !last_digits.ends_with("11") {
-            "st"
-        } else if last_digits.ends_with('2') && !last_digits.ends_with("12") {
-            "nd"
-        } else if last_digits.ends_with('3') && !last_digits.ends_with("13") {
-            "rd"
-        } else {
-            "th"
-        }
+        locale::english::suffix(self.0.last_two())
+    }
+
+    /// Writes the ordinal number to `out`, as `Display` does.
+    /// ```rust
+    /// use ordinal_type::Ordinal;
+    ///
+    /// let mut out = String::from("You came ");
+    /// Ordinal(2).write_to(&mut out).unwrap();
+    /// assert_eq!(out, "You came 2nd");
+    /// ```
+    pub fn write_to<W: fmt::Write + ?Sized>(&self, out: &mut W) -> fmt::Result {
+        self.0.write_digits(out)?;
+        out.write_str(self.suffix())
+    }
+
+    /// Formats the ordinal number into `buf` and returns the written part of it.
+    /// Returns an error if `buf` is too short.
+    /// ```rust
+    /// use ordinal_type::Ordinal;
+    ///
+    /// let mut buf = [0; 8];
+    /// assert_eq!(Ordinal(-13).format_into(&mut buf).unwrap(), "-13th");
+    /// assert!(Ordinal(1_234_567).format_into(&mut buf).is_err());
+    /// ```
+    pub fn format_into<'a>(&self, buf: &'a mut [u8]) -> Result<&'a str, fmt::Error> {
+        let mut writer = SliceWriter { buf, len: 0 };
+        self.write_to(&mut writer)?;
+        let SliceWriter { buf, len } = writer;
+        Ok(core::str::from_utf8(&buf[..len]).expect("only whole strs are written"))
+    }
+
+    /// Formats the ordinal number using the conventions of the given locale.
+    /// ```rust
+    /// use ordinal_type::{Locale, Ordinal};
+    ///
+    /// let ordinal = Ordinal(3);
+    /// assert_eq!(ordinal.to_string_with_locale(Locale::Spanish), "3.º");
+    /// ```
+    pub fn to_string_with_locale(&self, locale: Locale) -> String {
+        self.to_string_with_locale_opts(locale, &OrdinalOptions::default())
+    }
+
+    /// Formats the ordinal number using the conventions of the given locale and options.
+    /// Options that don't apply to the locale are ignored.
+    /// ```rust
+    /// use ordinal_type::{Gender, Locale, Ordinal, OrdinalOptions};
+    ///
+    /// let options = OrdinalOptions::new().gender(Gender::Feminine).period(false);
+    /// let ordinal = Ordinal(3);
+    /// assert_eq!(ordinal.to_string_with_locale_opts(Locale::Spanish, &options), "3ª");
+    /// ```
+    pub fn to_string_with_locale_opts(&self, locale: Locale, options: &OrdinalOptions) -> String {
+        locale.format(&Number::new(&self.0), options)
+    }
+
+    /// Returns the CLDR plural category of the ordinal number in the given locale.
+    /// Many locales have only [`OrdinalCategory::Other`], so every value gets that.
+    /// Esperanto and Latin aren't in CLDR and use its root rule, which is also `Other`.
+    /// ```rust
+    /// use ordinal_type::{Locale, Ordinal, OrdinalCategory};
+    ///
+    /// assert_eq!(Ordinal(22).plural_category(Locale::English), OrdinalCategory::Two);
+    /// assert_eq!(Ordinal(5).plural_category(Locale::Welsh), OrdinalCategory::Many);
+    /// ```
+    pub fn plural_category(&self, locale: Locale) -> OrdinalCategory {
+        locale.category(&Number::new(&self.0))
+    }
+
+    /// Spells out the ordinal number in the given locale.
+    /// Returns an error if the locale has no word forms or can't spell this value;
+    /// unlike formatting, this never falls back to another locale.
+    /// ```rust
+    /// use ordinal_type::{Gender, Locale, Ordinal, OrdinalOptions};
+    ///
+    /// let options = OrdinalOptions::new().gender(Gender::Neuter);
+    /// let ordinal = Ordinal(3);
+    /// assert_eq!(ordinal.to_words_locale(Locale::German, &options).unwrap(), "drittes");
+    /// ```
+    pub fn to_words_locale(
+        &self,
+        locale: Locale,
+        options: &OrdinalOptions,
+    ) -> Result<String, LocaleUnsupported> {
+        locale.words(&Number::new(&self.0), options)
+    }
+
+    /// Spells out the ordinal number in English.
+    /// Returns an error for negative values and values that don't fit in a `u128`.
+    /// ```rust
+    /// use ordinal_type::Ordinal;
+    ///
+    /// assert_eq!(Ordinal(22).to_words().unwrap(), "twenty-second");
+    /// assert_eq!(Ordinal(101).to_words().unwrap(), "one hundred first");
+    /// ```
+    pub fn to_words(&self) -> Result<String, LocaleUnsupported> {
+        self.to_words_locale(Locale::English, &OrdinalOptions::default())
+    }
+
+    /// Renders the ordinal once and keeps the text, for values displayed many times.
+    /// This allocates the text.
+    /// ```rust
+    /// use ordinal_type::Ordinal;
+    ///
+    /// let cached = Ordinal(3).cached();
+    /// assert_eq!(cached.to_string(), "3rd");
+    /// ```
+    pub fn cached(self) -> CachedOrdinal<T> {
+        CachedOrdinal::new(self)
     }
 
     /// Returns the primitive value of the ordinal number.
     pub fn to_primitive(&self) -> T {
         self.0.clone()
     }
+}
 
+#[cfg(feature = "num")]
+impl<T> Ordinal<T>
+where
+    T: Integer + Display + ToPrimitive + Clone,
+{
     /// Returns the primitive value of the ordinal number as a `u8`.
     pub fn to_u8(&self) -> u8 {
         self.0.to_u8().expect("Failed to convert to u8")
@@ -133,6 +425,24 @@ where
     }
 }
 
+/// A `fmt::Write` over a byte slice, for [`Ordinal::format_into`].
+struct SliceWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl fmt::Write for SliceWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.buf
+            .get_mut(self.len..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
 pub trait ToOrdinal {
     fn to_ordinal(&self) -> Ordinal<Self>
     where
@@ -153,10 +463,140 @@ macro_rules! impl_to_ordinal_for_integers {
 
 impl_to_ordinal_for_integers!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
 
+/// Support for the macros in `ordinal-type-derive`, not part of the public API.
+#[cfg(feature = "derive")]
+#[doc(hidden)]
+pub mod __private {
+    use crate::{Ordinal, OrdinalInteger};
+    // Through here so the expansions also work in `no_std` crates.
+    pub use alloc::format;
+    pub use alloc::string::{String, ToString};
+
+    /// Formats an `ordinal_format!` argument. It's borrowed, as plain placeholders may
+    /// use it too.
+    pub fn ordinal_string<T: OrdinalInteger>(value: &T) -> String {
+        Ordinal(value.clone()).to_string()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{Ordinal, ToOrdinal};
+    #[cfg(feature = "num")]
     use num_bigint::{BigInt, BigUint};
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+    use std::fmt::{self, Write};
+    use std::hint::black_box;
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn test_derive_variant_ordinal() {
+        use crate::VariantOrdinal;
+
+        #[derive(VariantOrdinal)]
+        #[allow(dead_code)]
+        enum Round<T> {
+            Quarterfinal,
+            Semifinal(T),
+            Final { best_of: u8 },
+        }
+
+        assert_eq!(Ordinal(1), Round::<()>::Quarterfinal.variant_ordinal());
+        assert_eq!(Ordinal(2), Round::Semifinal("a").variant_ordinal());
+        assert_eq!(
+            "3rd",
+            Round::<()>::Final { best_of: 5 }.variant_ordinal_string()
+        );
+
+        #[derive(VariantOrdinal)]
+        #[allow(dead_code)]
+        enum Medal {
+            Gold = 1,
+            Silver,
+            Bronze,
+            Participant = 10,
+            Spectator,
+        }
+
+        let medals = [
+            Medal::Gold,
+            Medal::Silver,
+            Medal::Bronze,
+            Medal::Participant,
+            Medal::Spectator,
+        ];
+        let ordinals: Vec<String> = medals.iter().map(Medal::variant_ordinal_string).collect();
+        assert_eq!(["1st", "2nd", "3rd", "10th", "11th"], &ordinals[..]);
+
+        #[derive(VariantOrdinal)]
+        #[repr(u8)]
+        #[allow(dead_code)]
+        enum Slot<'a> {
+            Open,
+            Taken(&'a str) = 4 + 1,
+            Closed { reason: &'a str },
+        }
+
+        assert_eq!(Ordinal(1), Slot::Open.variant_ordinal());
+        assert_eq!(Ordinal(5), Slot::Taken("x").variant_ordinal());
+        assert_eq!(Ordinal(6), Slot::Closed { reason: "y" }.variant_ordinal());
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn test_ordinal_format() {
+        use crate::ordinal_format;
+
+        let place = 3;
+        let count = 41u64;
+        assert_eq!(
+            format!("You came {}, beating {} players", Ordinal(place), count),
+            ordinal_format!(
+                "You came {ord:ord}, beating {n} players",
+                ord = place,
+                n = count
+            )
+        );
+        assert_eq!(
+            format!("{}, {}", Ordinal(place), place),
+            ordinal_format!("{place:ord}, {place}")
+        );
+        assert_eq!(
+            format!("{} {} {}", 1, Ordinal(2), 3),
+            ordinal_format!("{} {:ord} {}", 1, 2, 3)
+        );
+        assert_eq!(
+            format!("{1} {0} {1}", Ordinal(11), 12),
+            ordinal_format!("{1} {0:ord} {1}", 11, 12)
+        );
+        let big = i128::MIN;
+        assert_eq!(
+            format!("[{}]", Ordinal(big)),
+            ordinal_format!("[{:ord}]", big)
+        );
+
+        // Width and alignment apply to the ordinal as a whole.
+        let ordinal = Ordinal(22).to_string();
+        assert_eq!(
+            format!(
+                "[{:>8}|{:<8}|{:^8}|{:*^9}]",
+                ordinal, ordinal, ordinal, ordinal
+            ),
+            ordinal_format!("[{0:>8ord}|{0:<8ord}|{0:^8ord}|{0:*^9ord}]", 22)
+        );
+        let width = 7;
+        assert_eq!(
+            format!("[{0:>1$}] [{0:>width$}]", ordinal, width),
+            ordinal_format!("[{:>1$ord}] [{0:>width$ord}]", 22, width)
+        );
+        assert_eq!(
+            format!("{:.*} {}", 2, 1.23456, Ordinal(4)),
+            ordinal_format!("{:.*} {:ord}", 2, 1.23456, 4)
+        );
+        assert_eq!("{1st} {}", ordinal_format!("{{{:ord}}} {{}}", 1));
+        assert_eq!("no placeholders", ordinal_format!("no placeholders"));
+    }
 
     #[test]
     fn test_positive_ordinals() {
@@ -373,8 +813,6 @@ mod tests {
             usize,
         ) = (1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1);
 
-        let big_types: (BigInt, BigUint) = (1_i8.into(), 1_u8.into());
-
         assert_eq!("1st", Ordinal(types.0).to_string());
         assert_eq!("1st", Ordinal(types.1).to_string());
         assert_eq!("1st", Ordinal(types.2).to_string());
@@ -387,11 +825,18 @@ mod tests {
         assert_eq!("1st", Ordinal(types.9).to_string());
         assert_eq!("1st", Ordinal(types.10).to_string());
         assert_eq!("1st", Ordinal(types.11).to_string());
+    }
+
+    #[cfg(feature = "num")]
+    #[test]
+    fn test_big_types() {
+        let big_types: (BigInt, BigUint) = (1_i8.into(), 1_u8.into());
 
         assert_eq!("1st", Ordinal(big_types.0).to_string());
         assert_eq!("1st", Ordinal(big_types.1).to_string());
     }
 
+    #[cfg(feature = "num")]
     #[test]
     fn test_to_primitive_method() {
         let types: (
@@ -479,4 +924,111 @@ mod tests {
         assert_eq!("1st", types.10.to_ordinal().to_string());
         assert_eq!("1st", types.11.to_ordinal().to_string());
     }
+
+    /// Counts the allocations made on the current thread, so tests running in parallel
+    /// don't disturb each other's counts.
+    struct CountingAllocator;
+
+    thread_local! {
+        static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    fn count_allocation() {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+    }
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            count_allocation();
+            System.alloc(layout)
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout)
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            count_allocation();
+            System.realloc(ptr, layout, new_size)
+        }
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    /// A `fmt::Write` that only counts the bytes written to it.
+    struct Sink(usize);
+
+    impl fmt::Write for Sink {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            self.0 += s.len();
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_primitives_do_not_allocate() {
+        let mut sink = Sink(0);
+        let mut buf = [0u8; 48];
+        let before = ALLOCATIONS.with(Cell::get);
+        for i in 0..1_000_000u64 {
+            let n = i.wrapping_mul(0x9e37_79b9_7f4a_7c15);
+            black_box(Ordinal(n as u8).suffix());
+            write!(sink, "{}", Ordinal(n as i16)).unwrap();
+            write!(sink, "{}", Ordinal(n as i32 as isize)).unwrap();
+            Ordinal(n).write_to(&mut sink).unwrap();
+            Ordinal(n as i64).write_to(&mut sink).unwrap();
+            black_box(
+                Ordinal(n as u128 * n as u128)
+                    .format_into(&mut buf)
+                    .unwrap(),
+            );
+            black_box(Ordinal(-(n as i128) * 7).format_into(&mut buf).unwrap());
+        }
+        assert_eq!(ALLOCATIONS.with(Cell::get), before);
+        assert!(sink.0 > 0);
+    }
+
+    #[test]
+    fn test_adaptors_do_not_allocate() {
+        let mut sink = Sink(0);
+        let before = ALLOCATIONS.with(Cell::get);
+        for i in 0..100_000u64 {
+            let n = i.wrapping_mul(0x9e37_79b9_7f4a_7c15);
+            write!(sink, "{}", Ordinal(n).words()).unwrap();
+            write!(sink, "{}", Ordinal(u128::from(n) << (i % 64)).words()).unwrap();
+            write!(sink, "{}", Ordinal(n as i32).words()).unwrap();
+            write!(sink, "{}", Ordinal(n as i64).superscript()).unwrap();
+            write!(sink, "{}", Ordinal(n as u16).html()).unwrap();
+        }
+        assert_eq!(ALLOCATIONS.with(Cell::get), before);
+        assert!(sink.0 > 0);
+    }
+
+    #[test]
+    fn test_parse_partial_does_not_allocate() {
+        let texts: Vec<String> = (0..1000u64)
+            .map(|i| format!("{} of June", Ordinal(i.wrapping_mul(0x9e37_79b9_7f4a_7c15))))
+            .collect();
+        let before = ALLOCATIONS.with(Cell::get);
+        for text in &texts {
+            let (ordinal, rest) = Ordinal::<u64>::parse_partial(text).unwrap();
+            black_box(ordinal);
+            assert_eq!(rest, " of June");
+            black_box(Ordinal::<u64>::parse_partial_at(text, 0).unwrap());
+        }
+        assert_eq!(ALLOCATIONS.with(Cell::get), before);
+    }
+
+    #[cfg(feature = "num")]
+    #[test]
+    fn test_wide_bigint_suffix() {
+        let big = BigUint::from(u128::MAX) * 1000u32 + 12u32;
+        assert_eq!(Ordinal(big).suffix(), "th");
+        let big = -(BigInt::from(u128::MAX) * 1000u32 + 22u32);
+        assert_eq!(
+            Ordinal(big).to_string(),
+            "-340282366920938463463374607431768211455022nd"
+        );
+    }
 }
@@ -0,0 +1,170 @@
+//! Arabic has no abbreviated ordinal: the numeral is written on its own, in Latin or
+//! Arabic-Indic digits depending on [`OrdinalOptions::digits`].
+//!
+//! Word forms agree in gender and are definite by default (`الأول`/`الأولى`); compounds
+//! put the unit first (`الحادي والعشرون`), and only the unit of a teen takes the article
+//! (`الحادي عشر`). Values from 1 to 99 are supported.
+//!
+//! Strings are in logical order; [`OrdinalOptions::direction_marks`] surrounds them with
+//! right-to-left marks for embedding in left-to-right text.
+
+use super::{bidi, digits, Gender, Number, OrdinalOptions};
+use alloc::format;
+use alloc::string::{String, ToString};
+
+const MASCULINE: [&str; 11] = [
+    "", "أول", "ثاني", "ثالث", "رابع", "خامس", "سادس", "سابع", "ثامن", "تاسع", "عاشر",
+];
+
+const FEMININE: [&str; 11] = [
+    "",
+    "أولى",
+    "ثانية",
+    "ثالثة",
+    "رابعة",
+    "خامسة",
+    "سادسة",
+    "سابعة",
+    "ثامنة",
+    "تاسعة",
+    "عاشرة",
+];
+
+const TENS: [&str; 10] = [
+    "",
+    "",
+    "عشرون",
+    "ثلاثون",
+    "أربعون",
+    "خمسون",
+    "ستون",
+    "سبعون",
+    "ثمانون",
+    "تسعون",
+];
+
+pub(crate) fn format(number: &Number, options: &OrdinalOptions) -> String {
+    let shaped = digits::shape(&number.to_string(), options.digits);
+    bidi::mark(shaped, options.direction_marks)
+}
+
+/// Spells out `n` for values from 1 to 99.
+pub(crate) fn words(n: u128, options: &OrdinalOptions) -> Option<String> {
+    if n == 0 || n >= 100 {
+        return None;
+    }
+    let n = n as usize;
+    let feminine = options.gender == Gender::Feminine;
+    let article = |word: &str| {
+        if options.definite {
+            format!("ال{}", word)
+        } else {
+            word.to_string()
+        }
+    };
+    let unit = |unit: usize| match (unit, feminine) {
+        (1, false) => "حادي",
+        (1, true) => "حادية",
+        (_, false) => MASCULINE[unit],
+        (_, true) => FEMININE[unit],
+    };
+    let words = match (n / 10, n % 10) {
+        (0, _) | (1, 0) if feminine => article(FEMININE[n]),
+        (0, _) | (1, 0) => article(MASCULINE[n]),
+        (1, rest) if feminine => format!("{} عشرة", article(unit(rest))),
+        (1, rest) => format!("{} عشر", article(unit(rest))),
+        (tens, 0) => article(TENS[tens]),
+        (tens, rest) => format!("{} و{}", article(unit(rest)), article(TENS[tens])),
+    };
+    Some(bidi::mark(words, options.direction_marks))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Digits, Gender, Locale, Ordinal, OrdinalOptions};
+
+    #[test]
+    fn test_arabic_digits() {
+        assert_eq!("123", Ordinal(123).to_string_with_locale(Locale::Arabic));
+
+        let options = OrdinalOptions::new().digits(Digits::ArabicIndic);
+        assert_eq!(
+            "\u{661}\u{662}\u{663}",
+            Ordinal(123).to_string_with_locale_opts(Locale::Arabic, &options)
+        );
+        assert_eq!(
+            "\u{200f}-\u{661}\u{662}\u{663}\u{200f}",
+            Ordinal(-123)
+                .to_string_with_locale_opts(Locale::Arabic, &options.direction_marks(true))
+        );
+    }
+
+    #[test]
+    fn test_arabic_words() {
+        let masculine: [&str; 12] = [
+            "الأول",
+            "الثاني",
+            "الثالث",
+            "الرابع",
+            "الخامس",
+            "السادس",
+            "السابع",
+            "الثامن",
+            "التاسع",
+            "العاشر",
+            "الحادي عشر",
+            "الثاني عشر",
+        ];
+        let feminine: [&str; 12] = [
+            "الأولى",
+            "الثانية",
+            "الثالثة",
+            "الرابعة",
+            "الخامسة",
+            "السادسة",
+            "السابعة",
+            "الثامنة",
+            "التاسعة",
+            "العاشرة",
+            "الحادية عشرة",
+            "الثانية عشرة",
+        ];
+
+        let masculine_options = OrdinalOptions::new();
+        let feminine_options = OrdinalOptions::new().gender(Gender::Feminine);
+        for i in 0..12 {
+            let ordinal = Ordinal(i + 1);
+            assert_eq!(
+                masculine[i],
+                ordinal
+                    .to_words_locale(Locale::Arabic, &masculine_options)
+                    .unwrap()
+            );
+            assert_eq!(
+                feminine[i],
+                ordinal
+                    .to_words_locale(Locale::Arabic, &feminine_options)
+                    .unwrap()
+            );
+        }
+
+        let words = |n: u32, options: &OrdinalOptions| {
+            Ordinal(n).to_words_locale(Locale::Arabic, options).unwrap()
+        };
+        assert_eq!("الحادي والعشرون", words(21, &masculine_options));
+        assert_eq!("الحادية والعشرون", words(21, &feminine_options));
+        assert_eq!("العشرون", words(20, &masculine_options));
+        assert_eq!("الثالث عشر", words(13, &masculine_options));
+
+        let indefinite = OrdinalOptions::new().definite(false);
+        assert_eq!("أول", words(1, &indefinite));
+        assert_eq!("حادي وعشرون", words(21, &indefinite));
+        assert_eq!(
+            "ثانية عشرة",
+            words(12, &indefinite.clone().gender(Gender::Feminine))
+        );
+        assert!(Ordinal(100)
+            .to_words_locale(Locale::Arabic, &masculine_options)
+            .is_err());
+    }
+}
@@ -0,0 +1,149 @@
+//! Basque ordinals are written as the number followed by a period: `3.`.
+//!
+//! Word forms cover 1 to 100 and add `-garren` to the cardinal, with `bost` losing
+//! its `t`: `hirugarren`, `bosgarren`. Tens are vigesimal, scores joined to the rest
+//! with `-ta`: `hogeita batgarren` (21), `hogeita hamaikagarren` (31). First on its
+//! own is `lehen` unless [`BasqueFirst::Batgarren`] is asked for; in compounds it's
+//! always `batgarren`.
+
+use super::OrdinalOptions;
+use alloc::format;
+use alloc::string::{String, ToString};
+
+/// The word for "first" in Basque.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum BasqueFirst {
+    /// `lehen`.
+    #[default]
+    Lehen,
+    /// `batgarren`.
+    Batgarren,
+}
+
+const CARDINALS: [&str; 20] = [
+    "",
+    "bat",
+    "bi",
+    "hiru",
+    "lau",
+    "bost",
+    "sei",
+    "zazpi",
+    "zortzi",
+    "bederatzi",
+    "hamar",
+    "hamaika",
+    "hamabi",
+    "hamahiru",
+    "hamalau",
+    "hamabost",
+    "hamasei",
+    "hamazazpi",
+    "hemezortzi",
+    "hemeretzi",
+];
+
+/// The scores: 20, 40, 60 and 80.
+const SCORES: [&str; 5] = ["", "hogei", "berrogei", "hirurogei", "laurogei"];
+
+/// Spells out `n` for values from 1 to 100.
+pub(crate) fn words(n: u128, options: &OrdinalOptions) -> Option<String> {
+    let cardinal = match n {
+        1 if options.basque_first == BasqueFirst::Lehen => return Some("lehen".to_string()),
+        1..=19 => CARDINALS[n as usize].to_string(),
+        20..=99 => {
+            let (score, rest) = (SCORES[(n / 20) as usize], (n % 20) as usize);
+            match rest {
+                0 => score.to_string(),
+                _ => format!("{}ta {}", score, CARDINALS[rest]),
+            }
+        }
+        100 => "ehun".to_string(),
+        _ => return None,
+    };
+    let stem = cardinal
+        .strip_suffix('t')
+        .filter(|stem| stem.ends_with("bos"))
+        .unwrap_or(&cardinal);
+    Some(format!("{}garren", stem))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::locale::BasqueFirst;
+    use crate::{Locale, Ordinal, OrdinalOptions};
+
+    #[test]
+    fn test_basque_digits() {
+        for i in 1..=31 {
+            assert_eq!(
+                format!("{}.", i),
+                Ordinal(i).to_string_with_locale(Locale::Basque)
+            );
+        }
+    }
+
+    #[test]
+    fn test_basque_words() {
+        let basque_ordinals: [&str; 31] = [
+            "lehen",
+            "bigarren",
+            "hirugarren",
+            "laugarren",
+            "bosgarren",
+            "seigarren",
+            "zazpigarren",
+            "zortzigarren",
+            "bederatzigarren",
+            "hamargarren",
+            "hamaikagarren",
+            "hamabigarren",
+            "hamahirugarren",
+            "hamalaugarren",
+            "hamabosgarren",
+            "hamaseigarren",
+            "hamazazpigarren",
+            "hemezortzigarren",
+            "hemeretzigarren",
+            "hogeigarren",
+            "hogeita batgarren",
+            "hogeita bigarren",
+            "hogeita hirugarren",
+            "hogeita laugarren",
+            "hogeita bosgarren",
+            "hogeita seigarren",
+            "hogeita zazpigarren",
+            "hogeita zortzigarren",
+            "hogeita bederatzigarren",
+            "hogeita hamargarren",
+            "hogeita hamaikagarren",
+        ];
+
+        let options = OrdinalOptions::new();
+        for (i, expected) in basque_ordinals.iter().enumerate() {
+            assert_eq!(
+                *expected,
+                Ordinal(i + 1)
+                    .to_words_locale(Locale::Basque, &options)
+                    .unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_basque_first_and_range() {
+        let options = OrdinalOptions::new();
+        let words =
+            |n: u32, options: &OrdinalOptions| Ordinal(n).to_words_locale(Locale::Basque, options);
+        let batgarren = options.clone().basque_first(BasqueFirst::Batgarren);
+        assert_eq!("batgarren", words(1, &batgarren).unwrap());
+        assert_eq!("hogeita batgarren", words(21, &batgarren).unwrap());
+        assert_eq!("berrogeigarren", words(40, &options).unwrap());
+        assert_eq!("berrogeita hamabosgarren", words(55, &options).unwrap());
+        assert_eq!("laurogeita hemeretzigarren", words(99, &options).unwrap());
+        assert_eq!("ehungarren", words(100, &options).unwrap());
+        assert!(words(0, &options).is_err());
+        assert!(words(101, &options).is_err());
+    }
+}
@@ -0,0 +1,108 @@
+//! Bengali abbreviates the first ten ordinals with the ending of each word
+//! (`১ম` for প্রথম, `২য়` for দ্বিতীয়, `৪র্থ` for চতুর্থ, `৬ষ্ঠ` for ষষ্ঠ) and the rest
+//! with `তম` (`২১তম`). Bengali text usually writes the numeral in Bengali digits,
+//! chosen with [`OrdinalOptions::digits`].
+//!
+//! The CLDR categories match the endings exactly: one (`ম`, 1, 5, 7–10), two (`য়`, 2, 3),
+//! few (`র্থ`, 4), many (`ষ্ঠ`, 6) and other (`তম`).
+
+use super::{digits, Number, OrdinalCategory, OrdinalOptions};
+use alloc::format;
+use alloc::string::{String, ToString};
+
+const SUFFIXES: [&str; 11] = [
+    "তম",
+    "ম",
+    "\u{9af}\u{9bc}",
+    "\u{9af}\u{9bc}",
+    "র্থ",
+    "ম",
+    "ষ্ঠ",
+    "ম",
+    "ম",
+    "ম",
+    "ম",
+];
+
+pub(crate) fn format(number: &Number, options: &OrdinalOptions) -> String {
+    let suffix = match number.magnitude() {
+        Some(n @ 1..=10) => SUFFIXES[n as usize],
+        _ => "তম",
+    };
+    let numeral = digits::shape(&number.to_string(), options.digits);
+    format!("{}{}", numeral, suffix)
+}
+
+pub(crate) fn category(number: &Number) -> OrdinalCategory {
+    match number.magnitude() {
+        Some(1 | 5 | 7..=10) => OrdinalCategory::One,
+        Some(2 | 3) => OrdinalCategory::Two,
+        Some(4) => OrdinalCategory::Few,
+        Some(6) => OrdinalCategory::Many,
+        _ => OrdinalCategory::Other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Digits, Locale, Ordinal, OrdinalOptions};
+
+    #[test]
+    fn test_bengali() {
+        let bengali: [&str; 12] = [
+            "১ম",
+            "২য়",
+            "৩য়",
+            "৪র্থ",
+            "৫ম",
+            "৬ষ্ঠ",
+            "৭ম",
+            "৮ম",
+            "৯ম",
+            "১০ম",
+            "১১তম",
+            "১২তম",
+        ];
+        let latin: [&str; 12] = [
+            "1ম",
+            "2য়",
+            "3য়",
+            "4র্থ",
+            "5ম",
+            "6ষ্ঠ",
+            "7ম",
+            "8ম",
+            "9ম",
+            "10ম",
+            "11তম",
+            "12তম",
+        ];
+
+        let bengali_digits = OrdinalOptions::new().digits(Digits::Bengali);
+        let latin_digits = OrdinalOptions::new();
+        for i in 0..12 {
+            let ordinal = Ordinal(i + 1);
+            assert_eq!(
+                bengali[i],
+                ordinal.to_string_with_locale_opts(Locale::Bengali, &bengali_digits)
+            );
+            assert_eq!(
+                latin[i],
+                ordinal.to_string_with_locale_opts(Locale::Bengali, &latin_digits)
+            );
+        }
+
+        for (n, bengali, latin) in [(21, "২১তম", "21তম"), (100, "১০০তম", "100তম")]
+        {
+            let ordinal = Ordinal(n);
+            assert_eq!(
+                bengali,
+                ordinal.to_string_with_locale_opts(Locale::Bengali, &bengali_digits)
+            );
+            assert_eq!(
+                latin,
+                ordinal.to_string_with_locale_opts(Locale::Bengali, &latin_digits)
+            );
+        }
+    }
+}
@@ -0,0 +1,18 @@
+//! Directional marks for the right-to-left locales. Output is always in logical
+//! order; when asked for, the marks keep a numeral and its sign or punctuation
+//! attached to the right-to-left text around it instead of the surrounding Latin text.
+
+use alloc::format;
+use alloc::string::String;
+
+/// RIGHT-TO-LEFT MARK (U+200F).
+const RLM: char = '\u{200f}';
+
+/// Surrounds `text` with right-to-left marks if `marks` is set.
+pub(crate) fn mark(text: String, marks: bool) -> String {
+    if marks {
+        format!("{}{}{}", RLM, text, RLM)
+    } else {
+        text
+    }
+}
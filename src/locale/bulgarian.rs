@@ -0,0 +1,191 @@
+//! Bulgarian abbreviates an ordinal as the number, a hyphen and the last letters of the
+//! word, which depend on the word and the gender: `1-ви`, `2-ра`, `3-то`, `7-ми`,
+//! `100-ен`. Word forms cover 1 to 100; compounds join the cardinal tens to the
+//! ordinal unit with `и`: `двадесет и първи`.
+
+use super::{Gender, Number, OrdinalOptions};
+use alloc::format;
+use alloc::string::{String, ToString};
+
+/// The masculine forms from 1 to 19.
+const UNITS: [&str; 20] = [
+    "",
+    "първи",
+    "втори",
+    "трети",
+    "четвърти",
+    "пети",
+    "шести",
+    "седми",
+    "осми",
+    "девети",
+    "десети",
+    "единадесети",
+    "дванадесети",
+    "тринадесети",
+    "четиринадесети",
+    "петнадесети",
+    "шестнадесети",
+    "седемнадесети",
+    "осемнадесети",
+    "деветнадесети",
+];
+
+/// The cardinal tens, which lead a compound.
+const TENS: [&str; 10] = [
+    "",
+    "",
+    "двадесет",
+    "тридесет",
+    "четиридесет",
+    "петдесет",
+    "шестдесет",
+    "седемдесет",
+    "осемдесет",
+    "деветдесет",
+];
+
+pub(crate) fn format(number: &Number, options: &OrdinalOptions) -> String {
+    let digits = number.digits();
+    // The masculine, feminine and neuter endings of the word the number stands for.
+    let endings = match number.last_two() {
+        _ if digits == "0" => ["ев", "ва", "во"],
+        0 => ["ен", "на", "но"],
+        11..=19 => ["ти", "та", "то"],
+        n => match n % 10 {
+            1 => ["ви", "ва", "во"],
+            2 => ["ри", "ра", "ро"],
+            7 | 8 => ["ми", "ма", "мо"],
+            _ => ["ти", "та", "то"],
+        },
+    };
+    let ending = match options.gender {
+        Gender::Feminine => endings[1],
+        Gender::Neuter => endings[2],
+        Gender::Masculine | Gender::Common => endings[0],
+    };
+    format!("{}-{}", number, ending)
+}
+
+/// Spells out `n` for values from 1 to 100.
+pub(crate) fn words(n: u128, options: &OrdinalOptions) -> Option<String> {
+    let gender = options.gender;
+    match n {
+        1..=19 => Some(inflect(UNITS[n as usize], gender)),
+        20..=99 => {
+            let tens = TENS[(n / 10) as usize];
+            Some(match n % 10 {
+                0 => inflect(&format!("{}и", tens), gender),
+                unit => format!("{} и {}", tens, inflect(UNITS[unit as usize], gender)),
+            })
+        }
+        100 => Some(
+            match gender {
+                Gender::Feminine => "стотна",
+                Gender::Neuter => "стотно",
+                Gender::Masculine | Gender::Common => "стотен",
+            }
+            .to_string(),
+        ),
+        _ => None,
+    }
+}
+
+fn inflect(masculine: &str, gender: Gender) -> String {
+    let stem = masculine.strip_suffix('и').unwrap_or(masculine);
+    match gender {
+        Gender::Feminine => format!("{}а", stem),
+        Gender::Neuter => format!("{}о", stem),
+        Gender::Masculine | Gender::Common => masculine.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Gender, Locale, Ordinal, OrdinalOptions};
+
+    #[test]
+    fn test_bulgarian_abbreviations() {
+        // Masculine, feminine and neuter.
+        let bulgarian_endings: [(&str, &str, &str); 30] = [
+            ("ви", "ва", "во"),
+            ("ри", "ра", "ро"),
+            ("ти", "та", "то"),
+            ("ти", "та", "то"),
+            ("ти", "та", "то"),
+            ("ти", "та", "то"),
+            ("ми", "ма", "мо"),
+            ("ми", "ма", "мо"),
+            ("ти", "та", "то"),
+            ("ти", "та", "то"),
+            ("ти", "та", "то"),
+            ("ти", "та", "то"),
+            ("ти", "та", "то"),
+            ("ти", "та", "то"),
+            ("ти", "та", "то"),
+            ("ти", "та", "то"),
+            ("ти", "та", "то"),
+            ("ти", "та", "то"),
+            ("ти", "та", "то"),
+            ("ти", "та", "то"),
+            ("ви", "ва", "во"),
+            ("ри", "ра", "ро"),
+            ("ти", "та", "то"),
+            ("ти", "та", "то"),
+            ("ти", "та", "то"),
+            ("ти", "та", "то"),
+            ("ми", "ма", "мо"),
+            ("ми", "ма", "мо"),
+            ("ти", "та", "то"),
+            ("ти", "та", "то"),
+        ];
+
+        let genders = [Gender::Masculine, Gender::Feminine, Gender::Neuter];
+        for (i, endings) in bulgarian_endings.iter().enumerate() {
+            let n = i + 1;
+            for (gender, ending) in genders.into_iter().zip([endings.0, endings.1, endings.2]) {
+                let options = OrdinalOptions::new().gender(gender);
+                assert_eq!(
+                    format!("{}-{}", n, ending),
+                    Ordinal(n).to_string_with_locale_opts(Locale::Bulgarian, &options)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_bulgarian_abbreviations_past_thirty() {
+        let feminine = OrdinalOptions::new().gender(Gender::Feminine);
+        let format = |n: i64, options: &OrdinalOptions| {
+            Ordinal(n).to_string_with_locale_opts(Locale::Bulgarian, options)
+        };
+        assert_eq!("100-ен", format(100, &OrdinalOptions::new()));
+        assert_eq!("2000-на", format(2000, &feminine));
+        assert_eq!("101-ва", format(101, &feminine));
+        assert_eq!("111-та", format(111, &feminine));
+        assert_eq!("0-ев", format(0, &OrdinalOptions::new()));
+        assert_eq!("-2-ри", format(-2, &OrdinalOptions::new()));
+    }
+
+    #[test]
+    fn test_bulgarian_words() {
+        let words = |n: u32, gender: Gender| {
+            let options = OrdinalOptions::new().gender(gender);
+            Ordinal(n).to_words_locale(Locale::Bulgarian, &options)
+        };
+        assert_eq!("първи", words(1, Gender::Masculine).unwrap());
+        assert_eq!("първа", words(1, Gender::Feminine).unwrap());
+        assert_eq!("първо", words(1, Gender::Neuter).unwrap());
+        assert_eq!("втора", words(2, Gender::Feminine).unwrap());
+        assert_eq!("седмо", words(7, Gender::Neuter).unwrap());
+        assert_eq!("единадесети", words(11, Gender::Masculine).unwrap());
+        assert_eq!("двадесети", words(20, Gender::Masculine).unwrap());
+        assert_eq!("двадесет и първи", words(21, Gender::Masculine).unwrap());
+        assert_eq!("тридесет и втора", words(32, Gender::Feminine).unwrap());
+        assert_eq!("деветдесет и осмо", words(98, Gender::Neuter).unwrap());
+        assert_eq!("стотен", words(100, Gender::Masculine).unwrap());
+        assert_eq!("стотна", words(100, Gender::Feminine).unwrap());
+        assert!(words(0, Gender::Masculine).is_err());
+        assert!(words(101, Gender::Masculine).is_err());
+    }
+}
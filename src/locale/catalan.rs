@@ -0,0 +1,79 @@
+//! Catalan abbreviates ordinals with the last letters of the word.
+//! Only 1–4 have their own masculine forms (`1r`, `2n`, `3r`, `4t`); every other number
+//! takes `è` (`5è`, `21è`, since the suffix follows the whole number).
+//! The feminine is `a` throughout. Plurals are `1rs`, `2ns`, `3rs`, `4ts`, `5ns`
+//! in the masculine and `1es` in the feminine.
+//! The masculine suffixes split numbers exactly as the CLDR categories do.
+
+use super::{Gender, GrammaticalNumber, Number, OrdinalCategory, OrdinalOptions};
+use alloc::format;
+use alloc::string::String;
+
+pub(crate) fn format(number: &Number, options: &OrdinalOptions) -> String {
+    let plural = options.number == GrammaticalNumber::Plural;
+    let suffix = match (options.gender, number.magnitude()) {
+        (Gender::Feminine, _) if plural => "es",
+        (Gender::Feminine, _) => "a",
+        (_, Some(1 | 3)) if plural => "rs",
+        (_, Some(1 | 3)) => "r",
+        (_, Some(2)) if plural => "ns",
+        (_, Some(2)) => "n",
+        (_, Some(4)) if plural => "ts",
+        (_, Some(4)) => "t",
+        _ if plural => "ns",
+        _ => "è",
+    };
+    format!("{}{}", number, suffix)
+}
+
+pub(crate) fn category(number: &Number) -> OrdinalCategory {
+    match number.magnitude() {
+        Some(1 | 3) => OrdinalCategory::One,
+        Some(2) => OrdinalCategory::Two,
+        Some(4) => OrdinalCategory::Few,
+        _ => OrdinalCategory::Other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Gender, GrammaticalNumber, Locale, Ordinal, OrdinalOptions};
+
+    #[test]
+    fn test_catalan_singular() {
+        let masculine_ordinals: [&str; 25] = [
+            "1r", "2n", "3r", "4t", "5è", "6è", "7è", "8è", "9è", "10è", "11è", "12è", "13è",
+            "14è", "15è", "16è", "17è", "18è", "19è", "20è", "21è", "22è", "23è", "24è", "25è",
+        ];
+
+        let feminine = OrdinalOptions::new().gender(Gender::Feminine);
+        for (i, expected) in masculine_ordinals.iter().enumerate() {
+            let ordinal = Ordinal(i + 1);
+            assert_eq!(*expected, ordinal.to_string_with_locale(Locale::Catalan));
+            assert_eq!(
+                format!("{}a", i + 1),
+                ordinal.to_string_with_locale_opts(Locale::Catalan, &feminine)
+            );
+        }
+    }
+
+    #[test]
+    fn test_catalan_plural() {
+        let masculine = OrdinalOptions::new().number(GrammaticalNumber::Plural);
+        let feminine = masculine.clone().gender(Gender::Feminine);
+        let catalan = |n: u32, options: &OrdinalOptions| {
+            Ordinal(n).to_string_with_locale_opts(Locale::Catalan, options)
+        };
+
+        assert_eq!("1rs", catalan(1, &masculine));
+        assert_eq!("2ns", catalan(2, &masculine));
+        assert_eq!("3rs", catalan(3, &masculine));
+        assert_eq!("4ts", catalan(4, &masculine));
+        assert_eq!("5ns", catalan(5, &masculine));
+        assert_eq!("21ns", catalan(21, &masculine));
+        assert_eq!("1es", catalan(1, &feminine));
+        assert_eq!("2es", catalan(2, &feminine));
+        assert_eq!("3es", catalan(3, &feminine));
+        assert_eq!("4es", catalan(4, &feminine));
+    }
+}
@@ -0,0 +1,112 @@
+//! Chinese ordinals prefix `第` to Arabic or Chinese numerals: `第3`, `第三`. Chinese
+//! numerals are used below 10¹⁶ in the simplified or traditional script, which differ
+//! in the myriad characters (`万`/`萬`, `亿`/`億`); larger and negative values keep
+//! Arabic digits.
+
+#[cfg(feature = "cjk")]
+use super::{cjk, Number, OrdinalOptions};
+#[cfg(feature = "cjk")]
+use alloc::format;
+#[cfg(feature = "cjk")]
+use alloc::string::{String, ToString};
+
+/// Numerals for Chinese ordinals.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum ChineseNumerals {
+    /// `第3`.
+    #[default]
+    Arabic,
+    /// `第三`.
+    Hanzi,
+}
+
+/// Script for Chinese numerals.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum ChineseScript {
+    /// `一万`, `一亿`.
+    #[default]
+    Simplified,
+    /// `一萬`, `一億`.
+    Traditional,
+}
+
+#[cfg(feature = "cjk")]
+pub(crate) fn format(number: &Number, options: &OrdinalOptions) -> String {
+    let numerals = match options.chinese_script {
+        ChineseScript::Simplified => &cjk::SIMPLIFIED_CHINESE,
+        ChineseScript::Traditional => &cjk::TRADITIONAL_CHINESE,
+    };
+    let numeral = match options.chinese_numerals {
+        ChineseNumerals::Hanzi => number.value().and_then(|n| cjk::spell(n, numerals)),
+        ChineseNumerals::Arabic => None,
+    }
+    .unwrap_or_else(|| number.to_string());
+    format!("第{}", numeral)
+}
+
+#[cfg(all(test, feature = "cjk"))]
+mod tests {
+    use crate::locale::{ChineseNumerals, ChineseScript};
+    use crate::{Locale, Ordinal, OrdinalOptions};
+
+    #[test]
+    fn test_chinese() {
+        let values: [(u64, &str, &str); 7] = [
+            (1, "一", "一"),
+            (10, "十", "十"),
+            (14, "十四", "十四"),
+            (21, "二十一", "二十一"),
+            (105, "一百零五", "一百零五"),
+            (1000, "一千", "一千"),
+            (10_000, "一万", "一萬"),
+        ];
+
+        for (n, simplified, traditional) in values {
+            let ordinal = Ordinal(n);
+            for script in [ChineseScript::Simplified, ChineseScript::Traditional] {
+                let arabic = OrdinalOptions::new().chinese_script(script);
+                assert_eq!(
+                    format!("第{}", n),
+                    ordinal.to_string_with_locale_opts(Locale::Chinese, &arabic)
+                );
+            }
+
+            let hanzi = OrdinalOptions::new().chinese_numerals(ChineseNumerals::Hanzi);
+            assert_eq!(
+                format!("第{}", simplified),
+                ordinal.to_string_with_locale_opts(Locale::Chinese, &hanzi)
+            );
+            assert_eq!(
+                format!("第{}", traditional),
+                ordinal.to_string_with_locale_opts(
+                    Locale::Chinese,
+                    &hanzi.chinese_script(ChineseScript::Traditional)
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_chinese_large_values() {
+        let simplified = OrdinalOptions::new().chinese_numerals(ChineseNumerals::Hanzi);
+        let traditional = simplified
+            .clone()
+            .chinese_script(ChineseScript::Traditional);
+        let format = |n: u64, options: &OrdinalOptions| {
+            Ordinal(n).to_string_with_locale_opts(Locale::Chinese, options)
+        };
+
+        assert_eq!("第零", format(0, &simplified));
+        assert_eq!("第一百一十", format(110, &simplified));
+        assert_eq!("第一千零二十", format(1020, &simplified));
+        assert_eq!("第一万零五", format(10_005, &simplified));
+        assert_eq!("第十万", format(100_000, &simplified));
+        assert_eq!("第十万零一百", format(100_100, &simplified));
+        assert_eq!("第一亿零一千", format(100_001_000, &simplified));
+        assert_eq!("第三亿", format(300_000_000, &simplified));
+        assert_eq!("第三億", format(300_000_000, &traditional));
+        assert_eq!("第一億二千三百四十五萬", format(123_450_000, &traditional));
+    }
+}
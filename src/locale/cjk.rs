@@ -0,0 +1,92 @@
+//! Numerals shared by the locales that write numbers in Chinese characters, which
+//! group digits in myriads: `千二百三十四`, `一万`, `三億`.
+
+use alloc::string::{String, ToString};
+
+const DIGITS: [char; 10] = ['〇', '一', '二', '三', '四', '五', '六', '七', '八', '九'];
+
+/// The script-specific characters and rules of a numeral system.
+pub(crate) struct Numerals {
+    /// 10⁴, 10⁸ and 10¹².
+    myriads: [char; 3],
+    /// Chinese marks gaps with `零` and keeps the `一` before `百` and `千`
+    /// (`一百零五`), where Japanese writes `百五`.
+    zeros: bool,
+}
+
+pub(crate) const JAPANESE: Numerals = Numerals {
+    myriads: ['万', '億', '兆'],
+    zeros: false,
+};
+
+pub(crate) const SIMPLIFIED_CHINESE: Numerals = Numerals {
+    myriads: ['万', '亿', '兆'],
+    zeros: true,
+};
+
+pub(crate) const TRADITIONAL_CHINESE: Numerals = Numerals {
+    myriads: ['萬', '億', '兆'],
+    zeros: true,
+};
+
+/// Spells out `n`, or returns `None` from 10¹⁶ up.
+pub(crate) fn spell(n: u128, numerals: &Numerals) -> Option<String> {
+    if n >= 10_000_000_000_000_000 {
+        return None;
+    }
+    if n == 0 {
+        return Some(if numerals.zeros { '零' } else { DIGITS[0] }.to_string());
+    }
+    let mut spelled = String::new();
+    let mut gap = false;
+    for myriad in (0..4).rev() {
+        let group = (n / 10_000u128.pow(myriad) % 10_000) as usize;
+        let leading = spelled.is_empty();
+        if group == 0 {
+            gap |= !leading;
+            continue;
+        }
+        if numerals.zeros && !leading && (gap || group < 1000) {
+            spelled.push('零');
+        }
+        gap = false;
+        spelled.push_str(&below_myriad(group, leading, numerals));
+        if myriad > 0 {
+            spelled.push(numerals.myriads[myriad as usize - 1]);
+        }
+    }
+    Some(spelled)
+}
+
+/// Spells out a group of four digits. Japanese leaves out the `一` before `十`, `百` and
+/// `千`; Chinese only before a leading `十` (`十四`, but `一百一十`).
+fn below_myriad(group: usize, leading: bool, numerals: &Numerals) -> String {
+    let mut spelled = String::new();
+    let mut gap = false;
+    for (power, unit) in [
+        (1000, Some('千')),
+        (100, Some('百')),
+        (10, Some('十')),
+        (1, None),
+    ] {
+        let digit = group / power % 10;
+        if digit == 0 {
+            gap |= !spelled.is_empty();
+            continue;
+        }
+        if gap && numerals.zeros {
+            spelled.push('零');
+        }
+        gap = false;
+        let implicit_one = if numerals.zeros {
+            power == 10 && leading && spelled.is_empty()
+        } else {
+            unit.is_some()
+        };
+        if digit > 1 || !implicit_one {
+            spelled.push(DIGITS[digit]);
+        }
+        spelled.extend(unit);
+    }
+    spelled
+}
@@ -0,0 +1,163 @@
+//! Croatian ordinals are written as the number followed by a period: `21.`.
+//! Word forms cover 1 to 100 in the nominative. Only the last word of a compound is
+//! ordinal, `dvadeset prvi`, and it inflects like an adjective: `-i`, `-a`, `-o`, with
+//! `-e` for the neuter of the soft stem `treć-`.
+
+use super::{Gender, OrdinalOptions};
+use alloc::format;
+use alloc::string::String;
+
+/// The masculine forms from 1 to 19.
+const UNITS: [&str; 20] = [
+    "",
+    "prvi",
+    "drugi",
+    "treći",
+    "četvrti",
+    "peti",
+    "šesti",
+    "sedmi",
+    "osmi",
+    "deveti",
+    "deseti",
+    "jedanaesti",
+    "dvanaesti",
+    "trinaesti",
+    "četrnaesti",
+    "petnaesti",
+    "šesnaesti",
+    "sedamnaesti",
+    "osamnaesti",
+    "devetnaesti",
+];
+
+/// The cardinal tens, which lead a compound.
+const TENS: [&str; 10] = [
+    "",
+    "",
+    "dvadeset",
+    "trideset",
+    "četrdeset",
+    "pedeset",
+    "šezdeset",
+    "sedamdeset",
+    "osamdeset",
+    "devedeset",
+];
+
+/// Spells out `n` for values from 1 to 100.
+pub(crate) fn words(n: u128, options: &OrdinalOptions) -> Option<String> {
+    let gender = options.gender;
+    match n {
+        1..=19 => Some(inflect(UNITS[n as usize], gender)),
+        20..=99 => {
+            let tens = TENS[(n / 10) as usize];
+            Some(match n % 10 {
+                0 => inflect(&format!("{}i", tens), gender),
+                unit => format!("{} {}", tens, inflect(UNITS[unit as usize], gender)),
+            })
+        }
+        100 => Some(inflect("stoti", gender)),
+        _ => None,
+    }
+}
+
+fn inflect(masculine: &str, gender: Gender) -> String {
+    let stem = masculine.strip_suffix('i').unwrap_or(masculine);
+    let ending = match gender {
+        Gender::Feminine => "a",
+        Gender::Neuter if stem.ends_with('ć') => "e",
+        Gender::Neuter => "o",
+        Gender::Masculine | Gender::Common => "i",
+    };
+    format!("{}{}", stem, ending)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Gender, Locale, Ordinal, OrdinalOptions};
+
+    #[test]
+    fn test_croatian_digits() {
+        for i in [1, 2, 3, 11, 21, 100, 1991] {
+            assert_eq!(
+                format!("{}.", i),
+                Ordinal(i).to_string_with_locale(Locale::Croatian)
+            );
+        }
+        assert_eq!("-4.", Ordinal(-4).to_string_with_locale(Locale::Croatian));
+    }
+
+    #[test]
+    fn test_croatian_words() {
+        // Masculine, feminine and neuter.
+        let croatian_ordinals: [(&str, &str, &str); 31] = [
+            ("prvi", "prva", "prvo"),
+            ("drugi", "druga", "drugo"),
+            ("treći", "treća", "treće"),
+            ("četvrti", "četvrta", "četvrto"),
+            ("peti", "peta", "peto"),
+            ("šesti", "šesta", "šesto"),
+            ("sedmi", "sedma", "sedmo"),
+            ("osmi", "osma", "osmo"),
+            ("deveti", "deveta", "deveto"),
+            ("deseti", "deseta", "deseto"),
+            ("jedanaesti", "jedanaesta", "jedanaesto"),
+            ("dvanaesti", "dvanaesta", "dvanaesto"),
+            ("trinaesti", "trinaesta", "trinaesto"),
+            ("četrnaesti", "četrnaesta", "četrnaesto"),
+            ("petnaesti", "petnaesta", "petnaesto"),
+            ("šesnaesti", "šesnaesta", "šesnaesto"),
+            ("sedamnaesti", "sedamnaesta", "sedamnaesto"),
+            ("osamnaesti", "osamnaesta", "osamnaesto"),
+            ("devetnaesti", "devetnaesta", "devetnaesto"),
+            ("dvadeseti", "dvadeseta", "dvadeseto"),
+            ("dvadeset prvi", "dvadeset prva", "dvadeset prvo"),
+            ("dvadeset drugi", "dvadeset druga", "dvadeset drugo"),
+            ("dvadeset treći", "dvadeset treća", "dvadeset treće"),
+            ("dvadeset četvrti", "dvadeset četvrta", "dvadeset četvrto"),
+            ("dvadeset peti", "dvadeset peta", "dvadeset peto"),
+            ("dvadeset šesti", "dvadeset šesta", "dvadeset šesto"),
+            ("dvadeset sedmi", "dvadeset sedma", "dvadeset sedmo"),
+            ("dvadeset osmi", "dvadeset osma", "dvadeset osmo"),
+            ("dvadeset deveti", "dvadeset deveta", "dvadeset deveto"),
+            ("trideseti", "trideseta", "trideseto"),
+            ("trideset prvi", "trideset prva", "trideset prvo"),
+        ];
+
+        let genders = [Gender::Masculine, Gender::Feminine, Gender::Neuter];
+        for (i, forms) in croatian_ordinals.iter().enumerate() {
+            for (gender, expected) in genders.into_iter().zip([forms.0, forms.1, forms.2]) {
+                let options = OrdinalOptions::new().gender(gender);
+                assert_eq!(
+                    expected,
+                    Ordinal(i + 1)
+                        .to_words_locale(Locale::Croatian, &options)
+                        .unwrap()
+                );
+            }
+        }
+        for (gender, expected) in genders.into_iter().zip(["stoti", "stota", "stoto"]) {
+            let options = OrdinalOptions::new().gender(gender);
+            assert_eq!(
+                expected,
+                Ordinal(100)
+                    .to_words_locale(Locale::Croatian, &options)
+                    .unwrap()
+            );
+        }
+        let options = OrdinalOptions::new();
+        assert_eq!(
+            "devedeset deveti",
+            Ordinal(99)
+                .to_words_locale(Locale::Croatian, &options)
+                .unwrap()
+        );
+        assert!(Ordinal(0)
+            .to_words_locale(Locale::Croatian, &options)
+            .is_err());
+        assert!(Ordinal(101)
+            .to_words_locale(Locale::Croatian, &options)
+            .is_err());
+    }
+}
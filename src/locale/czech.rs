@@ -0,0 +1,176 @@
+//! Czech ordinals are written as the number followed by a period: `21.`.
+//! In word forms every component is ordinal (`stý dvacátý první`); hard adjectives
+//! inflect for gender (`druhý`, `druhá`, `druhé`) while soft ones don't (`první`, `třetí`).
+
+use super::{slavic, Gender, OrdinalOptions};
+use alloc::format;
+use alloc::string::{String, ToString};
+
+const UNITS: [&str; 20] = [
+    "",
+    "první",
+    "druhý",
+    "třetí",
+    "čtvrtý",
+    "pátý",
+    "šestý",
+    "sedmý",
+    "osmý",
+    "devátý",
+    "desátý",
+    "jedenáctý",
+    "dvanáctý",
+    "třináctý",
+    "čtrnáctý",
+    "patnáctý",
+    "šestnáctý",
+    "sedmnáctý",
+    "osmnáctý",
+    "devatenáctý",
+];
+
+const TENS: [&str; 10] = [
+    "",
+    "",
+    "dvacátý",
+    "třicátý",
+    "čtyřicátý",
+    "padesátý",
+    "šedesátý",
+    "sedmdesátý",
+    "osmdesátý",
+    "devadesátý",
+];
+
+/// Multiplier prefixes for `stý` and `tisící`.
+const MULTIPLIERS: [&str; 10] = [
+    "", "", "dvou", "tří", "čtyř", "pěti", "šesti", "sedmi", "osmi", "devíti",
+];
+
+/// Spells out `n` for values from 1 to 9999.
+pub(crate) fn words(n: u128, options: &OrdinalOptions) -> Option<String> {
+    if n == 0 || n >= 10_000 {
+        return None;
+    }
+    let n = n as usize;
+    let thousands = format!("{}tisící", MULTIPLIERS[n / 1000]);
+    let hundreds = format!("{}stý", MULTIPLIERS[n / 100 % 10]);
+    let parts = slavic::ordinal_parts(n, &UNITS, &TENS, &hundreds, &thousands);
+    Some(slavic::compose(&[], &parts, |word| {
+        inflect(word, options.gender)
+    }))
+}
+
+fn inflect(masculine: &str, gender: Gender) -> String {
+    match (masculine.strip_suffix('ý'), gender) {
+        (Some(stem), Gender::Feminine) => format!("{}á", stem),
+        (Some(stem), Gender::Neuter) => format!("{}é", stem),
+        _ => masculine.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Gender, Locale, Ordinal, OrdinalOptions};
+
+    #[test]
+    fn test_czech_digits() {
+        for i in [1, 2, 21, 100, 1000] {
+            assert_eq!(
+                format!("{}.", i),
+                Ordinal(i).to_string_with_locale(Locale::Czech)
+            );
+        }
+    }
+
+    #[test]
+    fn test_czech_words() {
+        let masculine: [&str; 31] = [
+            "první",
+            "druhý",
+            "třetí",
+            "čtvrtý",
+            "pátý",
+            "šestý",
+            "sedmý",
+            "osmý",
+            "devátý",
+            "desátý",
+            "jedenáctý",
+            "dvanáctý",
+            "třináctý",
+            "čtrnáctý",
+            "patnáctý",
+            "šestnáctý",
+            "sedmnáctý",
+            "osmnáctý",
+            "devatenáctý",
+            "dvacátý",
+            "dvacátý první",
+            "dvacátý druhý",
+            "dvacátý třetí",
+            "dvacátý čtvrtý",
+            "dvacátý pátý",
+            "dvacátý šestý",
+            "dvacátý sedmý",
+            "dvacátý osmý",
+            "dvacátý devátý",
+            "třicátý",
+            "třicátý první",
+        ];
+        let feminine: [&str; 31] = [
+            "první",
+            "druhá",
+            "třetí",
+            "čtvrtá",
+            "pátá",
+            "šestá",
+            "sedmá",
+            "osmá",
+            "devátá",
+            "desátá",
+            "jedenáctá",
+            "dvanáctá",
+            "třináctá",
+            "čtrnáctá",
+            "patnáctá",
+            "šestnáctá",
+            "sedmnáctá",
+            "osmnáctá",
+            "devatenáctá",
+            "dvacátá",
+            "dvacátá první",
+            "dvacátá druhá",
+            "dvacátá třetí",
+            "dvacátá čtvrtá",
+            "dvacátá pátá",
+            "dvacátá šestá",
+            "dvacátá sedmá",
+            "dvacátá osmá",
+            "dvacátá devátá",
+            "třicátá",
+            "třicátá první",
+        ];
+
+        let words = |n: usize, gender: Gender| {
+            let options = OrdinalOptions::new().gender(gender);
+            Ordinal(n).to_words_locale(Locale::Czech, &options).unwrap()
+        };
+        for i in 0..31 {
+            assert_eq!(masculine[i], words(i + 1, Gender::Masculine));
+            assert_eq!(feminine[i], words(i + 1, Gender::Feminine));
+        }
+
+        assert_eq!("stý", words(100, Gender::Masculine));
+        assert_eq!("stá", words(100, Gender::Feminine));
+        assert_eq!("stý první", words(101, Gender::Masculine));
+        assert_eq!("dvoustý", words(200, Gender::Masculine));
+        assert_eq!("tisící", words(1000, Gender::Masculine));
+        assert_eq!("tisící", words(1000, Gender::Feminine));
+        assert_eq!(
+            "dvoutisící devítistá devadesátá devátá",
+            words(2999, Gender::Feminine)
+        );
+        assert_eq!("druhé", words(2, Gender::Neuter));
+    }
+}
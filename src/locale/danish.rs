@@ -0,0 +1,154 @@
+//! Danish ordinals are written as the number followed by a period: `5.`.
+//! Word forms put the units before the tens (`enogtyvende`, one-and-twentieth),
+//! use the vigesimal tens from 50 up (`halvtredsindstyvende`), and inflect 2 for gender:
+//! `anden` in the common gender, `andet` in the neuter. They cover 0 to 100.
+
+use super::{Gender, OrdinalOptions};
+use alloc::format;
+use alloc::string::{String, ToString};
+
+const UNITS: [&str; 10] = [
+    "", "en", "to", "tre", "fire", "fem", "seks", "syv", "otte", "ni",
+];
+
+const TENS: [&str; 10] = [
+    "",
+    "tiende",
+    "tyvende",
+    "tredivte",
+    "fyrretyvende",
+    "halvtredsindstyvende",
+    "tresindstyvende",
+    "halvfjerdsindstyvende",
+    "firsindstyvende",
+    "halvfemsindstyvende",
+];
+
+const ORDINALS: [&str; 20] = [
+    "nulte",
+    "første",
+    "anden",
+    "tredje",
+    "fjerde",
+    "femte",
+    "sjette",
+    "syvende",
+    "ottende",
+    "niende",
+    "tiende",
+    "ellevte",
+    "tolvte",
+    "trettende",
+    "fjortende",
+    "femtende",
+    "sekstende",
+    "syttende",
+    "attende",
+    "nittende",
+];
+
+/// Spells out `n` for values from 0 to 100.
+pub(crate) fn words(n: u128, options: &OrdinalOptions) -> Option<String> {
+    match n {
+        2 if options.gender == Gender::Neuter => Some("andet".to_string()),
+        0..=19 => Some(ORDINALS[n as usize].to_string()),
+        20..=99 => Some(match n % 10 {
+            0 => TENS[(n / 10) as usize].to_string(),
+            unit => format!("{}og{}", UNITS[unit as usize], TENS[(n / 10) as usize]),
+        }),
+        100 => Some("hundrede".to_string()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Gender, Locale, Ordinal, OrdinalOptions};
+
+    #[test]
+    fn test_danish_digits() {
+        for i in [1, 2, 5, 12, 21, 100] {
+            assert_eq!(
+                format!("{}.", i),
+                Ordinal(i).to_string_with_locale(Locale::Danish)
+            );
+        }
+    }
+
+    #[test]
+    fn test_danish_words() {
+        let danish_ordinals: [&str; 31] = [
+            "første",
+            "anden",
+            "tredje",
+            "fjerde",
+            "femte",
+            "sjette",
+            "syvende",
+            "ottende",
+            "niende",
+            "tiende",
+            "ellevte",
+            "tolvte",
+            "trettende",
+            "fjortende",
+            "femtende",
+            "sekstende",
+            "syttende",
+            "attende",
+            "nittende",
+            "tyvende",
+            "enogtyvende",
+            "toogtyvende",
+            "treogtyvende",
+            "fireogtyvende",
+            "femogtyvende",
+            "seksogtyvende",
+            "syvogtyvende",
+            "otteogtyvende",
+            "niogtyvende",
+            "tredivte",
+            "enogtredivte",
+        ];
+
+        let options = OrdinalOptions::new().gender(Gender::Common);
+        for (i, expected) in danish_ordinals.iter().enumerate() {
+            assert_eq!(
+                *expected,
+                Ordinal(i + 1)
+                    .to_words_locale(Locale::Danish, &options)
+                    .unwrap()
+            );
+        }
+
+        let words = |n: u32| {
+            Ordinal(n)
+                .to_words_locale(Locale::Danish, &options)
+                .unwrap()
+        };
+        assert_eq!("halvtredsindstyvende", words(50));
+        assert_eq!("femoghalvfemsindstyvende", words(95));
+        assert_eq!("hundrede", words(100));
+    }
+
+    #[test]
+    fn test_danish_second_gender() {
+        let common = OrdinalOptions::new().gender(Gender::Common);
+        let neuter = OrdinalOptions::new().gender(Gender::Neuter);
+
+        assert_eq!(
+            "anden",
+            Ordinal(2).to_words_locale(Locale::Danish, &common).unwrap()
+        );
+        assert_eq!(
+            "andet",
+            Ordinal(2).to_words_locale(Locale::Danish, &neuter).unwrap()
+        );
+        assert_eq!(
+            "toogtyvende",
+            Ordinal(22)
+                .to_words_locale(Locale::Danish, &neuter)
+                .unwrap()
+        );
+    }
+}
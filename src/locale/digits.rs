@@ -0,0 +1,53 @@
+//! Digit shaping for the locales whose scripts have their own digits.
+
+use alloc::string::{String, ToString};
+
+/// The digits a numeral is written with.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum Digits {
+    /// `0123456789`.
+    #[default]
+    Latin,
+    /// Arabic-Indic digits: `٠١٢٣٤٥٦٧٨٩`.
+    ArabicIndic,
+    /// Extended Arabic-Indic digits used for Persian and Urdu: `۰۱۲۳۴۵۶۷۸۹`.
+    ExtendedArabicIndic,
+    /// Devanagari digits: `०१२३४५६७८९`.
+    Devanagari,
+    /// Bengali digits: `০১২৩৪৫৬৭৮৯`.
+    Bengali,
+    /// Thai digits: `๐๑๒๓๔๕๖๗๘๙`.
+    Thai,
+    /// Tamil digits: `௦௧௨௩௪௫௬௭௮௯`.
+    Tamil,
+}
+
+impl Digits {
+    fn zero(self) -> char {
+        match self {
+            Digits::Latin => '0',
+            Digits::ArabicIndic => '\u{660}',
+            Digits::ExtendedArabicIndic => '\u{6f0}',
+            Digits::Devanagari => '\u{966}',
+            Digits::Bengali => '\u{9e6}',
+            Digits::Thai => '\u{e50}',
+            Digits::Tamil => '\u{be6}',
+        }
+    }
+}
+
+/// Replaces the ASCII digits in `text` with those of `digits`.
+pub(crate) fn shape(text: &str, digits: Digits) -> String {
+    if digits == Digits::Latin {
+        return text.to_string();
+    }
+    let zero = digits.zero() as u32;
+    text.chars()
+        .map(|c| match c.to_digit(10) {
+            Some(digit) => char::from_u32(zero + digit).unwrap_or(c),
+            None => c,
+        })
+        .collect()
+}
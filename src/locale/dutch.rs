@@ -0,0 +1,89 @@
+//! Dutch ordinals take `e` in the common style (`1e`, `2e`, `8e`),
+//! or the full ending in the formal style: `ste` for `eerste`, `achtste`, `twintigste`,
+//! `honderdste` and `duizendste`, and `de` for the rest (`tweede`, `negende`, `elfde`).
+
+use super::{Number, OrdinalOptions};
+use alloc::format;
+use alloc::string::String;
+
+/// Suffix style for Dutch ordinals.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum DutchStyle {
+    /// `1e`, `2e`, `8e`.
+    #[default]
+    Short,
+    /// `1ste`, `2de`, `8ste`.
+    Long,
+}
+
+pub(crate) fn format(number: &Number, options: &OrdinalOptions) -> String {
+    match options.dutch_style {
+        DutchStyle::Short => format!("{}e", number),
+        DutchStyle::Long => format!("{}{}", number, long_suffix(number)),
+    }
+}
+
+fn long_suffix(number: &Number) -> &'static str {
+    match number.last_two() {
+        0 if number.digits() != "0" => "ste",
+        1 | 8 => "ste",
+        20.. => "ste",
+        _ => "de",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::locale::DutchStyle;
+    use crate::{Locale, Ordinal, OrdinalOptions};
+
+    #[test]
+    fn test_dutch_short() {
+        let options = OrdinalOptions::new().dutch_style(DutchStyle::Short);
+        for i in 1..=130 {
+            assert_eq!(
+                format!("{}e", i),
+                Ordinal(i).to_string_with_locale_opts(Locale::Dutch, &options)
+            );
+        }
+        assert_eq!("8e", Ordinal(8).to_string_with_locale(Locale::Dutch));
+    }
+
+    #[test]
+    fn test_dutch_long() {
+        let long_ordinals: [&str; 130] = [
+            "1ste", "2de", "3de", "4de", "5de", "6de", "7de", "8ste", "9de", "10de", "11de",
+            "12de", "13de", "14de", "15de", "16de", "17de", "18de", "19de", "20ste", "21ste",
+            "22ste", "23ste", "24ste", "25ste", "26ste", "27ste", "28ste", "29ste", "30ste",
+            "31ste", "32ste", "33ste", "34ste", "35ste", "36ste", "37ste", "38ste", "39ste",
+            "40ste", "41ste", "42ste", "43ste", "44ste", "45ste", "46ste", "47ste", "48ste",
+            "49ste", "50ste", "51ste", "52ste", "53ste", "54ste", "55ste", "56ste", "57ste",
+            "58ste", "59ste", "60ste", "61ste", "62ste", "63ste", "64ste", "65ste", "66ste",
+            "67ste", "68ste", "69ste", "70ste", "71ste", "72ste", "73ste", "74ste", "75ste",
+            "76ste", "77ste", "78ste", "79ste", "80ste", "81ste", "82ste", "83ste", "84ste",
+            "85ste", "86ste", "87ste", "88ste", "89ste", "90ste", "91ste", "92ste", "93ste",
+            "94ste", "95ste", "96ste", "97ste", "98ste", "99ste", "100ste", "101ste", "102de",
+            "103de", "104de", "105de", "106de", "107de", "108ste", "109de", "110de", "111de",
+            "112de", "113de", "114de", "115de", "116de", "117de", "118de", "119de", "120ste",
+            "121ste", "122ste", "123ste", "124ste", "125ste", "126ste", "127ste", "128ste",
+            "129ste", "130ste",
+        ];
+
+        let options = OrdinalOptions::new().dutch_style(DutchStyle::Long);
+        for (i, expected) in long_ordinals.iter().enumerate() {
+            assert_eq!(
+                *expected,
+                Ordinal(i + 1).to_string_with_locale_opts(Locale::Dutch, &options)
+            );
+        }
+        assert_eq!(
+            "0de",
+            Ordinal(0).to_string_with_locale_opts(Locale::Dutch, &options)
+        );
+        assert_eq!(
+            "1000ste",
+            Ordinal(1000).to_string_with_locale_opts(Locale::Dutch, &options)
+        );
+    }
+}
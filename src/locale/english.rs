@@ -0,0 +1,304 @@
+use crate::locale::{Number, OrdinalCategory};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Returns the English suffix for a number given the value of its last two digits.
+pub(crate) const fn suffix(last_two: u8) -> &'static str {
+    match (last_two % 10, last_two) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    }
+}
+
+pub(crate) fn category(number: &Number) -> OrdinalCategory {
+    match suffix(number.last_two()) {
+        "st" => OrdinalCategory::One,
+        "nd" => OrdinalCategory::Two,
+        "rd" => OrdinalCategory::Few,
+        _ => OrdinalCategory::Other,
+    }
+}
+
+const UNITS: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+const TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+/// Short scale names for each group of three digits, enough for all of `u128`.
+const SCALES: [&str; 13] = [
+    "",
+    "thousand",
+    "million",
+    "billion",
+    "trillion",
+    "quadrillion",
+    "quintillion",
+    "sextillion",
+    "septillion",
+    "octillion",
+    "nonillion",
+    "decillion",
+    "undecillion",
+];
+
+/// Ordinals that aren't the cardinal plus `th`.
+const IRREGULAR: [(&str, &str); 7] = [
+    ("one", "first"),
+    ("two", "second"),
+    ("three", "third"),
+    ("five", "fifth"),
+    ("eight", "eighth"),
+    ("nine", "ninth"),
+    ("twelve", "twelfth"),
+];
+
+/// Spells out `n` the American way, without `and`: `one hundred twenty-first`.
+pub(crate) fn words(n: u128) -> Option<String> {
+    let mut out = String::new();
+    write_words(n, &mut out).ok()?;
+    Some(out)
+}
+
+/// Writes the words of `n` to `out` piece by piece, without allocating. Only the last
+/// word is an ordinal, so each group of three digits is told whether it comes last.
+pub(crate) fn write_words<W: fmt::Write + ?Sized>(n: u128, out: &mut W) -> fmt::Result {
+    if n == 0 {
+        return out.write_str("zeroth");
+    }
+    let mut groups = [0usize; SCALES.len()];
+    let mut rest = n;
+    for group in &mut groups {
+        *group = (rest % 1000) as usize;
+        rest /= 1000;
+    }
+    let lowest = groups.iter().position(|&group| group > 0).unwrap_or(0);
+    let mut first = true;
+    for (scale, &group) in groups.iter().enumerate().rev() {
+        if group == 0 {
+            continue;
+        }
+        if !first {
+            out.write_char(' ')?;
+        }
+        first = false;
+        let last = scale == lowest;
+        write_below_thousand(group, last && scale == 0, out)?;
+        if scale > 0 {
+            out.write_char(' ')?;
+            write_word(SCALES[scale], last, out)?;
+        }
+    }
+    Ok(())
+}
+
+/// Writes `n`, from 1 to 999, making its last word an ordinal if `ordinal`.
+fn write_below_thousand<W: fmt::Write + ?Sized>(
+    n: usize,
+    ordinal: bool,
+    out: &mut W,
+) -> fmt::Result {
+    let rest = n % 100;
+    if n >= 100 {
+        write!(out, "{} ", UNITS[n / 100])?;
+        write_word("hundred", ordinal && rest == 0, out)?;
+        if rest > 0 {
+            out.write_char(' ')?;
+        }
+    }
+    match rest {
+        0 => Ok(()),
+        1..=19 => write_word(UNITS[rest], ordinal, out),
+        _ if rest.is_multiple_of(10) => write_word(TENS[rest / 10], ordinal, out),
+        _ => {
+            write!(out, "{}-", TENS[rest / 10])?;
+            write_word(UNITS[rest % 10], ordinal, out)
+        }
+    }
+}
+
+/// Writes a cardinal word, or its ordinal form if `ordinal`.
+fn write_word<W: fmt::Write + ?Sized>(cardinal: &str, ordinal: bool, out: &mut W) -> fmt::Result {
+    if !ordinal {
+        return out.write_str(cardinal);
+    }
+    if let Some((_, ordinal)) = IRREGULAR.iter().find(|(word, _)| *word == cardinal) {
+        return out.write_str(ordinal);
+    }
+    match cardinal.strip_suffix('y') {
+        Some(stem) => write!(out, "{}ieth", stem),
+        None => write!(out, "{}th", cardinal),
+    }
+}
+
+fn cardinal_word(ordinal: &str) -> Option<String> {
+    if let Some((cardinal, _)) = IRREGULAR.iter().find(|(_, word)| *word == ordinal) {
+        return Some(cardinal.to_string());
+    }
+    if let Some(stem) = ordinal.strip_suffix("ieth") {
+        return Some(format!("{}y", stem));
+    }
+    ordinal.strip_suffix("th").map(str::to_string)
+}
+
+/// Whether `word` can be part of a spelled-out ordinal, in any case: a number word,
+/// cardinal or ordinal, or `and`.
+pub(crate) fn is_number_word(word: &str) -> bool {
+    let word = word.to_lowercase();
+    let cardinal = cardinal_word(&word).unwrap_or_default();
+    word == "and"
+        || [word.as_str(), cardinal.as_str()].iter().any(|word| {
+            *word == "hundred"
+                || UNITS.contains(word)
+                || TENS[2..].contains(word)
+                || SCALES[1..].contains(word)
+        })
+}
+
+fn tokens(text: &str) -> impl Iterator<Item = &str> {
+    text.split(|c: char| c.is_whitespace() || c == '-')
+        .filter(|token| !token.is_empty() && *token != "and")
+}
+
+/// Parses a spelled-out ordinal such as `twenty-second`, accepting any case,
+/// spaces in place of hyphens and a British `and`.
+/// Returns `None` for anything [`words`] wouldn't produce up to those differences,
+/// including values that overflow `u128`.
+pub(crate) fn parse_words(text: &str) -> Option<u128> {
+    let text = text.trim().to_lowercase();
+    let mut words: Vec<String> = tokens(&text).map(str::to_string).collect();
+    let last = words.pop()?;
+    words.push(cardinal_word(&last)?);
+
+    let (mut total, mut current) = (0u128, 0u128);
+    for word in &words {
+        if let Some(unit) = UNITS.iter().position(|unit| unit == word) {
+            current = current.checked_add(unit as u128)?;
+        } else if let Some(tens) = TENS
+            .iter()
+            .position(|tens| !tens.is_empty() && tens == word)
+        {
+            current = current.checked_add(tens as u128 * 10)?;
+        } else if word == "hundred" {
+            current = current.checked_mul(100)?;
+        } else {
+            let scale = SCALES
+                .iter()
+                .position(|scale| !scale.is_empty() && scale == word)?;
+            let group = current.checked_mul(1000u128.checked_pow(scale as u32)?)?;
+            total = total.checked_add(group)?;
+            current = 0;
+        }
+    }
+    let n = total.checked_add(current)?;
+
+    let canonical = self::words(n)?;
+    tokens(&canonical).eq(tokens(&text)).then_some(n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_words;
+    use crate::{Locale, Ordinal, OrdinalOptions};
+
+    #[test]
+    fn test_english_words() {
+        let expected: [&str; 31] = [
+            "zeroth",
+            "first",
+            "second",
+            "third",
+            "fourth",
+            "fifth",
+            "sixth",
+            "seventh",
+            "eighth",
+            "ninth",
+            "tenth",
+            "eleventh",
+            "twelfth",
+            "thirteenth",
+            "fourteenth",
+            "fifteenth",
+            "sixteenth",
+            "seventeenth",
+            "eighteenth",
+            "nineteenth",
+            "twentieth",
+            "twenty-first",
+            "twenty-second",
+            "twenty-third",
+            "twenty-fourth",
+            "twenty-fifth",
+            "twenty-sixth",
+            "twenty-seventh",
+            "twenty-eighth",
+            "twenty-ninth",
+            "thirtieth",
+        ];
+        let words = |n: u128| {
+            Ordinal(n)
+                .to_words_locale(Locale::English, &OrdinalOptions::default())
+                .unwrap()
+        };
+        for (i, expected) in expected.iter().enumerate() {
+            assert_eq!(*expected, words(i as u128));
+        }
+
+        assert_eq!("ninety-ninth", words(99));
+        assert_eq!("one hundredth", words(100));
+        assert_eq!("one hundred first", words(101));
+        assert_eq!("one thousandth", words(1000));
+        assert_eq!("twelve thousand three hundred forty-fifth", words(12_345));
+        assert_eq!("one millionth", words(1_000_000));
+        assert_eq!("one million one thousand twelfth", words(1_001_012));
+        assert!(words(u128::MAX).starts_with("three hundred forty undecillion "));
+        assert!(words(u128::MAX).ends_with(" four hundred fifty-fifth"));
+        assert!(Ordinal(-1)
+            .to_words_locale(Locale::English, &OrdinalOptions::default())
+            .is_err());
+    }
+
+    #[test]
+    fn test_english_parse_words() {
+        for n in (0..2000).chain([1_000_000, 1_001_012, u64::MAX as u128, u128::MAX]) {
+            let words = super::words(n).unwrap();
+            assert_eq!(Some(n), parse_words(&words), "{}", words);
+        }
+
+        assert_eq!(Some(22), parse_words("Twenty Second"));
+        assert_eq!(Some(101), parse_words("one hundred and first"));
+        assert_eq!(None, parse_words(""));
+        assert_eq!(None, parse_words("twenty-two"));
+        assert_eq!(None, parse_words("one one first"));
+        assert_eq!(None, parse_words("twenty-twentieth"));
+        assert_eq!(None, parse_words("hundredth"));
+        assert_eq!(None, parse_words("one thousand undecillionth"));
+    }
+}
@@ -0,0 +1,175 @@
+//! Esperanto ordinals are the cardinal with the adjective ending `-a`, hyphenated after
+//! the number (`3-a`) and joined to it in words (`tria`, `dudek-unua`). Like any
+//! adjective they take `-n` in the accusative and `-j` in the plural: `3-an`, `triaj`,
+//! `triajn`.
+//!
+//! Being completely regular, this module is the smallest complete locale: a `format`
+//! for the digit form, a `words` returning `None` outside its range, both reading
+//! only the [`OrdinalOptions`] that matter to the language, and a `Locale` variant
+//! dispatching to them.
+
+use super::{Case, GrammaticalNumber, Number, OrdinalOptions};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+const UNITS: [&str; 10] = [
+    "nul", "unu", "du", "tri", "kvar", "kvin", "ses", "sep", "ok", "naŭ",
+];
+
+pub(crate) fn format(number: &Number, options: &OrdinalOptions) -> String {
+    format!("{}-{}", number, ending(options))
+}
+
+/// Spells out `n` for values below one million.
+pub(crate) fn words(n: u128, options: &OrdinalOptions) -> Option<String> {
+    if n >= 1_000_000 {
+        return None;
+    }
+    let n = n as usize;
+    let mut parts = Vec::new();
+    if n >= 1000 {
+        if n >= 2000 {
+            parts.extend(below_thousand(n / 1000));
+        }
+        parts.push("mil".to_string());
+    }
+    parts.extend(below_thousand(n % 1000));
+    if parts.is_empty() {
+        parts.push(UNITS[0].to_string());
+    }
+    Some(format!("{}{}", parts.join("-"), ending(options)))
+}
+
+fn below_thousand(n: usize) -> Vec<String> {
+    let mut parts = Vec::new();
+    for (digit, unit) in [(n / 100, "cent"), (n / 10 % 10, "dek")] {
+        match digit {
+            0 => {}
+            1 => parts.push(unit.to_string()),
+            _ => parts.push(format!("{}{}", UNITS[digit], unit)),
+        }
+    }
+    if !n.is_multiple_of(10) {
+        parts.push(UNITS[n % 10].to_string());
+    }
+    parts
+}
+
+fn ending(options: &OrdinalOptions) -> &'static str {
+    match (options.number, options.case) {
+        (GrammaticalNumber::Singular, Case::Accusative) => "an",
+        (GrammaticalNumber::Plural, Case::Accusative) => "ajn",
+        (GrammaticalNumber::Plural, _) => "aj",
+        _ => "a",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Case, GrammaticalNumber, Locale, Ordinal, OrdinalOptions};
+
+    #[test]
+    fn test_esperanto_digits() {
+        for i in 1..=30 {
+            assert_eq!(
+                format!("{}-a", i),
+                Ordinal(i).to_string_with_locale(Locale::Esperanto)
+            );
+        }
+    }
+
+    #[test]
+    fn test_esperanto_words() {
+        let esperanto: [&str; 30] = [
+            "unua",
+            "dua",
+            "tria",
+            "kvara",
+            "kvina",
+            "sesa",
+            "sepa",
+            "oka",
+            "naŭa",
+            "deka",
+            "dek-unua",
+            "dek-dua",
+            "dek-tria",
+            "dek-kvara",
+            "dek-kvina",
+            "dek-sesa",
+            "dek-sepa",
+            "dek-oka",
+            "dek-naŭa",
+            "dudeka",
+            "dudek-unua",
+            "dudek-dua",
+            "dudek-tria",
+            "dudek-kvara",
+            "dudek-kvina",
+            "dudek-sesa",
+            "dudek-sepa",
+            "dudek-oka",
+            "dudek-naŭa",
+            "trideka",
+        ];
+
+        let options = OrdinalOptions::new();
+        for (i, expected) in esperanto.iter().enumerate() {
+            assert_eq!(
+                *expected,
+                Ordinal(i + 1)
+                    .to_words_locale(Locale::Esperanto, &options)
+                    .unwrap()
+            );
+        }
+
+        let words = |n: u32| {
+            Ordinal(n)
+                .to_words_locale(Locale::Esperanto, &options)
+                .unwrap()
+        };
+        assert_eq!("nula", words(0));
+        assert_eq!("centa", words(100));
+        assert_eq!("ducent-tridek-kvina", words(235));
+        assert_eq!("mila", words(1000));
+        assert_eq!("du-mil-dudek-kvara", words(2024));
+    }
+
+    #[test]
+    fn test_esperanto_inflection() {
+        let accusative = OrdinalOptions::new().case(Case::Accusative);
+        let plural = OrdinalOptions::new().number(GrammaticalNumber::Plural);
+        let plural_accusative = plural.clone().case(Case::Accusative);
+
+        let ordinal = Ordinal(3);
+        assert_eq!(
+            "3-an",
+            ordinal.to_string_with_locale_opts(Locale::Esperanto, &accusative)
+        );
+        assert_eq!(
+            "3-aj",
+            ordinal.to_string_with_locale_opts(Locale::Esperanto, &plural)
+        );
+        assert_eq!(
+            "3-ajn",
+            ordinal.to_string_with_locale_opts(Locale::Esperanto, &plural_accusative)
+        );
+        assert_eq!(
+            "trian",
+            ordinal
+                .to_words_locale(Locale::Esperanto, &accusative)
+                .unwrap()
+        );
+        assert_eq!(
+            "triaj",
+            ordinal.to_words_locale(Locale::Esperanto, &plural).unwrap()
+        );
+        assert_eq!(
+            "dudek-unuajn",
+            Ordinal(21)
+                .to_words_locale(Locale::Esperanto, &plural_accusative)
+                .unwrap()
+        );
+    }
+}
@@ -0,0 +1,128 @@
+//! Estonian ordinals are written as the number followed by a period: `3.`.
+//!
+//! Word forms cover 1 to 1000. Only the last component of a compound is ordinal, the
+//! ones before it take the genitive: `kahekümne esimene`, `saja kolmas`. 1 and 2 are
+//! irregular (`esimene`, `teine`) and the rest build on the genitive stem with `-s`:
+//! `kolmas`, `kümnes`, `kahekümnes`, `sajas`.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+
+/// The ordinals from 1 to 10.
+const ORDINALS: [&str; 11] = [
+    "", "esimene", "teine", "kolmas", "neljas", "viies", "kuues", "seitsmes", "kaheksas",
+    "üheksas", "kümnes",
+];
+
+/// The genitive of the cardinals from 1 to 9, which lead teens, tens and hundreds.
+const GENITIVES: [&str; 10] = [
+    "", "ühe", "kahe", "kolme", "nelja", "viie", "kuue", "seitsme", "kaheksa", "üheksa",
+];
+
+/// Spells out `n` for values from 1 to 1000.
+pub(crate) fn words(n: u128) -> Option<String> {
+    let n = match n {
+        1..=999 => n as usize,
+        1000 => return Some("tuhandes".to_string()),
+        _ => return None,
+    };
+    let (hundreds, rest) = (n / 100, n % 100);
+    let multiplier = if hundreds > 1 {
+        GENITIVES[hundreds]
+    } else {
+        ""
+    };
+    Some(match (hundreds, rest) {
+        (_, 0) => format!("{}sajas", multiplier),
+        (0, _) => below_hundred(rest),
+        _ => format!("{}saja {}", multiplier, below_hundred(rest)),
+    })
+}
+
+fn below_hundred(n: usize) -> String {
+    let (tens, units) = (n / 10, n % 10);
+    match (tens, units) {
+        (0, _) | (1, 0) => ORDINALS[n].to_string(),
+        (1, _) => format!("{}teistkümnes", GENITIVES[units]),
+        (_, 0) => format!("{}kümnes", GENITIVES[tens]),
+        _ => format!("{}kümne {}", GENITIVES[tens], ORDINALS[units]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Locale, Ordinal, OrdinalOptions};
+
+    #[test]
+    fn test_estonian_digits() {
+        for i in [1, 2, 3, 21, 100, 1000] {
+            assert_eq!(
+                format!("{}.", i),
+                Ordinal(i).to_string_with_locale(Locale::Estonian)
+            );
+        }
+        assert_eq!("-1.", Ordinal(-1).to_string_with_locale(Locale::Estonian));
+    }
+
+    #[test]
+    fn test_estonian_words() {
+        let estonian_ordinals: [&str; 31] = [
+            "esimene",
+            "teine",
+            "kolmas",
+            "neljas",
+            "viies",
+            "kuues",
+            "seitsmes",
+            "kaheksas",
+            "üheksas",
+            "kümnes",
+            "üheteistkümnes",
+            "kaheteistkümnes",
+            "kolmeteistkümnes",
+            "neljateistkümnes",
+            "viieteistkümnes",
+            "kuueteistkümnes",
+            "seitsmeteistkümnes",
+            "kaheksateistkümnes",
+            "üheksateistkümnes",
+            "kahekümnes",
+            "kahekümne esimene",
+            "kahekümne teine",
+            "kahekümne kolmas",
+            "kahekümne neljas",
+            "kahekümne viies",
+            "kahekümne kuues",
+            "kahekümne seitsmes",
+            "kahekümne kaheksas",
+            "kahekümne üheksas",
+            "kolmekümnes",
+            "kolmekümne esimene",
+        ];
+
+        let options = OrdinalOptions::new();
+        for (i, expected) in estonian_ordinals.iter().enumerate() {
+            assert_eq!(
+                *expected,
+                Ordinal(i + 1)
+                    .to_words_locale(Locale::Estonian, &options)
+                    .unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_estonian_hundreds_and_thousand() {
+        let options = OrdinalOptions::new();
+        let words = |n: u32| Ordinal(n).to_words_locale(Locale::Estonian, &options);
+        assert_eq!("üheksakümne üheksas", words(99).unwrap());
+        assert_eq!("sajas", words(100).unwrap());
+        assert_eq!("saja esimene", words(101).unwrap());
+        assert_eq!("saja kaheteistkümnes", words(112).unwrap());
+        assert_eq!("kahesajas", words(200).unwrap());
+        assert_eq!("kolmesaja neljakümne viies", words(345).unwrap());
+        assert_eq!("tuhandes", words(1000).unwrap());
+        assert!(words(0).is_err());
+        assert!(words(1001).is_err());
+    }
+}
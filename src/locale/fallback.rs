@@ -0,0 +1,139 @@
+//! Resolution of locales that aren't compiled in to the closest one that is.
+
+use super::Locale;
+
+impl Locale {
+    /// Returns the locales tried, in order, when this one isn't available.
+    /// Every chain ends in English, which is always available.
+    pub fn fallbacks(self) -> &'static [Locale] {
+        match self {
+            Locale::English => &[],
+            Locale::Japanese => &[Locale::Chinese, Locale::English],
+            Locale::Chinese => &[Locale::Japanese, Locale::English],
+            Locale::Norwegian => &[Locale::Danish, Locale::English],
+            Locale::Danish => &[Locale::Norwegian, Locale::English],
+            Locale::Slovak => &[Locale::Czech, Locale::English],
+            Locale::Ukrainian => &[Locale::Russian, Locale::English],
+            Locale::Catalan => &[Locale::Spanish, Locale::English],
+            _ => &[Locale::English],
+        }
+    }
+
+    /// Returns whether this locale's formatting is compiled in. Japanese, Chinese and
+    /// Korean need the `cjk` feature; without it they format as their fallback, English.
+    pub fn is_available(self) -> bool {
+        cfg!(feature = "cjk")
+            || !matches!(self, Locale::Japanese | Locale::Chinese | Locale::Korean)
+    }
+
+    /// Returns the locale actually used for formatting: this one if it's available,
+    /// otherwise the first available one among its [`fallbacks`](Locale::fallbacks).
+    /// ```rust
+    /// use ordinal_type::Locale;
+    ///
+    /// assert_eq!(Locale::French.resolve(), Locale::French);
+    /// ```
+    pub fn resolve(self) -> Locale {
+        resolve(self, Locale::is_available)
+    }
+
+    /// Resolves a BCP 47 or POSIX locale name, falling back from a regional variant
+    /// to its language (`fr-CH` to French) and from an unknown language to English.
+    /// ```rust
+    /// use ordinal_type::Locale;
+    ///
+    /// assert_eq!(Locale::resolve_tag("fr-CH"), Locale::French);
+    /// assert_eq!(Locale::resolve_tag("xx-YY"), Locale::English);
+    /// ```
+    pub fn resolve_tag(tag: &str) -> Locale {
+        Locale::from_tag(tag).unwrap_or(Locale::English).resolve()
+    }
+}
+
+fn resolve(locale: Locale, available: impl Fn(Locale) -> bool) -> Locale {
+    core::iter::once(locale)
+        .chain(locale.fallbacks().iter().copied())
+        .find(|locale| available(*locale))
+        .unwrap_or(Locale::English)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::resolve;
+    use crate::{Locale, Ordinal, OrdinalCategory, OrdinalOptions};
+
+    #[test]
+    fn test_resolve() {
+        let without_cjk =
+            |locale: Locale| !matches!(locale, Locale::Japanese | Locale::Chinese | Locale::Korean);
+        assert_eq!(Locale::English, resolve(Locale::Japanese, without_cjk));
+        assert_eq!(Locale::English, resolve(Locale::Korean, without_cjk));
+        assert_eq!(Locale::French, resolve(Locale::French, without_cjk));
+
+        let without_norwegian = |locale: Locale| locale != Locale::Norwegian;
+        assert_eq!(
+            Locale::Danish,
+            resolve(Locale::Norwegian, without_norwegian)
+        );
+
+        let nothing = |_: Locale| false;
+        assert_eq!(Locale::English, resolve(Locale::Slovak, nothing));
+    }
+
+    #[test]
+    fn test_resolve_tag() {
+        assert_eq!(Locale::French, Locale::resolve_tag("fr-CH"));
+        assert_eq!(Locale::German, Locale::resolve_tag("de_CH.UTF-8"));
+        assert_eq!(Locale::English, Locale::resolve_tag("en-GB"));
+        assert_eq!(Locale::English, Locale::resolve_tag("tlh"));
+    }
+
+    #[test]
+    fn test_fallbacks_end_in_english() {
+        for locale in [
+            Locale::Japanese,
+            Locale::Norwegian,
+            Locale::Catalan,
+            Locale::Welsh,
+        ] {
+            assert_eq!(Some(&Locale::English), locale.fallbacks().last());
+        }
+        assert!(Locale::English.fallbacks().is_empty());
+    }
+
+    #[cfg(not(feature = "cjk"))]
+    #[test]
+    fn test_disabled_locale_falls_back() {
+        assert!(!Locale::Japanese.is_available());
+        assert_eq!(Locale::English, Locale::Japanese.resolve());
+        for locale in [Locale::Japanese, Locale::Chinese, Locale::Korean] {
+            assert_eq!(Locale::English, locale.resolve());
+            assert_eq!("3rd", Ordinal(3).to_string_with_locale(locale));
+        }
+        assert_eq!(
+            OrdinalCategory::Few,
+            Ordinal(3).plural_category(Locale::Korean)
+        );
+    }
+
+    #[test]
+    fn test_words_do_not_fall_back() {
+        let options = OrdinalOptions::new();
+        for locale in [Locale::Japanese, Locale::Chinese, Locale::Korean] {
+            let err = Ordinal(3).to_words_locale(locale, &options).unwrap_err();
+            assert_eq!(locale, err.locale());
+        }
+    }
+
+    #[cfg(feature = "cjk")]
+    #[test]
+    fn test_enabled_locale_resolves_to_itself() {
+        assert!(Locale::Japanese.is_available());
+        assert_eq!(Locale::Japanese, Locale::Japanese.resolve());
+        assert_eq!("第3", Ordinal(3).to_string_with_locale(Locale::Japanese));
+        assert_eq!(
+            OrdinalCategory::Other,
+            Ordinal(3).plural_category(Locale::Korean)
+        );
+    }
+}
@@ -0,0 +1,119 @@
+//! Filipino (Tagalog) ordinals prefix `ika` to the number: `ika-3` in digits, `ikatlo`
+//! in words. The small numbers contract with the prefix (`ikalawa`, `ikatlo`) and the
+//! first is usually `una`. A minus sign goes in front of the prefix (`-ika-3`), and CLDR
+//! puts only 1 in the one category.
+//!
+//! Spelled forms cover 1–10. Larger values are written `ika-21` even in word form, which
+//! is how they usually appear; the spelled cardinals mix native and Spanish-derived
+//! words too freely to pick one.
+
+use super::{Number, OrdinalCategory, OrdinalOptions};
+use alloc::format;
+use alloc::string::{String, ToString};
+
+/// The word for "first" in Filipino.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum FilipinoFirst {
+    /// `una`.
+    #[default]
+    Una,
+    /// `ikauna`.
+    Ikauna,
+}
+
+const WORDS: [&str; 11] = [
+    "", "ikauna", "ikalawa", "ikatlo", "ikaapat", "ikalima", "ikaanim", "ikapito", "ikawalo",
+    "ikasiyam", "ikasampu",
+];
+
+pub(crate) fn format(number: &Number) -> String {
+    format!("{}ika-{}", number.sign(), number.digits())
+}
+
+pub(crate) fn category(number: &Number) -> OrdinalCategory {
+    match number.magnitude() {
+        Some(1) => OrdinalCategory::One,
+        _ => OrdinalCategory::Other,
+    }
+}
+
+/// Spells out 1–10 and writes larger values with digits.
+pub(crate) fn words(n: u128, options: &OrdinalOptions) -> Option<String> {
+    match n {
+        0 => None,
+        1 if options.filipino_first == FilipinoFirst::Una => Some("una".to_string()),
+        1..=10 => Some(WORDS[n as usize].to_string()),
+        _ => Some(format!("ika-{}", n)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::locale::FilipinoFirst;
+    use crate::{Locale, Ordinal, OrdinalCategory, OrdinalOptions};
+
+    #[test]
+    fn test_filipino_digits() {
+        for i in (1..=10).chain([21]) {
+            assert_eq!(
+                format!("ika-{}", i),
+                Ordinal(i).to_string_with_locale(Locale::Filipino)
+            );
+        }
+        assert_eq!(
+            "-ika-1",
+            Ordinal(-1).to_string_with_locale(Locale::Filipino)
+        );
+    }
+
+    #[test]
+    fn test_filipino_category() {
+        assert_eq!(
+            OrdinalCategory::One,
+            Ordinal(1).plural_category(Locale::Filipino)
+        );
+        for n in [0, 2, 11, 21] {
+            assert_eq!(
+                OrdinalCategory::Other,
+                Ordinal(n).plural_category(Locale::Filipino)
+            );
+        }
+    }
+
+    #[test]
+    fn test_filipino_words() {
+        let filipino: [&str; 10] = [
+            "una", "ikalawa", "ikatlo", "ikaapat", "ikalima", "ikaanim", "ikapito", "ikawalo",
+            "ikasiyam", "ikasampu",
+        ];
+
+        let options = OrdinalOptions::new();
+        for (i, expected) in filipino.iter().enumerate() {
+            assert_eq!(
+                *expected,
+                Ordinal(i + 1)
+                    .to_words_locale(Locale::Filipino, &options)
+                    .unwrap()
+            );
+        }
+        assert_eq!(
+            "ikauna",
+            Ordinal(1)
+                .to_words_locale(
+                    Locale::Filipino,
+                    &options.clone().filipino_first(FilipinoFirst::Ikauna)
+                )
+                .unwrap()
+        );
+        assert_eq!(
+            "ika-21",
+            Ordinal(21)
+                .to_words_locale(Locale::Filipino, &options)
+                .unwrap()
+        );
+        assert!(Ordinal(0)
+            .to_words_locale(Locale::Filipino, &options)
+            .is_err());
+    }
+}
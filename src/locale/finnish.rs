@@ -0,0 +1,148 @@
+//! Finnish ordinals are written as the number followed by a period: `3.`.
+//!
+//! Word forms are nominative and every part of a compound is ordinal:
+//! `kahdeskymmenesensimmäinen`. Other cases inflect each part with the same stems
+//! (`kolmannen`, `kolmannelle`), so they can be added as further stem tables
+//! selected by the case option; until then, they're reported as unsupported.
+
+use super::{Case, OrdinalOptions};
+use alloc::string::String;
+
+/// Ordinal stems used inside compounds, where 1 and 2 are regular.
+const STEMS: [&str; 10] = [
+    "",
+    "yhdes",
+    "kahdes",
+    "kolmas",
+    "neljäs",
+    "viides",
+    "kuudes",
+    "seitsemäs",
+    "kahdeksas",
+    "yhdeksäs",
+];
+
+/// Spells out `n` for values from 1 to 9999.
+pub(crate) fn words(n: u128, options: &OrdinalOptions) -> Option<String> {
+    if n == 0 || n >= 10_000 || options.case != Case::Nominative {
+        return None;
+    }
+    let n = n as usize;
+    let mut words = String::new();
+    for (multiplier, name) in [(1000, "tuhannes"), (100, "sadas")] {
+        match n / multiplier % 10 {
+            0 => {}
+            1 => words.push_str(name),
+            count => {
+                words.push_str(STEMS[count]);
+                words.push_str(name);
+            }
+        }
+    }
+    let rest = n % 100;
+    match rest {
+        0 => {}
+        11..=19 => {
+            words.push_str(STEMS[rest - 10]);
+            words.push_str("toista");
+        }
+        _ => {
+            if rest >= 20 {
+                words.push_str(STEMS[rest / 10]);
+            }
+            if rest >= 10 {
+                words.push_str("kymmenes");
+            }
+            words.push_str(match rest % 10 {
+                0 => "",
+                1 => "ensimmäinen",
+                2 => "toinen",
+                unit => STEMS[unit],
+            });
+        }
+    }
+    Some(words)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Case, Locale, Ordinal, OrdinalOptions};
+
+    #[test]
+    fn test_finnish_digits() {
+        for i in [1, 3, 10, 21, 100] {
+            assert_eq!(
+                format!("{}.", i),
+                Ordinal(i).to_string_with_locale(Locale::Finnish)
+            );
+        }
+    }
+
+    #[test]
+    fn test_finnish_words() {
+        let finnish_ordinals: [&str; 30] = [
+            "ensimmäinen",
+            "toinen",
+            "kolmas",
+            "neljäs",
+            "viides",
+            "kuudes",
+            "seitsemäs",
+            "kahdeksas",
+            "yhdeksäs",
+            "kymmenes",
+            "yhdestoista",
+            "kahdestoista",
+            "kolmastoista",
+            "neljästoista",
+            "viidestoista",
+            "kuudestoista",
+            "seitsemästoista",
+            "kahdeksastoista",
+            "yhdeksästoista",
+            "kahdeskymmenes",
+            "kahdeskymmenesensimmäinen",
+            "kahdeskymmenestoinen",
+            "kahdeskymmeneskolmas",
+            "kahdeskymmenesneljäs",
+            "kahdeskymmenesviides",
+            "kahdeskymmeneskuudes",
+            "kahdeskymmenesseitsemäs",
+            "kahdeskymmeneskahdeksas",
+            "kahdeskymmenesyhdeksäs",
+            "kolmaskymmenes",
+        ];
+
+        let options = OrdinalOptions::new();
+        for (i, expected) in finnish_ordinals.iter().enumerate() {
+            assert_eq!(
+                *expected,
+                Ordinal(i + 1)
+                    .to_words_locale(Locale::Finnish, &options)
+                    .unwrap()
+            );
+        }
+
+        let words = |n: u32| {
+            Ordinal(n)
+                .to_words_locale(Locale::Finnish, &options)
+                .unwrap()
+        };
+        assert_eq!("sadas", words(100));
+        assert_eq!("sadasensimmäinen", words(101));
+        assert_eq!("sadaskymmenes", words(110));
+        assert_eq!("kahdessadas", words(200));
+        assert_eq!("tuhannes", words(1000));
+    }
+
+    #[test]
+    fn test_finnish_words_unsupported() {
+        let genitive = OrdinalOptions::new().case(Case::Genitive);
+        assert!(Ordinal(3)
+            .to_words_locale(Locale::Finnish, &genitive)
+            .is_err());
+        assert!(Ordinal(0)
+            .to_words_locale(Locale::Finnish, &OrdinalOptions::new())
+            .is_err());
+    }
+}
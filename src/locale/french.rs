@@ -0,0 +1,273 @@
+//! French ordinals take `e` (`2e`, `21e`) except for the first, which agrees in gender:
+//! `1er`, `1re`. Both take an `s` in the plural (`1ers`, `2es`). The abbreviations
+//! follow the Imprimerie nationale; `1ère` and `2ème` are common but nonstandard.
+//!
+//! Word forms add `ième` to the last word of the cardinal (`vingt et unième`,
+//! `quatre-vingtième`, `deux centième`), in the traditional spelling that hyphenates
+//! only below a hundred. Only `premier`/`première` inflects for gender.
+//!
+//! The CLDR categories follow the suffix: one for 1 (`1er`), other for the rest.
+
+use super::{Gender, GrammaticalNumber, Number, OrdinalCategory, OrdinalOptions};
+use alloc::format;
+use alloc::string::{String, ToString};
+
+const UNITS: [&str; 20] = [
+    "", "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf", "dix", "onze",
+    "douze", "treize", "quatorze", "quinze", "seize", "dix-sept", "dix-huit", "dix-neuf",
+];
+
+const TENS: [&str; 7] = [
+    "",
+    "",
+    "vingt",
+    "trente",
+    "quarante",
+    "cinquante",
+    "soixante",
+];
+
+pub(crate) fn format(number: &Number, options: &OrdinalOptions) -> String {
+    let suffix = match (number.magnitude(), options.gender) {
+        (Some(1), Gender::Feminine) => "re",
+        (Some(1), _) => "er",
+        _ => "e",
+    };
+    let plural = if options.number == GrammaticalNumber::Plural {
+        "s"
+    } else {
+        ""
+    };
+    format!("{}{}{}", number, suffix, plural)
+}
+
+/// Spells out `n` for values below one million.
+pub(crate) fn category(number: &Number) -> OrdinalCategory {
+    match number.magnitude() {
+        Some(1) => OrdinalCategory::One,
+        _ => OrdinalCategory::Other,
+    }
+}
+
+pub(crate) fn words(n: u128, options: &OrdinalOptions) -> Option<String> {
+    let mut words = match n {
+        1 if options.gender == Gender::Feminine => "première".to_string(),
+        1 => "premier".to_string(),
+        2..=999_999 => {
+            let cardinal = cardinal(n as usize);
+            let split = cardinal.rfind([' ', '-']).map_or(0, |i| i + 1);
+            let (head, last) = cardinal.split_at(split);
+            let stem = match last {
+                "cinq" => "cinqu",
+                "neuf" => "neuv",
+                "cents" | "vingts" => &last[..last.len() - 1],
+                _ => last.strip_suffix('e').unwrap_or(last),
+            };
+            format!("{}{}ième", head, stem)
+        }
+        _ => return None,
+    };
+    if options.number == GrammaticalNumber::Plural {
+        words.push('s');
+    }
+    Some(words)
+}
+
+fn cardinal(n: usize) -> String {
+    let (thousands, rest) = (n / 1000, n % 1000);
+    let mut words = match thousands {
+        0 => String::new(),
+        1 => "mille".to_string(),
+        _ => format!("{} mille", below_thousand(thousands, false)),
+    };
+    if rest > 0 {
+        if !words.is_empty() {
+            words.push(' ');
+        }
+        words.push_str(&below_thousand(rest, true));
+    }
+    words
+}
+
+/// Spells out `n` below a thousand. `cents` and `quatre-vingts` only keep their `s` at
+/// the end of the number.
+fn below_thousand(n: usize, last: bool) -> String {
+    let (hundreds, rest) = (n / 100, n % 100);
+    let mut words = match hundreds {
+        0 => String::new(),
+        1 => "cent".to_string(),
+        _ if rest == 0 && last => format!("{} cents", UNITS[hundreds]),
+        _ => format!("{} cent", UNITS[hundreds]),
+    };
+    if rest > 0 {
+        if !words.is_empty() {
+            words.push(' ');
+        }
+        words.push_str(&below_hundred(rest, last));
+    }
+    words
+}
+
+fn below_hundred(n: usize, last: bool) -> String {
+    match (n / 10, n % 10) {
+        (0..=1, _) => UNITS[n].to_string(),
+        (tens @ 2..=6, 0) => TENS[tens].to_string(),
+        (tens @ 2..=6, 1) => format!("{} et un", TENS[tens]),
+        (tens @ 2..=6, unit) => format!("{}-{}", TENS[tens], UNITS[unit]),
+        (7, 1) => "soixante et onze".to_string(),
+        (7, unit) => format!("soixante-{}", UNITS[10 + unit]),
+        (8, 0) if last => "quatre-vingts".to_string(),
+        (8, 0) => "quatre-vingt".to_string(),
+        (_, _) => format!("quatre-vingt-{}", UNITS[n - 80]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Gender, GrammaticalNumber, Locale, Ordinal, OrdinalCategory, OrdinalOptions};
+
+    #[test]
+    fn test_french() {
+        let masculine = OrdinalOptions::new();
+        let feminine = OrdinalOptions::new().gender(Gender::Feminine);
+
+        assert_eq!("1er", Ordinal(1).to_string_with_locale(Locale::French));
+        assert_eq!(
+            "1re",
+            Ordinal(1).to_string_with_locale_opts(Locale::French, &feminine)
+        );
+        for i in 2..=100 {
+            let ordinal = Ordinal(i);
+            assert_eq!(
+                format!("{}e", i),
+                ordinal.to_string_with_locale_opts(Locale::French, &masculine)
+            );
+            assert_eq!(
+                format!("{}e", i),
+                ordinal.to_string_with_locale_opts(Locale::French, &feminine)
+            );
+        }
+        assert_eq!("0e", Ordinal(0).to_string_with_locale(Locale::French));
+    }
+
+    #[test]
+    fn test_french_category() {
+        assert_eq!(
+            OrdinalCategory::One,
+            Ordinal(1).plural_category(Locale::French)
+        );
+        for n in [0, 2, 11, 21, 101] {
+            assert_eq!(
+                OrdinalCategory::Other,
+                Ordinal(n).plural_category(Locale::French)
+            );
+        }
+    }
+
+    #[test]
+    fn test_french_plural() {
+        let plural = OrdinalOptions::new().number(GrammaticalNumber::Plural);
+        let format = |n: i32, options: &OrdinalOptions| {
+            Ordinal(n).to_string_with_locale_opts(Locale::French, options)
+        };
+
+        assert_eq!("1ers", format(1, &plural));
+        assert_eq!("1res", format(1, &plural.clone().gender(Gender::Feminine)));
+        assert_eq!("2es", format(2, &plural));
+    }
+
+    #[test]
+    fn test_french_words() {
+        let french: [&str; 31] = [
+            "premier",
+            "deuxième",
+            "troisième",
+            "quatrième",
+            "cinquième",
+            "sixième",
+            "septième",
+            "huitième",
+            "neuvième",
+            "dixième",
+            "onzième",
+            "douzième",
+            "treizième",
+            "quatorzième",
+            "quinzième",
+            "seizième",
+            "dix-septième",
+            "dix-huitième",
+            "dix-neuvième",
+            "vingtième",
+            "vingt et unième",
+            "vingt-deuxième",
+            "vingt-troisième",
+            "vingt-quatrième",
+            "vingt-cinquième",
+            "vingt-sixième",
+            "vingt-septième",
+            "vingt-huitième",
+            "vingt-neuvième",
+            "trentième",
+            "trente et unième",
+        ];
+
+        let options = OrdinalOptions::new();
+        for (i, expected) in french.iter().enumerate() {
+            assert_eq!(
+                *expected,
+                Ordinal(i + 1)
+                    .to_words_locale(Locale::French, &options)
+                    .unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_french_words_boundaries() {
+        let options = OrdinalOptions::new();
+        let words = |n: u32, options: &OrdinalOptions| {
+            Ordinal(n).to_words_locale(Locale::French, options).unwrap()
+        };
+
+        let french: [(u32, &str); 21] = [
+            (70, "soixante-dixième"),
+            (71, "soixante et onzième"),
+            (77, "soixante-dix-septième"),
+            (80, "quatre-vingtième"),
+            (81, "quatre-vingt-unième"),
+            (90, "quatre-vingt-dixième"),
+            (91, "quatre-vingt-onzième"),
+            (99, "quatre-vingt-dix-neuvième"),
+            (100, "centième"),
+            (101, "cent unième"),
+            (200, "deux centième"),
+            (201, "deux cent unième"),
+            (999, "neuf cent quatre-vingt-dix-neuvième"),
+            (1000, "millième"),
+            (1001, "mille unième"),
+            (2000, "deux millième"),
+            (9999, "neuf mille neuf cent quatre-vingt-dix-neuvième"),
+            (10_000, "dix millième"),
+            (80_000, "quatre-vingt millième"),
+            (200_000, "deux cent millième"),
+            (
+                999_999,
+                "neuf cent quatre-vingt-dix-neuf mille neuf cent quatre-vingt-dix-neuvième",
+            ),
+        ];
+        for (n, expected) in french {
+            assert_eq!(expected, words(n, &options));
+        }
+
+        let feminine = OrdinalOptions::new().gender(Gender::Feminine);
+        assert_eq!("première", words(1, &feminine));
+        assert_eq!("vingt et unième", words(21, &feminine));
+        let plural = OrdinalOptions::new().number(GrammaticalNumber::Plural);
+        assert_eq!("premiers", words(1, &plural));
+        assert_eq!("deuxièmes", words(2, &plural));
+        assert!(Ordinal(0)
+            .to_words_locale(Locale::French, &options)
+            .is_err());
+    }
+}
@@ -0,0 +1,113 @@
+//! Galician ordinals use the ordinal indicators with a period (`1.º`, `1.ª`), like
+//! Portuguese. Word forms are composed part by part, each agreeing in gender:
+//! `vixésimo primeiro`, `vixésima primeira`.
+
+use super::{romance, Number, OrdinalOptions};
+use alloc::string::String;
+
+const UNITS: [&str; 10] = [
+    "", "primeiro", "segundo", "terceiro", "cuarto", "quinto", "sexto", "sétimo", "oitavo",
+    "noveno",
+];
+
+const TENS: [&str; 10] = [
+    "",
+    "décimo",
+    "vixésimo",
+    "trixésimo",
+    "cuadraxésimo",
+    "quincuaxésimo",
+    "sexaxésimo",
+    "septuaxésimo",
+    "octoxésimo",
+    "nonaxésimo",
+];
+
+const HUNDREDS: [&str; 10] = [
+    "",
+    "centésimo",
+    "ducentésimo",
+    "tricentésimo",
+    "cuadrinxentésimo",
+    "quinxentésimo",
+    "sexcentésimo",
+    "septinxentésimo",
+    "octinxentésimo",
+    "noninxentésimo",
+];
+
+pub(crate) fn format(number: &Number, options: &OrdinalOptions) -> String {
+    romance::indicator(number, options.gender, options.period)
+}
+
+/// Spells out `n` for values from 1 to 1999.
+pub(crate) fn words(n: u128, options: &OrdinalOptions) -> Option<String> {
+    romance::words(n, options.gender, "milésimo", &HUNDREDS, &TENS, &UNITS)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Gender, Locale, Ordinal, OrdinalOptions};
+
+    #[test]
+    fn test_galician_indicator() {
+        let feminine = OrdinalOptions::new().gender(Gender::Feminine);
+        let feminine_short = OrdinalOptions::new().gender(Gender::Feminine).period(false);
+
+        for i in 1..=100 {
+            let ordinal = Ordinal(i);
+            assert_eq!(
+                format!("{}.\u{ba}", i),
+                ordinal.to_string_with_locale(Locale::Galician)
+            );
+            assert_eq!(
+                format!("{}.\u{aa}", i),
+                ordinal.to_string_with_locale_opts(Locale::Galician, &feminine)
+            );
+            assert_eq!(
+                format!("{}\u{aa}", i),
+                ordinal.to_string_with_locale_opts(Locale::Galician, &feminine_short)
+            );
+        }
+    }
+
+    #[test]
+    fn test_galician_words() {
+        // Masculine and feminine.
+        let galician_ordinals: [(&str, &str); 12] = [
+            ("primeiro", "primeira"),
+            ("segundo", "segunda"),
+            ("terceiro", "terceira"),
+            ("cuarto", "cuarta"),
+            ("quinto", "quinta"),
+            ("sexto", "sexta"),
+            ("sétimo", "sétima"),
+            ("oitavo", "oitava"),
+            ("noveno", "novena"),
+            ("décimo", "décima"),
+            ("décimo primeiro", "décima primeira"),
+            ("décimo segundo", "décima segunda"),
+        ];
+
+        let masculine = OrdinalOptions::new();
+        let feminine = OrdinalOptions::new().gender(Gender::Feminine);
+        let words = |n: u32, options: &OrdinalOptions| {
+            Ordinal(n)
+                .to_words_locale(Locale::Galician, options)
+                .unwrap()
+        };
+        for (i, forms) in galician_ordinals.iter().enumerate() {
+            let n = i as u32 + 1;
+            assert_eq!(forms.0, words(n, &masculine));
+            assert_eq!(forms.1, words(n, &feminine));
+        }
+        assert_eq!("vixésimo", words(20, &masculine));
+        assert_eq!("vixésimo primeiro", words(21, &masculine));
+        assert_eq!("vixésima primeira", words(21, &feminine));
+        assert_eq!("centésimo", words(100, &masculine));
+        assert_eq!("centésima", words(100, &feminine));
+        assert!(Ordinal(0)
+            .to_words_locale(Locale::Galician, &masculine)
+            .is_err());
+    }
+}
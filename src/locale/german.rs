@@ -0,0 +1,332 @@
+//! German ordinals are written as the number followed by a period: `3.` for `dritte`.
+//!
+//! Word forms are adjectives in the strong declension (no preceding article):
+//! `dritter Platz`, `dritte Reihe`, `drittes Mal`.
+
+use super::{Case, Gender, OrdinalOptions};
+use alloc::string::String;
+
+const UNITS: [&str; 20] = [
+    "",
+    "ein",
+    "zwei",
+    "drei",
+    "vier",
+    "fünf",
+    "sechs",
+    "sieben",
+    "acht",
+    "neun",
+    "zehn",
+    "elf",
+    "zwölf",
+    "dreizehn",
+    "vierzehn",
+    "fünfzehn",
+    "sechzehn",
+    "siebzehn",
+    "achtzehn",
+    "neunzehn",
+];
+
+const TENS: [&str; 10] = [
+    "", "", "zwanzig", "dreißig", "vierzig", "fünfzig", "sechzig", "siebzig", "achtzig", "neunzig",
+];
+
+const ORDINAL_STEMS: [&str; 20] = [
+    "nullt",
+    "erst",
+    "zweit",
+    "dritt",
+    "viert",
+    "fünft",
+    "sechst",
+    "siebt",
+    "acht",
+    "neunt",
+    "zehnt",
+    "elft",
+    "zwölft",
+    "dreizehnt",
+    "vierzehnt",
+    "fünfzehnt",
+    "sechzehnt",
+    "siebzehnt",
+    "achtzehnt",
+    "neunzehnt",
+];
+
+/// Spells out `n` for values below one million.
+pub(crate) fn words(n: u128, options: &OrdinalOptions) -> Option<String> {
+    if n >= 1_000_000 {
+        return None;
+    }
+    let n = n as u32;
+    let low = n % 100;
+    let mut stem = if low < 20 && (low != 0 || n == 0) {
+        let mut stem = cardinal(n - low);
+        stem.push_str(ORDINAL_STEMS[low as usize]);
+        stem
+    } else {
+        let mut stem = cardinal(n);
+        stem.push_str("st");
+        stem
+    };
+    stem.push_str(ending(options.gender, options.case)?);
+    Some(stem)
+}
+
+/// Returns the strong ending, or `None` for cases German doesn't have.
+fn ending(gender: Gender, case: Case) -> Option<&'static str> {
+    Some(match (case, gender) {
+        (Case::Nominative, Gender::Feminine) | (Case::Accusative, Gender::Feminine) => "e",
+        (Case::Nominative, Gender::Neuter) | (Case::Accusative, Gender::Neuter) => "es",
+        (Case::Nominative, _) => "er",
+        (Case::Accusative, _) => "en",
+        (Case::Dative, Gender::Feminine) | (Case::Genitive, Gender::Feminine) => "er",
+        (Case::Dative, _) => "em",
+        (Case::Genitive, _) => "en",
+        (Case::Instrumental, _) | (Case::Prepositional, _) => return None,
+    })
+}
+
+/// Cardinal in compound form, omitting the `ein` of a leading `hundert` or `tausend`.
+fn cardinal(n: u32) -> String {
+    let mut words = String::new();
+    let thousands = n / 1000;
+    if thousands > 0 {
+        if thousands > 1 {
+            words.push_str(&below_thousand(thousands, true));
+        }
+        words.push_str("tausend");
+    }
+    words.push_str(&below_thousand(n % 1000, thousands == 0));
+    words
+}
+
+fn below_thousand(n: u32, leading: bool) -> String {
+    let mut words = String::new();
+    let hundreds = n / 100;
+    if hundreds > 0 {
+        if hundreds > 1 || !leading {
+            words.push_str(UNITS[hundreds as usize]);
+        }
+        words.push_str("hundert");
+    }
+    let rest = n % 100;
+    if rest < 20 {
+        words.push_str(UNITS[rest as usize]);
+    } else {
+        let unit = rest % 10;
+        if unit > 0 {
+            words.push_str(UNITS[unit as usize]);
+            words.push_str("und");
+        }
+        words.push_str(TENS[(rest / 10) as usize]);
+    }
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Case, Gender, Locale, Ordinal, OrdinalOptions};
+
+    #[test]
+    fn test_german_digits() {
+        for i in 1..=20 {
+            assert_eq!(
+                format!("{}.", i),
+                Ordinal(i).to_string_with_locale(Locale::German)
+            );
+        }
+        assert_eq!("100.", Ordinal(100).to_string_with_locale(Locale::German));
+    }
+
+    #[test]
+    fn test_german_words_nominative() {
+        let masculine: [&str; 13] = [
+            "erster",
+            "zweiter",
+            "dritter",
+            "vierter",
+            "fünfter",
+            "sechster",
+            "siebter",
+            "achter",
+            "neunter",
+            "zehnter",
+            "elfter",
+            "zwölfter",
+            "dreizehnter",
+        ];
+        let feminine: [&str; 13] = [
+            "erste",
+            "zweite",
+            "dritte",
+            "vierte",
+            "fünfte",
+            "sechste",
+            "siebte",
+            "achte",
+            "neunte",
+            "zehnte",
+            "elfte",
+            "zwölfte",
+            "dreizehnte",
+        ];
+        let neuter: [&str; 13] = [
+            "erstes",
+            "zweites",
+            "drittes",
+            "viertes",
+            "fünftes",
+            "sechstes",
+            "siebtes",
+            "achtes",
+            "neuntes",
+            "zehntes",
+            "elftes",
+            "zwölftes",
+            "dreizehntes",
+        ];
+
+        let options = OrdinalOptions::new();
+        for i in 0..13 {
+            let ordinal = Ordinal(i as u32 + 1);
+            assert_eq!(
+                masculine[i],
+                ordinal
+                    .to_words_locale(Locale::German, &options.clone().gender(Gender::Masculine))
+                    .unwrap()
+            );
+            assert_eq!(
+                feminine[i],
+                ordinal
+                    .to_words_locale(Locale::German, &options.clone().gender(Gender::Feminine))
+                    .unwrap()
+            );
+            assert_eq!(
+                neuter[i],
+                ordinal
+                    .to_words_locale(Locale::German, &options.clone().gender(Gender::Neuter))
+                    .unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_german_words_compounds_and_cases() {
+        let feminine = OrdinalOptions::new().gender(Gender::Feminine);
+        let words = |n: u32| {
+            Ordinal(n)
+                .to_words_locale(Locale::German, &feminine)
+                .unwrap()
+        };
+
+        assert_eq!("nullte", words(0));
+        assert_eq!("zwanzigste", words(20));
+        assert_eq!("einundzwanzigste", words(21));
+        assert_eq!("dreißigste", words(30));
+        assert_eq!("hundertste", words(100));
+        assert_eq!("hunderterste", words(101));
+        assert_eq!("hundertdritte", words(103));
+        assert_eq!("hunderteinundzwanzigste", words(121));
+        assert_eq!("tausendste", words(1000));
+        assert_eq!("tausendeinhundertste", words(1100));
+        assert_eq!("zweitausendsiebte", words(2007));
+        assert_eq!("zweihunderteintausendste", words(201_000));
+
+        let masculine = OrdinalOptions::new();
+        assert_eq!(
+            "dritten",
+            Ordinal(3)
+                .to_words_locale(Locale::German, &masculine.clone().case(Case::Accusative))
+                .unwrap()
+        );
+        assert_eq!(
+            "drittem",
+            Ordinal(3)
+                .to_words_locale(Locale::German, &masculine.clone().case(Case::Dative))
+                .unwrap()
+        );
+        assert_eq!(
+            "dritter",
+            Ordinal(3)
+                .to_words_locale(Locale::German, &feminine.clone().case(Case::Genitive))
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_german_words_unsupported() {
+        let options = OrdinalOptions::new();
+        assert!(Ordinal(-1)
+            .to_words_locale(Locale::German, &options)
+            .is_err());
+        assert!(Ordinal(1_000_000)
+            .to_words_locale(Locale::German, &options)
+            .is_err());
+        assert!(Ordinal(1).to_words_locale(Locale::Dutch, &options).is_err());
+    }
+
+    #[test]
+    fn test_german_words_table() {
+        let german: [&str; 31] = [
+            "erster",
+            "zweiter",
+            "dritter",
+            "vierter",
+            "fünfter",
+            "sechster",
+            "siebter",
+            "achter",
+            "neunter",
+            "zehnter",
+            "elfter",
+            "zwölfter",
+            "dreizehnter",
+            "vierzehnter",
+            "fünfzehnter",
+            "sechzehnter",
+            "siebzehnter",
+            "achtzehnter",
+            "neunzehnter",
+            "zwanzigster",
+            "einundzwanzigster",
+            "zweiundzwanzigster",
+            "dreiundzwanzigster",
+            "vierundzwanzigster",
+            "fünfundzwanzigster",
+            "sechsundzwanzigster",
+            "siebenundzwanzigster",
+            "achtundzwanzigster",
+            "neunundzwanzigster",
+            "dreißigster",
+            "einunddreißigster",
+        ];
+
+        let options = OrdinalOptions::new();
+        for (i, expected) in german.iter().enumerate() {
+            assert_eq!(
+                *expected,
+                Ordinal(i + 1)
+                    .to_words_locale(Locale::German, &options)
+                    .unwrap()
+            );
+        }
+
+        let words = |n: u32| {
+            Ordinal(n)
+                .to_words_locale(Locale::German, &options)
+                .unwrap()
+        };
+        assert_eq!("neunundneunzigster", words(99));
+        assert_eq!("hundertster", words(100));
+        assert_eq!("zweihundertster", words(200));
+        assert_eq!("neunhundertneunundneunzigster", words(999));
+        assert_eq!("tausendster", words(1000));
+        assert_eq!("tausenderster", words(1001));
+        assert_eq!("neuntausendneunhundertneunundneunzigster", words(9999));
+        assert_eq!("zehntausendster", words(10_000));
+    }
+}
@@ -0,0 +1,169 @@
+//! Greek ordinals are adjectives that agree in gender, abbreviated as the number
+//! followed by the adjective ending: `1ος`, `1η`, `1ο`. The ending doesn't depend on
+//! the number, only on the gender.
+//!
+//! In word forms every component is ordinal and inflects: `εικοστός πρώτος`,
+//! `εικοστή πρώτη`, `εικοστό πρώτο`.
+
+use super::{Gender, Number, OrdinalOptions};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+
+const UNITS: [&str; 10] = [
+    "",
+    "πρώτος",
+    "δεύτερος",
+    "τρίτος",
+    "τέταρτος",
+    "πέμπτος",
+    "έκτος",
+    "έβδομος",
+    "όγδοος",
+    "ένατος",
+];
+
+const TENS: [&str; 10] = [
+    "",
+    "δέκατος",
+    "εικοστός",
+    "τριακοστός",
+    "τεσσαρακοστός",
+    "πεντηκοστός",
+    "εξηκοστός",
+    "εβδομηκοστός",
+    "ογδοηκοστός",
+    "ενενηκοστός",
+];
+
+const HUNDREDS: [&str; 10] = [
+    "",
+    "εκατοστός",
+    "διακοσιοστός",
+    "τριακοσιοστός",
+    "τετρακοσιοστός",
+    "πεντακοσιοστός",
+    "εξακοσιοστός",
+    "επτακοσιοστός",
+    "οκτακοσιοστός",
+    "εννιακοσιοστός",
+];
+
+pub(crate) fn format(number: &Number, options: &OrdinalOptions) -> String {
+    let ending = match options.gender {
+        Gender::Feminine => "η",
+        Gender::Neuter => "ο",
+        _ => "ος",
+    };
+    format!("{}{}", number, ending)
+}
+
+/// Spells out `n` in the nominative for values from 1 to 999.
+pub(crate) fn words(n: u128, options: &OrdinalOptions) -> Option<String> {
+    if n == 0 || n >= 1000 {
+        return None;
+    }
+    let n = n as usize;
+    let mut parts = vec![HUNDREDS[n / 100]];
+    match n % 100 {
+        11 => parts.push("ενδέκατος"),
+        12 => parts.push("δωδέκατος"),
+        rest => parts.extend([TENS[rest / 10], UNITS[rest % 10]]),
+    }
+    Some(
+        parts
+            .into_iter()
+            .filter(|part| !part.is_empty())
+            .map(|part| inflect(part, options.gender))
+            .collect::<Vec<_>>()
+            .join(" "),
+    )
+}
+
+/// Turns a masculine `-ος` adjective into the requested gender, keeping the accent.
+fn inflect(masculine: &str, gender: Gender) -> String {
+    let (stem, accented) = match masculine.strip_suffix("ός") {
+        Some(stem) => (stem, true),
+        None => (masculine.strip_suffix("ος").unwrap_or(masculine), false),
+    };
+    let ending = match (gender, accented) {
+        (Gender::Feminine, true) => "ή",
+        (Gender::Feminine, false) => "η",
+        (Gender::Neuter, true) => "ό",
+        (Gender::Neuter, false) => "ο",
+        _ => return masculine.to_string(),
+    };
+    format!("{}{}", stem, ending)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Gender, Locale, Ordinal, OrdinalOptions};
+
+    #[test]
+    fn test_greek_digits() {
+        let masculine = OrdinalOptions::new();
+        let feminine = OrdinalOptions::new().gender(Gender::Feminine);
+        let neuter = OrdinalOptions::new().gender(Gender::Neuter);
+
+        for i in 1..=25 {
+            let ordinal = Ordinal(i);
+            assert_eq!(
+                format!("{}\u{3bf}\u{3c2}", i),
+                ordinal.to_string_with_locale_opts(Locale::Greek, &masculine)
+            );
+            assert_eq!(
+                format!("{}\u{3b7}", i),
+                ordinal.to_string_with_locale_opts(Locale::Greek, &feminine)
+            );
+            assert_eq!(
+                format!("{}\u{3bf}", i),
+                ordinal.to_string_with_locale_opts(Locale::Greek, &neuter)
+            );
+        }
+        assert_eq!("1ος", Ordinal(1).to_string_with_locale(Locale::Greek));
+    }
+
+    #[test]
+    fn test_greek_words() {
+        let words = |n: u32, gender: Gender| {
+            Ordinal(n)
+                .to_words_locale(Locale::Greek, &OrdinalOptions::new().gender(gender))
+                .unwrap()
+        };
+
+        assert_eq!("πρώτος", words(1, Gender::Masculine));
+        assert_eq!("δεύτερος", words(2, Gender::Masculine));
+        assert_eq!("όγδοος", words(8, Gender::Masculine));
+        assert_eq!("ενδέκατος", words(11, Gender::Masculine));
+        assert_eq!("δέκατος τρίτος", words(13, Gender::Masculine));
+        assert_eq!("εικοστός", words(20, Gender::Masculine));
+        assert_eq!("εικοστός πρώτος", words(21, Gender::Masculine));
+        assert_eq!("εκατοστός", words(100, Gender::Masculine));
+        assert_eq!("εκατοστός πέμπτος", words(105, Gender::Masculine));
+        assert_eq!(
+            "εννιακοσιοστός ενενηκοστός ένατος",
+            words(999, Gender::Masculine)
+        );
+
+        assert_eq!("πρώτη", words(1, Gender::Feminine));
+        assert_eq!("όγδοη", words(8, Gender::Feminine));
+        assert_eq!("εικοστή πρώτη", words(21, Gender::Feminine));
+        assert_eq!("πρώτο", words(1, Gender::Neuter));
+        assert_eq!("δεύτερο", words(2, Gender::Neuter));
+        assert_eq!("εικοστό πρώτο", words(21, Gender::Neuter));
+
+        // No Latin lookalikes and a final sigma only at the end of each word.
+        for n in 1..1000 {
+            let word = words(n, Gender::Masculine);
+            assert!(word
+                .chars()
+                .all(|c| c == ' ' || ('\u{370}'..='\u{3ff}').contains(&c)));
+            for part in word.split(' ') {
+                assert!(part.ends_with('ς'));
+                assert!(!part[..part.len() - 'ς'.len_utf8()].contains('ς'));
+            }
+        }
+    }
+}
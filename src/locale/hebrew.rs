@@ -0,0 +1,208 @@
+//! Hebrew has no abbreviated ordinal: the numeral is written on its own and the
+//! sentence supplies the gender. Word forms exist for 1–10 (`ראשון`/`ראשונה`,
+//! `שני`/`שנייה`, … `עשירי`/`עשירית`); from 11 on Hebrew uses the definite cardinal
+//! (`האחד עשר`, `העשרים ואחת`), which is spelled out here up to 99.
+//!
+//! Strings are in logical order without directional marks unless
+//! [`OrdinalOptions::direction_marks`] asks for them.
+
+use super::{bidi, Gender, Number, OrdinalOptions};
+use alloc::format;
+use alloc::string::{String, ToString};
+
+const MASCULINE: [&str; 11] = [
+    "",
+    "ראשון",
+    "שני",
+    "שלישי",
+    "רביעי",
+    "חמישי",
+    "שישי",
+    "שביעי",
+    "שמיני",
+    "תשיעי",
+    "עשירי",
+];
+
+const FEMININE: [&str; 11] = [
+    "",
+    "ראשונה",
+    "שנייה",
+    "שלישית",
+    "רביעית",
+    "חמישית",
+    "שישית",
+    "שביעית",
+    "שמינית",
+    "תשיעית",
+    "עשירית",
+];
+
+const MASCULINE_UNITS: [&str; 10] = [
+    "",
+    "אחד",
+    "שניים",
+    "שלושה",
+    "ארבעה",
+    "חמישה",
+    "שישה",
+    "שבעה",
+    "שמונה",
+    "תשעה",
+];
+
+const FEMININE_UNITS: [&str; 10] = [
+    "",
+    "אחת",
+    "שתיים",
+    "שלוש",
+    "ארבע",
+    "חמש",
+    "שש",
+    "שבע",
+    "שמונה",
+    "תשע",
+];
+
+const TENS: [&str; 10] = [
+    "",
+    "",
+    "עשרים",
+    "שלושים",
+    "ארבעים",
+    "חמישים",
+    "שישים",
+    "שבעים",
+    "שמונים",
+    "תשעים",
+];
+
+pub(crate) fn format(number: &Number, options: &OrdinalOptions) -> String {
+    bidi::mark(number.to_string(), options.direction_marks)
+}
+
+/// Spells out 1–10 as ordinals and 11–99 as definite cardinals.
+pub(crate) fn words(n: u128, options: &OrdinalOptions) -> Option<String> {
+    let feminine = options.gender == Gender::Feminine;
+    let words = match n {
+        1..=10 if feminine => FEMININE[n as usize].to_string(),
+        1..=10 => MASCULINE[n as usize].to_string(),
+        11..=99 => format!("ה{}", cardinal(n as usize, feminine)),
+        _ => return None,
+    };
+    Some(bidi::mark(words, options.direction_marks))
+}
+
+fn cardinal(n: usize, feminine: bool) -> String {
+    let (tens, unit) = (n / 10, n % 10);
+    match (tens, feminine) {
+        (1, false) => {
+            let unit = if unit == 2 {
+                "שנים"
+            } else {
+                MASCULINE_UNITS[unit]
+            };
+            format!("{} עשר", unit)
+        }
+        (1, true) => {
+            let unit = if unit == 2 {
+                "שתים"
+            } else {
+                FEMININE_UNITS[unit]
+            };
+            format!("{} עשרה", unit)
+        }
+        (_, _) if unit == 0 => TENS[tens].to_string(),
+        (_, false) => format!("{} ו{}", TENS[tens], MASCULINE_UNITS[unit]),
+        (_, true) => format!("{} ו{}", TENS[tens], FEMININE_UNITS[unit]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Gender, Locale, Ordinal, OrdinalOptions};
+
+    #[test]
+    fn test_hebrew_digits() {
+        assert_eq!("3", Ordinal(3).to_string_with_locale(Locale::Hebrew));
+        assert_eq!("-3", Ordinal(-3).to_string_with_locale(Locale::Hebrew));
+
+        let options = OrdinalOptions::new().direction_marks(true);
+        assert_eq!(
+            "\u{200f}21\u{200f}",
+            Ordinal(21).to_string_with_locale_opts(Locale::Hebrew, &options)
+        );
+    }
+
+    #[test]
+    fn test_hebrew_words() {
+        let masculine: [&str; 10] = [
+            "ראשון",
+            "שני",
+            "שלישי",
+            "רביעי",
+            "חמישי",
+            "שישי",
+            "שביעי",
+            "שמיני",
+            "תשיעי",
+            "עשירי",
+        ];
+        let feminine: [&str; 10] = [
+            "ראשונה",
+            "שנייה",
+            "שלישית",
+            "רביעית",
+            "חמישית",
+            "שישית",
+            "שביעית",
+            "שמינית",
+            "תשיעית",
+            "עשירית",
+        ];
+
+        let masculine_options = OrdinalOptions::new();
+        let feminine_options = OrdinalOptions::new().gender(Gender::Feminine);
+        for i in 0..10 {
+            let ordinal = Ordinal(i + 1);
+            assert_eq!(
+                masculine[i],
+                ordinal
+                    .to_words_locale(Locale::Hebrew, &masculine_options)
+                    .unwrap()
+            );
+            assert_eq!(
+                feminine[i],
+                ordinal
+                    .to_words_locale(Locale::Hebrew, &feminine_options)
+                    .unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_hebrew_words_fallback() {
+        let masculine = OrdinalOptions::new();
+        let feminine = OrdinalOptions::new().gender(Gender::Feminine);
+        let words = |n: u32, options: &OrdinalOptions| {
+            Ordinal(n).to_words_locale(Locale::Hebrew, options).unwrap()
+        };
+
+        assert_eq!("האחד עשר", words(11, &masculine));
+        assert_eq!("האחת עשרה", words(11, &feminine));
+        assert_eq!("השנים עשר", words(12, &masculine));
+        assert_eq!("העשרים ואחד", words(21, &masculine));
+        assert_eq!("העשרים ואחת", words(21, &feminine));
+        assert_eq!("השלושים", words(30, &feminine));
+        assert_eq!(
+            "\u{200f}ראשון\u{200f}",
+            words(1, &masculine.clone().direction_marks(true))
+        );
+        assert!(Ordinal(100)
+            .to_words_locale(Locale::Hebrew, &masculine)
+            .is_err());
+        assert!(Ordinal((1u128 << 64) + 5)
+            .to_words_locale(Locale::Hebrew, &masculine)
+            .is_err());
+    }
+}
@@ -0,0 +1,145 @@
+//! Hindi ordinals add `वाँ` to the numeral (`3वाँ`), which agrees like an adjective:
+//! `वीं` in the feminine and `वें` in the masculine plural. The numeral can be written
+//! in Devanagari digits with [`OrdinalOptions::digits`] (`३वाँ`).
+//!
+//! Word forms cover the irregular 1–4 and 6 (`पहला`, `दूसरा`, `तीसरा`, `चौथा`, `छठा`)
+//! and the regular 5 (`पाँचवाँ`); from 7 on the word is the cardinal plus `वाँ`, and
+//! the cardinals aren't spelled out here.
+//!
+//! The CLDR categories follow the irregular words: one (1), two (2, 3), few (4) and
+//! many (6), with 5 and everything else in other.
+
+use super::{digits, Gender, GrammaticalNumber, Number, OrdinalCategory, OrdinalOptions};
+use alloc::format;
+use alloc::string::{String, ToString};
+
+const WORDS: [&str; 7] = ["", "पहला", "दूसरा", "तीसरा", "चौथा", "पाँचवाँ", "छठा"];
+
+pub(crate) fn format(number: &Number, options: &OrdinalOptions) -> String {
+    let numeral = digits::shape(&number.to_string(), options.digits);
+    inflect(&format!("{}वाँ", numeral), options)
+}
+
+/// Spells out 1–6.
+pub(crate) fn category(number: &Number) -> OrdinalCategory {
+    match number.magnitude() {
+        Some(1) => OrdinalCategory::One,
+        Some(2 | 3) => OrdinalCategory::Two,
+        Some(4) => OrdinalCategory::Few,
+        Some(6) => OrdinalCategory::Many,
+        _ => OrdinalCategory::Other,
+    }
+}
+
+pub(crate) fn words(n: u128, options: &OrdinalOptions) -> Option<String> {
+    match n {
+        1..=6 => Some(inflect(WORDS[n as usize], options)),
+        _ => None,
+    }
+}
+
+/// Turns a masculine singular form (`-ा`, `-वाँ`) into the requested gender and number.
+fn inflect(masculine: &str, options: &OrdinalOptions) -> String {
+    let (feminine, plural) = match masculine.strip_suffix("वाँ") {
+        Some(stem) => (format!("{}वीं", stem), format!("{}वें", stem)),
+        None => {
+            let stem = masculine.strip_suffix('ा').unwrap_or(masculine);
+            (format!("{}ी", stem), format!("{}े", stem))
+        }
+    };
+    match (options.gender, options.number) {
+        (Gender::Feminine, _) => feminine,
+        (_, GrammaticalNumber::Plural) => plural,
+        _ => masculine.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Digits, Gender, GrammaticalNumber, Locale, Ordinal, OrdinalCategory, OrdinalOptions,
+    };
+
+    #[test]
+    fn test_hindi_digits() {
+        for (n, expected) in [(7, "7वाँ"), (21, "21वाँ"), (100, "100वाँ")] {
+            assert_eq!(expected, Ordinal(n).to_string_with_locale(Locale::Hindi));
+        }
+
+        let feminine = OrdinalOptions::new().gender(Gender::Feminine);
+        assert_eq!(
+            "7वीं",
+            Ordinal(7).to_string_with_locale_opts(Locale::Hindi, &feminine)
+        );
+        let plural = OrdinalOptions::new().number(GrammaticalNumber::Plural);
+        assert_eq!(
+            "7वें",
+            Ordinal(7).to_string_with_locale_opts(Locale::Hindi, &plural)
+        );
+
+        let devanagari = OrdinalOptions::new().digits(Digits::Devanagari);
+        assert_eq!(
+            "३वाँ",
+            Ordinal(3).to_string_with_locale_opts(Locale::Hindi, &devanagari)
+        );
+        assert_eq!(
+            "१००वाँ",
+            Ordinal(100).to_string_with_locale_opts(Locale::Hindi, &devanagari)
+        );
+    }
+
+    #[test]
+    fn test_hindi_words() {
+        let masculine: [&str; 6] = ["पहला", "दूसरा", "तीसरा", "चौथा", "पाँचवाँ", "छठा"];
+        let feminine: [&str; 6] = ["पहली", "दूसरी", "तीसरी", "चौथी", "पाँचवीं", "छठी"];
+        let plural: [&str; 6] = ["पहले", "दूसरे", "तीसरे", "चौथे", "पाँचवें", "छठे"];
+
+        let masculine_options = OrdinalOptions::new();
+        let feminine_options = OrdinalOptions::new().gender(Gender::Feminine);
+        let plural_options = OrdinalOptions::new().number(GrammaticalNumber::Plural);
+        for i in 0..6 {
+            let ordinal = Ordinal(i + 1);
+            assert_eq!(
+                masculine[i],
+                ordinal
+                    .to_words_locale(Locale::Hindi, &masculine_options)
+                    .unwrap()
+            );
+            assert_eq!(
+                feminine[i],
+                ordinal
+                    .to_words_locale(Locale::Hindi, &feminine_options)
+                    .unwrap()
+            );
+            assert_eq!(
+                plural[i],
+                ordinal
+                    .to_words_locale(Locale::Hindi, &plural_options)
+                    .unwrap()
+            );
+        }
+        assert!(Ordinal(7)
+            .to_words_locale(Locale::Hindi, &masculine_options)
+            .is_err());
+    }
+
+    #[test]
+    fn test_hindi_category() {
+        let options = OrdinalOptions::new();
+        let category = |n: u32| Ordinal(n).plural_category(Locale::Hindi);
+
+        assert_eq!(OrdinalCategory::One, category(1));
+        assert_eq!(OrdinalCategory::Two, category(2));
+        assert_eq!(OrdinalCategory::Two, category(3));
+        assert_eq!(OrdinalCategory::Few, category(4));
+        assert_eq!(OrdinalCategory::Many, category(6));
+        for n in [0, 5, 7, 11, 21, 100] {
+            assert_eq!(OrdinalCategory::Other, category(n));
+        }
+        // Only the irregular words have a category of their own.
+        for n in 1..=6u32 {
+            let word = Ordinal(n).to_words_locale(Locale::Hindi, &options).unwrap();
+            assert_eq!(word.ends_with("वाँ"), category(n) == OrdinalCategory::Other);
+        }
+    }
+}
@@ -0,0 +1,204 @@
+//! Hungarian ordinals are written as the number followed by a period: `3.`, and `-3.`
+//! for negative values. CLDR puts 1 and 5 in the one category.
+//!
+//! Word forms add `-dik` to the last component's stem with a linking vowel chosen by
+//! vowel harmony: `a` after back-vowel stems (`harmadik`, `huszadik`, `századik`),
+//! `ö` after front rounded ones (`ötödik`) and `e` otherwise (`negyedik`, `ezredik`),
+//! with `hatodik` as the one lowered exception. 1 and 2 are irregular on their own
+//! (`első`, `második`) but regular in compounds (`huszonegyedik`, `huszonkettedik`).
+
+use super::{Number, OrdinalCategory};
+use alloc::string::{String, ToString};
+
+const UNIT_STEMS: [&str; 10] = [
+    "", "egy", "kett", "harm", "negy", "öt", "hat", "het", "nyolc", "kilenc",
+];
+
+const TENS_STEMS: [&str; 10] = [
+    "",
+    "tiz",
+    "husz",
+    "harminc",
+    "negyven",
+    "ötven",
+    "hatvan",
+    "hetven",
+    "nyolcvan",
+    "kilencven",
+];
+
+/// Tens as they appear before a unit: `tizen-`, `huszon-`.
+const TENS_PREFIXES: [&str; 10] = [
+    "",
+    "tizen",
+    "huszon",
+    "harminc",
+    "negyven",
+    "ötven",
+    "hatvan",
+    "hetven",
+    "nyolcvan",
+    "kilencven",
+];
+
+/// Multipliers before `száz` and `ezer`.
+const MULTIPLIERS: [&str; 10] = [
+    "", "", "két", "három", "négy", "öt", "hat", "hét", "nyolc", "kilenc",
+];
+
+/// Spells out `n` for values from 1 to 9999.
+pub(crate) fn category(number: &Number) -> OrdinalCategory {
+    match number.magnitude() {
+        Some(1 | 5) => OrdinalCategory::One,
+        _ => OrdinalCategory::Other,
+    }
+}
+
+pub(crate) fn words(n: u128) -> Option<String> {
+    let n = match n {
+        1 => return Some("első".to_string()),
+        2 => return Some("második".to_string()),
+        3..=9999 => n as usize,
+        _ => return None,
+    };
+    let (thousands, hundreds, tens, units) = (n / 1000, n / 100 % 10, n / 10 % 10, n % 10);
+    let mut words = String::new();
+    let stem = if hundreds + tens + units == 0 {
+        words.push_str(MULTIPLIERS[thousands]);
+        "ezr"
+    } else {
+        if thousands > 0 {
+            words.push_str(MULTIPLIERS[thousands]);
+            words.push_str("ezer");
+            if n > 2000 {
+                words.push('-');
+            }
+        }
+        words.push_str(MULTIPLIERS[hundreds]);
+        if units > 0 || tens > 0 {
+            if hundreds > 0 {
+                words.push_str("száz");
+            }
+            if units > 0 {
+                words.push_str(TENS_PREFIXES[tens]);
+                UNIT_STEMS[units]
+            } else {
+                TENS_STEMS[tens]
+            }
+        } else {
+            "száz"
+        }
+    };
+    words.push_str(stem);
+    words.push(linking_vowel(stem));
+    words.push_str("dik");
+    Some(words)
+}
+
+fn linking_vowel(stem: &str) -> char {
+    if stem == "hat" {
+        'o'
+    } else if stem.contains(['a', 'á', 'o', 'ó', 'u', 'ú']) {
+        'a'
+    } else if stem
+        .chars()
+        .rfind(|c| "eéöőüű".contains(*c))
+        .is_some_and(|c| "öőüű".contains(c))
+    {
+        'ö'
+    } else {
+        'e'
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Locale, Ordinal, OrdinalCategory, OrdinalOptions};
+
+    #[test]
+    fn test_hungarian_digits() {
+        for i in [1, 3, 21, 100, 1848] {
+            assert_eq!(
+                format!("{}.", i),
+                Ordinal(i).to_string_with_locale(Locale::Hungarian)
+            );
+        }
+        assert_eq!("-3.", Ordinal(-3).to_string_with_locale(Locale::Hungarian));
+    }
+
+    #[test]
+    fn test_hungarian_category() {
+        for (n, category) in [
+            (1, OrdinalCategory::One),
+            (5, OrdinalCategory::One),
+            (2, OrdinalCategory::Other),
+            (11, OrdinalCategory::Other),
+            (15, OrdinalCategory::Other),
+            (21, OrdinalCategory::Other),
+        ] {
+            assert_eq!(category, Ordinal(n).plural_category(Locale::Hungarian));
+        }
+    }
+
+    #[test]
+    fn test_hungarian_words() {
+        let hungarian_ordinals: [&str; 30] = [
+            "első",
+            "második",
+            "harmadik",
+            "negyedik",
+            "ötödik",
+            "hatodik",
+            "hetedik",
+            "nyolcadik",
+            "kilencedik",
+            "tizedik",
+            "tizenegyedik",
+            "tizenkettedik",
+            "tizenharmadik",
+            "tizennegyedik",
+            "tizenötödik",
+            "tizenhatodik",
+            "tizenhetedik",
+            "tizennyolcadik",
+            "tizenkilencedik",
+            "huszadik",
+            "huszonegyedik",
+            "huszonkettedik",
+            "huszonharmadik",
+            "huszonnegyedik",
+            "huszonötödik",
+            "huszonhatodik",
+            "huszonhetedik",
+            "huszonnyolcadik",
+            "huszonkilencedik",
+            "harmincadik",
+        ];
+
+        let options = OrdinalOptions::new();
+        for (i, expected) in hungarian_ordinals.iter().enumerate() {
+            assert_eq!(
+                *expected,
+                Ordinal(i + 1)
+                    .to_words_locale(Locale::Hungarian, &options)
+                    .unwrap()
+            );
+        }
+
+        let words = |n: u32| {
+            Ordinal(n)
+                .to_words_locale(Locale::Hungarian, &options)
+                .unwrap()
+        };
+        assert_eq!("negyvenedik", words(40));
+        assert_eq!("ötvenedik", words(50));
+        assert_eq!("hatvanadik", words(60));
+        assert_eq!("századik", words(100));
+        assert_eq!("százegyedik", words(101));
+        assert_eq!("kétszázadik", words(200));
+        assert_eq!("ezredik", words(1000));
+        assert_eq!("ezeregyedik", words(1001));
+        assert_eq!("kétezredik", words(2000));
+        assert_eq!("kétezer-huszonegyedik", words(2021));
+    }
+}
@@ -0,0 +1,10 @@
+//! The hyphenated style shared by the locales that abbreviate ordinals with a hyphen
+//! and the word's ending: Russian `1-й`, Ukrainian `1-ша`.
+
+use super::Number;
+use alloc::format;
+use alloc::string::String;
+
+pub(crate) fn format(number: &Number, ending: &str) -> String {
+    format!("{}-{}", number, ending)
+}
@@ -0,0 +1,209 @@
+//! Icelandic ordinals are written as the number followed by a period: `3.`.
+//! Word forms cover 1 to 100. They inflect like weak adjectives, `-i` for the masculine
+//! and `-a` for the feminine and neuter, except `annar`, `önnur`, `annað`. Compounds
+//! join the tens and the unit with `og`, both ordinal: `tuttugasti og fyrsti`.
+
+use super::{Gender, OrdinalOptions};
+use alloc::format;
+use alloc::string::{String, ToString};
+
+/// The masculine forms from 1 to 19.
+const UNITS: [&str; 20] = [
+    "",
+    "fyrsti",
+    "annar",
+    "þriðji",
+    "fjórði",
+    "fimmti",
+    "sjötti",
+    "sjöundi",
+    "áttundi",
+    "níundi",
+    "tíundi",
+    "ellefti",
+    "tólfti",
+    "þrettándi",
+    "fjórtándi",
+    "fimmtándi",
+    "sextándi",
+    "sautjándi",
+    "átjándi",
+    "nítjándi",
+];
+
+const TENS: [&str; 10] = [
+    "",
+    "",
+    "tuttugasti",
+    "þrítugasti",
+    "fertugasti",
+    "fimmtugasti",
+    "sextugasti",
+    "sjötugasti",
+    "áttugasti",
+    "nítugasti",
+];
+
+/// Spells out `n` for values from 1 to 100.
+pub(crate) fn words(n: u128, options: &OrdinalOptions) -> Option<String> {
+    let gender = options.gender;
+    match n {
+        1..=19 => Some(inflect(UNITS[n as usize], gender)),
+        20..=99 => {
+            let tens = inflect(TENS[(n / 10) as usize], gender);
+            Some(match n % 10 {
+                0 => tens,
+                unit => format!("{} og {}", tens, inflect(UNITS[unit as usize], gender)),
+            })
+        }
+        100 => Some(inflect("hundraðasti", gender)),
+        _ => None,
+    }
+}
+
+fn inflect(masculine: &str, gender: Gender) -> String {
+    match (masculine, gender) {
+        (_, Gender::Masculine | Gender::Common) => masculine.to_string(),
+        ("annar", Gender::Feminine) => "önnur".to_string(),
+        ("annar", _) => "annað".to_string(),
+        _ => format!("{}a", masculine.strip_suffix('i').unwrap_or(masculine)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Gender, Locale, Ordinal, OrdinalOptions};
+
+    #[test]
+    fn test_icelandic_digits() {
+        for i in [1, 2, 3, 21, 100, 2024] {
+            assert_eq!(
+                format!("{}.", i),
+                Ordinal(i).to_string_with_locale(Locale::Icelandic)
+            );
+        }
+        assert_eq!(
+            Ordinal(3).to_string_with_locale(Locale::German),
+            Ordinal(3).to_string_with_locale(Locale::Icelandic)
+        );
+    }
+
+    #[test]
+    fn test_icelandic_words() {
+        // Masculine, feminine and neuter.
+        let icelandic_ordinals: [(&str, &str, &str); 31] = [
+            ("fyrsti", "fyrsta", "fyrsta"),
+            ("annar", "önnur", "annað"),
+            ("þriðji", "þriðja", "þriðja"),
+            ("fjórði", "fjórða", "fjórða"),
+            ("fimmti", "fimmta", "fimmta"),
+            ("sjötti", "sjötta", "sjötta"),
+            ("sjöundi", "sjöunda", "sjöunda"),
+            ("áttundi", "áttunda", "áttunda"),
+            ("níundi", "níunda", "níunda"),
+            ("tíundi", "tíunda", "tíunda"),
+            ("ellefti", "ellefta", "ellefta"),
+            ("tólfti", "tólfta", "tólfta"),
+            ("þrettándi", "þrettánda", "þrettánda"),
+            ("fjórtándi", "fjórtánda", "fjórtánda"),
+            ("fimmtándi", "fimmtánda", "fimmtánda"),
+            ("sextándi", "sextánda", "sextánda"),
+            ("sautjándi", "sautjánda", "sautjánda"),
+            ("átjándi", "átjánda", "átjánda"),
+            ("nítjándi", "nítjánda", "nítjánda"),
+            ("tuttugasti", "tuttugasta", "tuttugasta"),
+            (
+                "tuttugasti og fyrsti",
+                "tuttugasta og fyrsta",
+                "tuttugasta og fyrsta",
+            ),
+            (
+                "tuttugasti og annar",
+                "tuttugasta og önnur",
+                "tuttugasta og annað",
+            ),
+            (
+                "tuttugasti og þriðji",
+                "tuttugasta og þriðja",
+                "tuttugasta og þriðja",
+            ),
+            (
+                "tuttugasti og fjórði",
+                "tuttugasta og fjórða",
+                "tuttugasta og fjórða",
+            ),
+            (
+                "tuttugasti og fimmti",
+                "tuttugasta og fimmta",
+                "tuttugasta og fimmta",
+            ),
+            (
+                "tuttugasti og sjötti",
+                "tuttugasta og sjötta",
+                "tuttugasta og sjötta",
+            ),
+            (
+                "tuttugasti og sjöundi",
+                "tuttugasta og sjöunda",
+                "tuttugasta og sjöunda",
+            ),
+            (
+                "tuttugasti og áttundi",
+                "tuttugasta og áttunda",
+                "tuttugasta og áttunda",
+            ),
+            (
+                "tuttugasti og níundi",
+                "tuttugasta og níunda",
+                "tuttugasta og níunda",
+            ),
+            ("þrítugasti", "þrítugasta", "þrítugasta"),
+            (
+                "þrítugasti og fyrsti",
+                "þrítugasta og fyrsta",
+                "þrítugasta og fyrsta",
+            ),
+        ];
+
+        let genders = [Gender::Masculine, Gender::Feminine, Gender::Neuter];
+        for (i, forms) in icelandic_ordinals.iter().enumerate() {
+            for (gender, expected) in genders.into_iter().zip([forms.0, forms.1, forms.2]) {
+                let options = OrdinalOptions::new().gender(gender);
+                assert_eq!(
+                    expected,
+                    Ordinal(i + 1)
+                        .to_words_locale(Locale::Icelandic, &options)
+                        .unwrap()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_icelandic_tens_and_hundred() {
+        let options = OrdinalOptions::new();
+        let words = |n: u32| Ordinal(n).to_words_locale(Locale::Icelandic, &options);
+        let tens = [
+            "fertugasti",
+            "fimmtugasti",
+            "sextugasti",
+            "sjötugasti",
+            "áttugasti",
+            "nítugasti",
+        ];
+        for (i, expected) in tens.iter().enumerate() {
+            assert_eq!(*expected, words(40 + 10 * i as u32).unwrap());
+        }
+        assert_eq!("nítugasti og níundi", words(99).unwrap());
+        assert_eq!("hundraðasti", words(100).unwrap());
+        let feminine = OrdinalOptions::new().gender(Gender::Feminine);
+        assert_eq!(
+            "hundraðasta",
+            Ordinal(100)
+                .to_words_locale(Locale::Icelandic, &feminine)
+                .unwrap()
+        );
+        assert!(words(0).is_err());
+        assert!(words(101).is_err());
+    }
+}
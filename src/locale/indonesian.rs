@@ -0,0 +1,127 @@
+//! Indonesian ordinals prefix `ke` to the number: `ke-3` in digits, `ketiga` in words,
+//! with the cardinal written out after it in compounds (`kedua puluh satu`). The first
+//! is usually `pertama`, though the regular `kesatu` exists too. A minus sign goes in
+//! front of the prefix (`-ke-3`).
+//!
+//! Malay follows the same pattern with different cardinals, so these tables stay here.
+
+use super::{Number, OrdinalOptions};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// The word for "first" in Indonesian.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum IndonesianFirst {
+    /// `pertama`.
+    #[default]
+    Pertama,
+    /// `kesatu`.
+    Kesatu,
+}
+
+const UNITS: [&str; 10] = [
+    "", "satu", "dua", "tiga", "empat", "lima", "enam", "tujuh", "delapan", "sembilan",
+];
+
+pub(crate) fn format(number: &Number) -> String {
+    format!("{}ke-{}", number.sign(), number.digits())
+}
+
+/// Spells out `n` for values from 1 to 999 999 999.
+pub(crate) fn words(n: u128, options: &OrdinalOptions) -> Option<String> {
+    match n {
+        1 if options.indonesian_first == IndonesianFirst::Pertama => Some("pertama".to_string()),
+        1..=999_999_999 => Some(format!("ke{}", cardinal(n as usize))),
+        _ => None,
+    }
+}
+
+fn cardinal(n: usize) -> String {
+    let mut words = Vec::new();
+    for (scale, name) in [(1_000_000, "juta"), (1000, "ribu")] {
+        match n / scale % 1000 {
+            0 => {}
+            1 if scale == 1000 => words.push("seribu".to_string()),
+            count => words.push(format!("{} {}", below_thousand(count), name)),
+        }
+    }
+    if !n.is_multiple_of(1000) {
+        words.push(below_thousand(n % 1000));
+    }
+    words.join(" ")
+}
+
+fn below_thousand(n: usize) -> String {
+    let mut words = Vec::new();
+    match n / 100 {
+        0 => {}
+        1 => words.push("seratus".to_string()),
+        hundreds => words.push(format!("{} ratus", UNITS[hundreds])),
+    }
+    match (n / 10 % 10, n % 10) {
+        (0, 0) => {}
+        (0, unit) => words.push(UNITS[unit].to_string()),
+        (1, 0) => words.push("sepuluh".to_string()),
+        (1, 1) => words.push("sebelas".to_string()),
+        (1, unit) => words.push(format!("{} belas", UNITS[unit])),
+        (tens, 0) => words.push(format!("{} puluh", UNITS[tens])),
+        (tens, unit) => words.push(format!("{} puluh {}", UNITS[tens], UNITS[unit])),
+    }
+    words.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::locale::IndonesianFirst;
+    use crate::{Locale, Ordinal, OrdinalOptions};
+
+    #[test]
+    fn test_indonesian_digits() {
+        for i in [1, 2, 3, 10, 21, 100] {
+            assert_eq!(
+                format!("ke-{}", i),
+                Ordinal(i).to_string_with_locale(Locale::Indonesian)
+            );
+        }
+        assert_eq!(
+            "-ke-1",
+            Ordinal(-1).to_string_with_locale(Locale::Indonesian)
+        );
+    }
+
+    #[test]
+    fn test_indonesian_words() {
+        let options = OrdinalOptions::new();
+        let words = |n: u32, options: &OrdinalOptions| {
+            Ordinal(n)
+                .to_words_locale(Locale::Indonesian, options)
+                .unwrap()
+        };
+
+        assert_eq!("pertama", words(1, &options));
+        assert_eq!(
+            "kesatu",
+            words(
+                1,
+                &options.clone().indonesian_first(IndonesianFirst::Kesatu)
+            )
+        );
+        assert_eq!("kedua", words(2, &options));
+        assert_eq!("ketiga", words(3, &options));
+        assert_eq!("kesepuluh", words(10, &options));
+        assert_eq!("kesebelas", words(11, &options));
+        assert_eq!("kedua belas", words(12, &options));
+        assert_eq!("kedua puluh", words(20, &options));
+        assert_eq!("kedua puluh satu", words(21, &options));
+        assert_eq!("keseratus", words(100, &options));
+        assert_eq!("keseratus satu", words(101, &options));
+        assert_eq!("keseribu", words(1000, &options));
+        assert_eq!("kedua ribu dua puluh empat", words(2024, &options));
+        assert_eq!("kesatu juta", words(1_000_000, &options));
+        assert!(Ordinal(0)
+            .to_words_locale(Locale::Indonesian, &options)
+            .is_err());
+    }
+}
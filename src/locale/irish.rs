@@ -0,0 +1,94 @@
+//! Irish ordinals take `ú` after every numeral (`1ú`, `3ú`, `21ú`), so only the CLDR
+//! category varies: one for 1, other for everything else.
+//!
+//! Word forms cover 1–10 and the round tens up to 100. `chéad` lenites the following
+//! noun (`an chéad bhean`) while the others prefix `h` to a vowel (`an dara háit`);
+//! that mutation belongs to the noun, so it isn't applied here. Compounds from 11 up
+//! wrap the noun (`an t-aonú lá déag`) and aren't supported.
+
+use super::{Number, OrdinalCategory};
+use alloc::format;
+use alloc::string::{String, ToString};
+
+const UNITS: [&str; 11] = [
+    "", "chéad", "dara", "tríú", "ceathrú", "cúigiú", "séú", "seachtú", "ochtú", "naoú", "deichiú",
+];
+
+const TENS: [&str; 11] = [
+    "",
+    "deichiú",
+    "fichiú",
+    "tríochadú",
+    "daicheadú",
+    "caogadú",
+    "seascadú",
+    "seachtódú",
+    "ochtódú",
+    "nóchadú",
+    "céadú",
+];
+
+pub(crate) fn format(number: &Number) -> String {
+    format!("{}ú", number)
+}
+
+pub(crate) fn category(number: &Number) -> OrdinalCategory {
+    match number.magnitude() {
+        Some(1) => OrdinalCategory::One,
+        _ => OrdinalCategory::Other,
+    }
+}
+
+/// Spells out 1–10 and the round tens up to 100.
+pub(crate) fn words(n: u128) -> Option<String> {
+    match n {
+        1..=10 => Some(UNITS[n as usize].to_string()),
+        20..=100 if n.is_multiple_of(10) => Some(TENS[(n / 10) as usize].to_string()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Locale, Ordinal, OrdinalCategory, OrdinalOptions};
+
+    #[test]
+    fn test_irish_digits_and_categories() {
+        for i in 1..=30 {
+            let ordinal = Ordinal(i);
+            assert_eq!(
+                format!("{}ú", i),
+                ordinal.to_string_with_locale(Locale::Irish)
+            );
+            let expected = if i == 1 {
+                OrdinalCategory::One
+            } else {
+                OrdinalCategory::Other
+            };
+            assert_eq!(expected, ordinal.plural_category(Locale::Irish));
+        }
+        assert_eq!(
+            OrdinalCategory::Other,
+            Ordinal(11).plural_category(Locale::Irish)
+        );
+        assert_eq!(
+            OrdinalCategory::Other,
+            Ordinal(21).plural_category(Locale::Irish)
+        );
+    }
+
+    #[test]
+    fn test_irish_words() {
+        let options = OrdinalOptions::new();
+        let words = |n: u32| Ordinal(n).to_words_locale(Locale::Irish, &options);
+
+        assert_eq!("chéad", words(1).unwrap());
+        assert_eq!("dara", words(2).unwrap());
+        assert_eq!("tríú", words(3).unwrap());
+        assert_eq!("deichiú", words(10).unwrap());
+        assert_eq!("fichiú", words(20).unwrap());
+        assert_eq!("céadú", words(100).unwrap());
+        assert!(words(11).is_err());
+        assert!(words(21).is_err());
+    }
+}
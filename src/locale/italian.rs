@@ -0,0 +1,224 @@
+//! Italian ordinals use the ordinal indicator without a period: `1º`, `1ª`.
+//! Word forms above ten drop the final vowel of the cardinal and add `-esimo`
+//! (`ventunesimo`), keeping it after `tre` and `sei` (`ventitreesimo`, `ventiseiesimo`).
+
+use super::{romance, Gender, Number, OrdinalCategory, OrdinalOptions};
+use alloc::format;
+use alloc::string::{String, ToString};
+
+const UNITS: [&str; 20] = [
+    "",
+    "uno",
+    "due",
+    "tre",
+    "quattro",
+    "cinque",
+    "sei",
+    "sette",
+    "otto",
+    "nove",
+    "dieci",
+    "undici",
+    "dodici",
+    "tredici",
+    "quattordici",
+    "quindici",
+    "sedici",
+    "diciassette",
+    "diciotto",
+    "diciannove",
+];
+
+const TENS: [&str; 10] = [
+    "",
+    "",
+    "venti",
+    "trenta",
+    "quaranta",
+    "cinquanta",
+    "sessanta",
+    "settanta",
+    "ottanta",
+    "novanta",
+];
+
+const ORDINALS: [&str; 11] = [
+    "", "primo", "secondo", "terzo", "quarto", "quinto", "sesto", "settimo", "ottavo", "nono",
+    "decimo",
+];
+
+pub(crate) fn format(number: &Number, options: &OrdinalOptions) -> String {
+    romance::indicator(number, options.gender, false)
+}
+
+/// CLDR puts 8, 11, 80 and 800 in the many category (`l'8`, `l'11`).
+pub(crate) fn category(number: &Number) -> OrdinalCategory {
+    match number.magnitude() {
+        Some(8 | 11 | 80 | 800) => OrdinalCategory::Many,
+        _ => OrdinalCategory::Other,
+    }
+}
+
+/// Spells out `n` for values from 1 to 999 999.
+pub(crate) fn words(n: u128, options: &OrdinalOptions) -> Option<String> {
+    if n == 0 || n >= 1_000_000 {
+        return None;
+    }
+    let n = n as u32;
+    let mut word = if n <= 10 {
+        ORDINALS[n as usize].to_string()
+    } else {
+        let cardinal = cardinal(n);
+        if let Some(stem) = cardinal.strip_suffix("mila") {
+            format!("{}millesimo", stem)
+        } else if cardinal.ends_with("tre") || cardinal.ends_with("sei") {
+            format!("{}esimo", cardinal)
+        } else {
+            let mut stem = cardinal;
+            stem.pop();
+            stem.push_str("esimo");
+            stem
+        }
+    };
+    if options.gender == Gender::Feminine {
+        word.pop();
+        word.push('a');
+    }
+    Some(word)
+}
+
+fn cardinal(n: u32) -> String {
+    let thousands = n / 1000;
+    let mut words = match thousands {
+        0 => String::new(),
+        1 => "mille".to_string(),
+        _ => below_thousand(thousands) + "mila",
+    };
+    words.push_str(&below_thousand(n % 1000));
+    words
+}
+
+fn below_thousand(n: u32) -> String {
+    let mut words = String::new();
+    let hundreds = n / 100;
+    if hundreds > 1 {
+        words.push_str(UNITS[hundreds as usize]);
+    }
+    if hundreds > 0 {
+        words.push_str("cento");
+    }
+    let rest = n % 100;
+    if hundreds > 0 && (rest == 1 || rest == 8 || rest / 10 == 8) {
+        words.pop();
+    }
+    if rest < 20 {
+        words.push_str(UNITS[rest as usize]);
+    } else {
+        let mut tens = TENS[(rest / 10) as usize];
+        let unit = rest % 10;
+        if unit == 1 || unit == 8 {
+            tens = &tens[..tens.len() - 1];
+        }
+        words.push_str(tens);
+        words.push_str(UNITS[unit as usize]);
+    }
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Gender, Locale, Ordinal, OrdinalOptions};
+
+    #[test]
+    fn test_italian_indicator() {
+        let feminine = OrdinalOptions::new().gender(Gender::Feminine);
+        for i in 1..=100 {
+            assert_eq!(
+                format!("{}\u{ba}", i),
+                Ordinal(i).to_string_with_locale(Locale::Italian)
+            );
+            assert_eq!(
+                format!("{}\u{aa}", i),
+                Ordinal(i).to_string_with_locale_opts(Locale::Italian, &feminine)
+            );
+        }
+    }
+
+    #[test]
+    fn test_italian_words() {
+        let masculine: [&str; 30] = [
+            "primo",
+            "secondo",
+            "terzo",
+            "quarto",
+            "quinto",
+            "sesto",
+            "settimo",
+            "ottavo",
+            "nono",
+            "decimo",
+            "undicesimo",
+            "dodicesimo",
+            "tredicesimo",
+            "quattordicesimo",
+            "quindicesimo",
+            "sedicesimo",
+            "diciassettesimo",
+            "diciottesimo",
+            "diciannovesimo",
+            "ventesimo",
+            "ventunesimo",
+            "ventiduesimo",
+            "ventitreesimo",
+            "ventiquattresimo",
+            "venticinquesimo",
+            "ventiseiesimo",
+            "ventisettesimo",
+            "ventottesimo",
+            "ventinovesimo",
+            "trentesimo",
+        ];
+
+        let options = OrdinalOptions::new();
+        let feminine = OrdinalOptions::new().gender(Gender::Feminine);
+        for (i, expected) in masculine.iter().enumerate() {
+            let ordinal = Ordinal(i + 1);
+            assert_eq!(
+                *expected,
+                ordinal.to_words_locale(Locale::Italian, &options).unwrap()
+            );
+            assert_eq!(
+                format!("{}a", &expected[..expected.len() - 1]),
+                ordinal.to_words_locale(Locale::Italian, &feminine).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_italian_words_large() {
+        let options = OrdinalOptions::new();
+        let words = |n: u32| {
+            Ordinal(n)
+                .to_words_locale(Locale::Italian, &options)
+                .unwrap()
+        };
+
+        assert_eq!("trentatreesimo", words(33));
+        assert_eq!("centesimo", words(100));
+        assert_eq!("centunesimo", words(101));
+        assert_eq!("centoduesimo", words(102));
+        assert_eq!("centottesimo", words(108));
+        assert_eq!("centottantesimo", words(180));
+        assert_eq!("duecentesimo", words(200));
+        assert_eq!("millesimo", words(1000));
+        assert_eq!("milleunesimo", words(1001));
+        assert_eq!("centotreesimo", words(103));
+        assert_eq!("duemillesimo", words(2000));
+        assert_eq!("ventitremillesimo", words(23_000));
+        assert_eq!("trentatremillesimo", words(33_000));
+        assert_eq!("ventitremilatreesimo", words(23_003));
+        assert!(Ordinal(0)
+            .to_words_locale(Locale::Italian, &options)
+            .is_err());
+    }
+}
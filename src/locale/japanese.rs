@@ -0,0 +1,117 @@
+//! Japanese ordinals either prefix `第` (`第3`) or add `番目` (`3番目`), to Arabic or
+//! kanji numerals (`第三`, `三番目`). Kanji numerals are used below 10¹⁶; larger and
+//! negative values keep Arabic digits.
+
+#[cfg(feature = "cjk")]
+use super::{cjk, Number, OrdinalOptions};
+#[cfg(feature = "cjk")]
+use alloc::format;
+#[cfg(feature = "cjk")]
+use alloc::string::{String, ToString};
+
+/// Affix style for Japanese ordinals.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum JapaneseStyle {
+    /// `第3`.
+    #[default]
+    DaiPrefix,
+    /// `3番目`.
+    BanmeSuffix,
+}
+
+/// Numerals for Japanese ordinals.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum JapaneseNumerals {
+    /// `3`.
+    #[default]
+    Arabic,
+    /// `三`.
+    Kanji,
+}
+
+#[cfg(feature = "cjk")]
+pub(crate) fn format(number: &Number, options: &OrdinalOptions) -> String {
+    let numeral = match options.japanese_numerals {
+        JapaneseNumerals::Kanji => number.value().and_then(|n| cjk::spell(n, &cjk::JAPANESE)),
+        JapaneseNumerals::Arabic => None,
+    }
+    .unwrap_or_else(|| number.to_string());
+    match options.japanese_style {
+        JapaneseStyle::DaiPrefix => format!("第{}", numeral),
+        JapaneseStyle::BanmeSuffix => format!("{}番目", numeral),
+    }
+}
+
+#[cfg(all(test, feature = "cjk"))]
+mod tests {
+    use crate::locale::{JapaneseNumerals, JapaneseStyle};
+    use crate::{Locale, Ordinal, OrdinalOptions};
+
+    #[test]
+    fn test_japanese() {
+        let values: [(u64, &str); 6] = [
+            (1, "一"),
+            (3, "三"),
+            (10, "十"),
+            (21, "二十一"),
+            (100, "百"),
+            (1234, "千二百三十四"),
+        ];
+
+        for (n, kanji) in values {
+            let ordinal = Ordinal(n);
+            let options = |style, numerals| {
+                OrdinalOptions::new()
+                    .japanese_style(style)
+                    .japanese_numerals(numerals)
+            };
+            assert_eq!(
+                format!("第{}", n),
+                ordinal.to_string_with_locale_opts(
+                    Locale::Japanese,
+                    &options(JapaneseStyle::DaiPrefix, JapaneseNumerals::Arabic)
+                )
+            );
+            assert_eq!(
+                format!("第{}", kanji),
+                ordinal.to_string_with_locale_opts(
+                    Locale::Japanese,
+                    &options(JapaneseStyle::DaiPrefix, JapaneseNumerals::Kanji)
+                )
+            );
+            assert_eq!(
+                format!("{}番目", n),
+                ordinal.to_string_with_locale_opts(
+                    Locale::Japanese,
+                    &options(JapaneseStyle::BanmeSuffix, JapaneseNumerals::Arabic)
+                )
+            );
+            assert_eq!(
+                format!("{}番目", kanji),
+                ordinal.to_string_with_locale_opts(
+                    Locale::Japanese,
+                    &options(JapaneseStyle::BanmeSuffix, JapaneseNumerals::Kanji)
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_japanese_kanji_large_values() {
+        let kanji = OrdinalOptions::new().japanese_numerals(JapaneseNumerals::Kanji);
+        let format = |n: i128| Ordinal(n).to_string_with_locale_opts(Locale::Japanese, &kanji);
+
+        assert_eq!("第十一", format(11));
+        assert_eq!("第百五", format(105));
+        assert_eq!("第九千九百九十九", format(9999));
+        assert_eq!("第一万", format(10_000));
+        assert_eq!("第十二万三千四百五十六", format(123_456));
+        assert_eq!("第一億百", format(100_000_100));
+        assert_eq!("第三兆", format(3_000_000_000_000));
+        assert_eq!("第10000000000000000", format(10_000_000_000_000_000));
+        assert_eq!("第-3", format(-3));
+        assert_eq!("第3", Ordinal(3).to_string_with_locale(Locale::Japanese));
+    }
+}
@@ -0,0 +1,79 @@
+//! Korean ordinals count with `번째`. Small values use native Korean numerals in their
+//! bound forms (`첫 번째`, `두 번째`, `스무 번째`, `스물한 번째`) and larger ones Arabic
+//! digits (`21번째`); [`OrdinalOptions::korean_native_limit`] sets where one ends
+//! and the other begins. Native numerals go up to 99.
+
+use super::{Number, OrdinalOptions};
+use alloc::format;
+use alloc::string::{String, ToString};
+
+/// Units in the bound form taken before a counter.
+const UNITS: [&str; 10] = [
+    "", "한", "두", "세", "네", "다섯", "여섯", "일곱", "여덟", "아홉",
+];
+
+const TENS: [&str; 10] = [
+    "", "열", "스물", "서른", "마흔", "쉰", "예순", "일흔", "여든", "아흔",
+];
+
+pub(crate) fn format(number: &Number, options: &OrdinalOptions) -> String {
+    match number.value() {
+        Some(n @ 1..=99) if n <= options.korean_native_limit as u128 => {
+            format!("{} 번째", native(n as usize))
+        }
+        _ => format!("{}번째", number),
+    }
+}
+
+fn native(n: usize) -> String {
+    match (n / 10, n % 10) {
+        (0, 1) => "첫".to_string(),
+        (2, 0) => "스무".to_string(),
+        (tens, units) => format!("{}{}", TENS[tens], UNITS[units]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Locale, Ordinal, OrdinalOptions};
+
+    #[test]
+    fn test_korean_native() {
+        let korean: [(u32, &str); 9] = [
+            (1, "첫 번째"),
+            (2, "두 번째"),
+            (3, "세 번째"),
+            (4, "네 번째"),
+            (5, "다섯 번째"),
+            (10, "열 번째"),
+            (11, "열한 번째"),
+            (12, "열두 번째"),
+            (20, "스무 번째"),
+        ];
+        for (n, expected) in korean {
+            assert_eq!(expected, Ordinal(n).to_string_with_locale(Locale::Korean));
+        }
+    }
+
+    #[test]
+    fn test_korean_limit() {
+        assert_eq!("21번째", Ordinal(21).to_string_with_locale(Locale::Korean));
+        assert_eq!("0번째", Ordinal(0).to_string_with_locale(Locale::Korean));
+        assert_eq!("-1번째", Ordinal(-1).to_string_with_locale(Locale::Korean));
+
+        let native = OrdinalOptions::new().korean_native_limit(99);
+        let format = |n: u32, options: &OrdinalOptions| {
+            Ordinal(n).to_string_with_locale_opts(Locale::Korean, options)
+        };
+        assert_eq!("스물한 번째", format(21, &native));
+        assert_eq!("스물네 번째", format(24, &native));
+        assert_eq!("아흔아홉 번째", format(99, &native));
+        assert_eq!("100번째", format(100, &native));
+
+        let digits = OrdinalOptions::new().korean_native_limit(0);
+        assert_eq!("1번째", format(1, &digits));
+        let ten = OrdinalOptions::new().korean_native_limit(10);
+        assert_eq!("열 번째", format(10, &ten));
+        assert_eq!("11번째", format(11, &ten));
+    }
+}
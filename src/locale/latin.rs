@@ -0,0 +1,231 @@
+//! Latin ordinals are abbreviated as the Roman numeral followed by a period (`XXI.`),
+//! falling back to Arabic digits outside 1–3999 where Roman numerals don't reach.
+//!
+//! Word forms are first- and second-declension adjectives in the nominative
+//! (`primus`, `prima`, `primum`), every component inflecting: `vicesima prima`.
+//! From 18 to 89, an 8 or 9 in the units counts down from the next ten
+//! (`duodevicesimus`, `undetricesimus`). Values from 1 to 3999 are supported.
+
+use super::{Gender, Number, OrdinalOptions};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+
+const UNITS: [&str; 20] = [
+    "",
+    "primus",
+    "secundus",
+    "tertius",
+    "quartus",
+    "quintus",
+    "sextus",
+    "septimus",
+    "octavus",
+    "nonus",
+    "decimus",
+    "undecimus",
+    "duodecimus",
+    "tertius decimus",
+    "quartus decimus",
+    "quintus decimus",
+    "sextus decimus",
+    "septimus decimus",
+    "duodevicesimus",
+    "undevicesimus",
+];
+
+/// Tens stems, taking `-esimus` and the `duode-`/`unde-` prefixes.
+const TENS: [&str; 10] = [
+    "",
+    "decimus",
+    "vicesimus",
+    "tricesimus",
+    "quadragesimus",
+    "quinquagesimus",
+    "sexagesimus",
+    "septuagesimus",
+    "octogesimus",
+    "nonagesimus",
+];
+
+const HUNDREDS: [&str; 10] = [
+    "",
+    "centesimus",
+    "ducentesimus",
+    "trecentesimus",
+    "quadringentesimus",
+    "quingentesimus",
+    "sescentesimus",
+    "septingentesimus",
+    "octingentesimus",
+    "nongentesimus",
+];
+
+const THOUSANDS: [&str; 4] = ["", "millesimus", "bis millesimus", "ter millesimus"];
+
+const ROMAN: [(u128, &str); 13] = [
+    (1000, "M"),
+    (900, "CM"),
+    (500, "D"),
+    (400, "CD"),
+    (100, "C"),
+    (90, "XC"),
+    (50, "L"),
+    (40, "XL"),
+    (10, "X"),
+    (9, "IX"),
+    (5, "V"),
+    (4, "IV"),
+    (1, "I"),
+];
+
+pub(crate) fn format(number: &Number) -> String {
+    match number.value() {
+        Some(n @ 1..=3999) => format!("{}.", roman(n)),
+        _ => format!("{}.", number),
+    }
+}
+
+fn roman(mut n: u128) -> String {
+    let mut numeral = String::new();
+    for (value, letters) in ROMAN {
+        while n >= value {
+            numeral.push_str(letters);
+            n -= value;
+        }
+    }
+    numeral
+}
+
+/// Spells out `n` for values from 1 to 3999.
+pub(crate) fn words(n: u128, options: &OrdinalOptions) -> Option<String> {
+    if n == 0 || n >= 4000 {
+        return None;
+    }
+    let n = n as usize;
+    let mut parts = vec![THOUSANDS[n / 1000], HUNDREDS[n / 100 % 10]];
+    let rest = n % 100;
+    let counted_down;
+    match (rest / 10, rest % 10) {
+        (0..=1, _) => parts.push(UNITS[rest]),
+        (tens @ 2..=8, unit @ 8..=9) => {
+            let prefix = if unit == 8 { "duode" } else { "unde" };
+            counted_down = format!("{}{}", prefix, TENS[tens + 1]);
+            parts.push(&counted_down);
+        }
+        (tens, unit) => parts.extend([TENS[tens], UNITS[unit]]),
+    }
+    Some(
+        parts
+            .iter()
+            .flat_map(|part| part.split(' '))
+            .filter(|word| !word.is_empty())
+            .map(|word| inflect(word, options.gender))
+            .collect::<Vec<_>>()
+            .join(" "),
+    )
+}
+
+fn inflect(masculine: &str, gender: Gender) -> String {
+    match (masculine.strip_suffix("us"), gender) {
+        (Some(stem), Gender::Feminine) => format!("{}a", stem),
+        (Some(stem), Gender::Neuter) => format!("{}um", stem),
+        _ => masculine.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Gender, Locale, Ordinal, OrdinalOptions};
+
+    #[test]
+    fn test_latin_digits() {
+        let latin: [(u32, &str); 8] = [
+            (1, "I."),
+            (4, "IV."),
+            (9, "IX."),
+            (21, "XXI."),
+            (49, "XLIX."),
+            (1999, "MCMXCIX."),
+            (3999, "MMMCMXCIX."),
+            (4000, "4000."),
+        ];
+        for (n, expected) in latin {
+            assert_eq!(expected, Ordinal(n).to_string_with_locale(Locale::Latin));
+        }
+        assert_eq!("0.", Ordinal(0).to_string_with_locale(Locale::Latin));
+    }
+
+    #[test]
+    fn test_latin_words() {
+        let masculine: [&str; 31] = [
+            "primus",
+            "secundus",
+            "tertius",
+            "quartus",
+            "quintus",
+            "sextus",
+            "septimus",
+            "octavus",
+            "nonus",
+            "decimus",
+            "undecimus",
+            "duodecimus",
+            "tertius decimus",
+            "quartus decimus",
+            "quintus decimus",
+            "sextus decimus",
+            "septimus decimus",
+            "duodevicesimus",
+            "undevicesimus",
+            "vicesimus",
+            "vicesimus primus",
+            "vicesimus secundus",
+            "vicesimus tertius",
+            "vicesimus quartus",
+            "vicesimus quintus",
+            "vicesimus sextus",
+            "vicesimus septimus",
+            "duodetricesimus",
+            "undetricesimus",
+            "tricesimus",
+            "tricesimus primus",
+        ];
+        let others: [(u32, &str); 6] = [
+            (40, "quadragesimus"),
+            (50, "quinquagesimus"),
+            (90, "nonagesimus"),
+            (100, "centesimus"),
+            (1000, "millesimus"),
+            (2024, "bis millesimus vicesimus quartus"),
+        ];
+
+        let options = OrdinalOptions::new();
+        let feminine = OrdinalOptions::new().gender(Gender::Feminine);
+        let neuter = OrdinalOptions::new().gender(Gender::Neuter);
+        let cases = masculine
+            .iter()
+            .enumerate()
+            .map(|(i, words)| (i as u32 + 1, *words))
+            .chain(others);
+        for (n, expected) in cases {
+            let ordinal = Ordinal(n);
+            assert_eq!(
+                expected,
+                ordinal.to_words_locale(Locale::Latin, &options).unwrap()
+            );
+            assert_eq!(
+                expected.replace("us", "a"),
+                ordinal.to_words_locale(Locale::Latin, &feminine).unwrap()
+            );
+            assert_eq!(
+                expected.replace("us", "um"),
+                ordinal.to_words_locale(Locale::Latin, &neuter).unwrap()
+            );
+        }
+        assert!(Ordinal(4000)
+            .to_words_locale(Locale::Latin, &options)
+            .is_err());
+    }
+}
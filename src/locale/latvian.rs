@@ -0,0 +1,169 @@
+//! Latvian ordinals are written as the number followed by a period: `3.`.
+//! Word forms cover 1 to 100 in the nominative, with the definite adjective endings
+//! `-ais` and `-ā`. Only the last word of a compound is ordinal, `divdesmit pirmais`.
+//! There's no neuter, so it takes the masculine.
+
+use super::{Gender, OrdinalOptions};
+use alloc::format;
+use alloc::string::{String, ToString};
+
+/// The masculine forms from 1 to 19.
+const UNITS: [&str; 20] = [
+    "",
+    "pirmais",
+    "otrais",
+    "trešais",
+    "ceturtais",
+    "piektais",
+    "sestais",
+    "septītais",
+    "astotais",
+    "devītais",
+    "desmitais",
+    "vienpadsmitais",
+    "divpadsmitais",
+    "trīspadsmitais",
+    "četrpadsmitais",
+    "piecpadsmitais",
+    "sešpadsmitais",
+    "septiņpadsmitais",
+    "astoņpadsmitais",
+    "deviņpadsmitais",
+];
+
+/// The cardinal tens, which lead a compound. Alone, they take `-ais`.
+const TENS: [&str; 10] = [
+    "",
+    "",
+    "divdesmit",
+    "trīsdesmit",
+    "četrdesmit",
+    "piecdesmit",
+    "sešdesmit",
+    "septiņdesmit",
+    "astoņdesmit",
+    "deviņdesmit",
+];
+
+/// Spells out `n` for values from 1 to 100.
+pub(crate) fn words(n: u128, options: &OrdinalOptions) -> Option<String> {
+    let gender = options.gender;
+    match n {
+        1..=19 => Some(inflect(UNITS[n as usize], gender)),
+        20..=99 => {
+            let tens = TENS[(n / 10) as usize];
+            Some(match n % 10 {
+                0 => inflect(&format!("{}ais", tens), gender),
+                unit => format!("{} {}", tens, inflect(UNITS[unit as usize], gender)),
+            })
+        }
+        100 => Some(inflect("simtais", gender)),
+        _ => None,
+    }
+}
+
+fn inflect(masculine: &str, gender: Gender) -> String {
+    match gender {
+        Gender::Feminine => format!("{}ā", masculine.strip_suffix("ais").unwrap_or(masculine)),
+        Gender::Masculine | Gender::Neuter | Gender::Common => masculine.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Gender, Locale, Ordinal, OrdinalOptions};
+
+    #[test]
+    fn test_latvian_digits() {
+        for i in [1, 2, 3, 21, 31, 100, 2024] {
+            assert_eq!(
+                format!("{}.", i),
+                Ordinal(i).to_string_with_locale(Locale::Latvian)
+            );
+        }
+        let feminine = OrdinalOptions::new().gender(Gender::Feminine);
+        assert_eq!(
+            "3.",
+            Ordinal(3).to_string_with_locale_opts(Locale::Latvian, &feminine)
+        );
+    }
+
+    #[test]
+    fn test_latvian_words() {
+        // Masculine and feminine.
+        let latvian_ordinals: [(&str, &str); 31] = [
+            ("pirmais", "pirmā"),
+            ("otrais", "otrā"),
+            ("trešais", "trešā"),
+            ("ceturtais", "ceturtā"),
+            ("piektais", "piektā"),
+            ("sestais", "sestā"),
+            ("septītais", "septītā"),
+            ("astotais", "astotā"),
+            ("devītais", "devītā"),
+            ("desmitais", "desmitā"),
+            ("vienpadsmitais", "vienpadsmitā"),
+            ("divpadsmitais", "divpadsmitā"),
+            ("trīspadsmitais", "trīspadsmitā"),
+            ("četrpadsmitais", "četrpadsmitā"),
+            ("piecpadsmitais", "piecpadsmitā"),
+            ("sešpadsmitais", "sešpadsmitā"),
+            ("septiņpadsmitais", "septiņpadsmitā"),
+            ("astoņpadsmitais", "astoņpadsmitā"),
+            ("deviņpadsmitais", "deviņpadsmitā"),
+            ("divdesmitais", "divdesmitā"),
+            ("divdesmit pirmais", "divdesmit pirmā"),
+            ("divdesmit otrais", "divdesmit otrā"),
+            ("divdesmit trešais", "divdesmit trešā"),
+            ("divdesmit ceturtais", "divdesmit ceturtā"),
+            ("divdesmit piektais", "divdesmit piektā"),
+            ("divdesmit sestais", "divdesmit sestā"),
+            ("divdesmit septītais", "divdesmit septītā"),
+            ("divdesmit astotais", "divdesmit astotā"),
+            ("divdesmit devītais", "divdesmit devītā"),
+            ("trīsdesmitais", "trīsdesmitā"),
+            ("trīsdesmit pirmais", "trīsdesmit pirmā"),
+        ];
+
+        let genders = [Gender::Masculine, Gender::Feminine];
+        for (i, forms) in latvian_ordinals.iter().enumerate() {
+            for (gender, expected) in genders.into_iter().zip([forms.0, forms.1]) {
+                let options = OrdinalOptions::new().gender(gender);
+                assert_eq!(
+                    expected,
+                    Ordinal(i + 1)
+                        .to_words_locale(Locale::Latvian, &options)
+                        .unwrap()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_latvian_tens_and_hundred() {
+        let options = OrdinalOptions::new();
+        let words = |n: u32| Ordinal(n).to_words_locale(Locale::Latvian, &options);
+        let tens = [
+            "četrdesmitais",
+            "piecdesmitais",
+            "sešdesmitais",
+            "septiņdesmitais",
+            "astoņdesmitais",
+            "deviņdesmitais",
+        ];
+        for (i, expected) in tens.iter().enumerate() {
+            assert_eq!(*expected, words(40 + 10 * i as u32).unwrap());
+        }
+        assert_eq!("deviņdesmit devītais", words(99).unwrap());
+        assert_eq!("simtais", words(100).unwrap());
+        let feminine = OrdinalOptions::new().gender(Gender::Feminine);
+        assert_eq!(
+            "simtā",
+            Ordinal(100)
+                .to_words_locale(Locale::Latvian, &feminine)
+                .unwrap()
+        );
+        assert!(words(0).is_err());
+        assert!(words(101).is_err());
+    }
+}
@@ -0,0 +1,206 @@
+//! Lithuanian abbreviates an ordinal as the number, a hyphen and the ending of the word:
+//! `1-asis`, `1-oji`, or `1-as`, `1-a` in the simple forms. Word forms cover 1 to 99;
+//! only the last word of a compound is ordinal, `dvidešimt pirmas`.
+//!
+//! The pronominal (definite) forms, `pirmasis` and `pirmoji`, are the default as they're
+//! the ones used for rankings; [`OrdinalOptions::definite`] set to `false` gives the
+//! simple `pirmas` and `pirma`. There's no neuter, so it takes the masculine.
+
+use super::{Gender, Number, OrdinalOptions};
+use alloc::format;
+use alloc::string::{String, ToString};
+
+/// The simple masculine forms from 1 to 19.
+const UNITS: [&str; 20] = [
+    "",
+    "pirmas",
+    "antras",
+    "trečias",
+    "ketvirtas",
+    "penktas",
+    "šeštas",
+    "septintas",
+    "aštuntas",
+    "devintas",
+    "dešimtas",
+    "vienuoliktas",
+    "dvyliktas",
+    "tryliktas",
+    "keturioliktas",
+    "penkioliktas",
+    "šešioliktas",
+    "septynioliktas",
+    "aštuonioliktas",
+    "devynioliktas",
+];
+
+/// The cardinal tens, which lead a compound. Alone, they take `-as`.
+const TENS: [&str; 10] = [
+    "",
+    "",
+    "dvidešimt",
+    "trisdešimt",
+    "keturiasdešimt",
+    "penkiasdešimt",
+    "šešiasdešimt",
+    "septyniasdešimt",
+    "aštuoniasdešimt",
+    "devyniasdešimt",
+];
+
+pub(crate) fn format(number: &Number, options: &OrdinalOptions) -> String {
+    // `trečias` and the compounds ending in it keep its `i` in the abbreviation.
+    let soft = number.last_two() % 10 == 3 && number.last_two() != 13;
+    let ending = ending(options);
+    if soft {
+        format!("{}-i{}", number, ending)
+    } else {
+        format!("{}-{}", number, ending)
+    }
+}
+
+/// Spells out `n` for values from 1 to 99.
+pub(crate) fn words(n: u128, options: &OrdinalOptions) -> Option<String> {
+    let masculine = match n {
+        1..=19 => UNITS[n as usize].to_string(),
+        20..=99 => {
+            let tens = TENS[(n / 10) as usize];
+            match n % 10 {
+                0 => format!("{}as", tens),
+                unit => format!("{} {}", tens, UNITS[unit as usize]),
+            }
+        }
+        _ => return None,
+    };
+    let stem = masculine.strip_suffix("as").unwrap_or(&masculine);
+    Some(format!("{}{}", stem, ending(options)))
+}
+
+/// Returns the ending after the stem, like `asis` in `pirmasis`.
+fn ending(options: &OrdinalOptions) -> &'static str {
+    match (options.gender, options.definite) {
+        (Gender::Feminine, true) => "oji",
+        (Gender::Feminine, false) => "a",
+        (_, true) => "asis",
+        (_, false) => "as",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Gender, Locale, Ordinal, OrdinalOptions};
+
+    #[test]
+    fn test_lithuanian_words() {
+        // Masculine and feminine, pronominal and then simple.
+        let lithuanian_ordinals: [(&str, &str, &str, &str); 21] = [
+            ("pirmasis", "pirmoji", "pirmas", "pirma"),
+            ("antrasis", "antroji", "antras", "antra"),
+            ("trečiasis", "trečioji", "trečias", "trečia"),
+            ("ketvirtasis", "ketvirtoji", "ketvirtas", "ketvirta"),
+            ("penktasis", "penktoji", "penktas", "penkta"),
+            ("šeštasis", "šeštoji", "šeštas", "šešta"),
+            ("septintasis", "septintoji", "septintas", "septinta"),
+            ("aštuntasis", "aštuntoji", "aštuntas", "aštunta"),
+            ("devintasis", "devintoji", "devintas", "devinta"),
+            ("dešimtasis", "dešimtoji", "dešimtas", "dešimta"),
+            (
+                "vienuoliktasis",
+                "vienuoliktoji",
+                "vienuoliktas",
+                "vienuolikta",
+            ),
+            ("dvyliktasis", "dvyliktoji", "dvyliktas", "dvylikta"),
+            ("tryliktasis", "tryliktoji", "tryliktas", "trylikta"),
+            (
+                "keturioliktasis",
+                "keturioliktoji",
+                "keturioliktas",
+                "keturiolikta",
+            ),
+            (
+                "penkioliktasis",
+                "penkioliktoji",
+                "penkioliktas",
+                "penkiolikta",
+            ),
+            ("šešioliktasis", "šešioliktoji", "šešioliktas", "šešiolikta"),
+            (
+                "septynioliktasis",
+                "septynioliktoji",
+                "septynioliktas",
+                "septyniolikta",
+            ),
+            (
+                "aštuonioliktasis",
+                "aštuonioliktoji",
+                "aštuonioliktas",
+                "aštuoniolikta",
+            ),
+            (
+                "devynioliktasis",
+                "devynioliktoji",
+                "devynioliktas",
+                "devyniolikta",
+            ),
+            ("dvidešimtasis", "dvidešimtoji", "dvidešimtas", "dvidešimta"),
+            (
+                "dvidešimt pirmasis",
+                "dvidešimt pirmoji",
+                "dvidešimt pirmas",
+                "dvidešimt pirma",
+            ),
+        ];
+
+        for (i, forms) in lithuanian_ordinals.iter().enumerate() {
+            let cases = [
+                (Gender::Masculine, true, forms.0),
+                (Gender::Feminine, true, forms.1),
+                (Gender::Masculine, false, forms.2),
+                (Gender::Feminine, false, forms.3),
+            ];
+            for (gender, definite, expected) in cases {
+                let options = OrdinalOptions::new().gender(gender).definite(definite);
+                assert_eq!(
+                    expected,
+                    Ordinal(i + 1)
+                        .to_words_locale(Locale::Lithuanian, &options)
+                        .unwrap()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_lithuanian_abbreviations() {
+        let format = |n: i32, gender: Gender, definite: bool| {
+            let options = OrdinalOptions::new().gender(gender).definite(definite);
+            Ordinal(n).to_string_with_locale_opts(Locale::Lithuanian, &options)
+        };
+        for n in [1, 2, 4, 10, 11, 13, 20, 21] {
+            assert_eq!(format!("{}-asis", n), format(n, Gender::Masculine, true));
+            assert_eq!(format!("{}-oji", n), format(n, Gender::Feminine, true));
+            assert_eq!(format!("{}-as", n), format(n, Gender::Masculine, false));
+            assert_eq!(format!("{}-a", n), format(n, Gender::Feminine, false));
+        }
+        assert_eq!("3-iasis", format(3, Gender::Masculine, true));
+        assert_eq!("3-ioji", format(3, Gender::Feminine, true));
+        assert_eq!("23-ias", format(23, Gender::Masculine, false));
+        assert_eq!("103-ia", format(103, Gender::Feminine, false));
+        assert_eq!(
+            "1-asis",
+            Ordinal(1).to_string_with_locale(Locale::Lithuanian)
+        );
+    }
+
+    #[test]
+    fn test_lithuanian_tens() {
+        let options = OrdinalOptions::new().definite(false);
+        let words = |n: u32| Ordinal(n).to_words_locale(Locale::Lithuanian, &options);
+        assert_eq!("trisdešimtas", words(30).unwrap());
+        assert_eq!("trisdešimt pirmas", words(31).unwrap());
+        assert_eq!("devyniasdešimt trečias", words(93).unwrap());
+        assert!(words(0).is_err());
+        assert!(words(100).is_err());
+    }
+}
@@ -0,0 +1,872 @@
+//! Locale-aware ordinal formatting.
+//!
+//! ```rust
+//! use ordinal_type::{Gender, Locale, Ordinal, OrdinalOptions};
+//!
+//! let options = OrdinalOptions::new().gender(Gender::Feminine);
+//! assert_eq!(Ordinal(1).to_string_with_locale_opts(Locale::Spanish, &options), "1.ª");
+//! ```
+
+mod arabic;
+mod basque;
+mod bengali;
+mod bidi;
+mod bulgarian;
+mod catalan;
+mod chinese;
+#[cfg(feature = "cjk")]
+mod cjk;
+mod croatian;
+mod czech;
+mod danish;
+mod digits;
+mod dutch;
+pub(crate) mod english;
+mod esperanto;
+mod estonian;
+mod fallback;
+mod filipino;
+mod finnish;
+mod french;
+mod galician;
+mod german;
+mod greek;
+mod hebrew;
+mod hindi;
+mod hungarian;
+mod hyphen;
+mod icelandic;
+mod indonesian;
+mod irish;
+mod italian;
+mod japanese;
+#[cfg(feature = "cjk")]
+mod korean;
+mod latin;
+mod latvian;
+mod lithuanian;
+mod norwegian;
+mod period;
+mod persian;
+mod polish;
+mod portuguese;
+mod romance;
+mod romanian;
+mod russian;
+mod scottish_gaelic;
+mod serbian;
+mod slavic;
+mod slovak;
+mod slovenian;
+mod spanish;
+mod swahili;
+mod swedish;
+mod tag;
+mod tamil;
+mod thai;
+mod turkish;
+mod ukrainian;
+mod urdu;
+mod vietnamese;
+mod welsh;
+
+pub use basque::BasqueFirst;
+pub use chinese::{ChineseNumerals, ChineseScript};
+pub use digits::Digits;
+pub use dutch::DutchStyle;
+pub use filipino::FilipinoFirst;
+pub use indonesian::IndonesianFirst;
+pub use japanese::{JapaneseNumerals, JapaneseStyle};
+pub use swahili::SwahiliPrefix;
+pub use tamil::TamilFirst;
+pub use thai::ThaiNumerals;
+pub use turkish::TurkishStyle;
+pub use vietnamese::VietnameseNumerals;
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::fmt::{self, Display, Formatter};
+
+/// A language whose ordinal conventions can be used for formatting.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum Locale {
+    /// English: `1st`, `2nd`, `3rd`, `4th`.
+    #[default]
+    English,
+    /// Spanish: `1.º`, `1.ª`, or `1º`, `1ª` without the period.
+    Spanish,
+    /// German: `3.`, spelled `dritter`, `dritte`, `drittes`.
+    ///
+    /// The period is part of the ordinal and always written, so a sentence ending in one
+    /// already ends with a period: write `Er wurde 3.`, not `Er wurde 3..`.
+    German,
+    /// Dutch: `1e`, `2e`, or `1ste`, `2de` in the long style.
+    Dutch,
+    /// Italian: `1º`, `1ª`, spelled `primo`, `ventunesimo`.
+    Italian,
+    /// Portuguese, European and Brazilian: `1.º`, `1.ª`, spelled `primeiro`, `vigésimo primeiro`.
+    Portuguese,
+    /// Swedish: `1:a`, `2:a`, `3:e`, spelled `första`, `tjugoförsta`.
+    Swedish,
+    /// Norwegian Bokmål: `3.`, spelled `tredje`, `tjueførste`.
+    Norwegian,
+    /// Danish: `5.`, spelled `femte`, `enogtyvende`.
+    Danish,
+    /// Finnish: `3.`, spelled `kolmas`, `kahdeskymmenesensimmäinen`.
+    Finnish,
+    /// Russian: `1-й`, `1-я`, `1-е`, `1-го`.
+    Russian,
+    /// Ukrainian: `1-й`, `1-ша`, `1-ше`, `1-го`.
+    Ukrainian,
+    /// Polish: `3.`, spelled `trzeci`, `dwudziesty pierwszy`.
+    Polish,
+    /// Czech: `21.`, spelled `dvacátý první`.
+    Czech,
+    /// Slovak: `3.`, spelled `tretí`, `dvadsiaty prvý`.
+    Slovak,
+    /// Welsh: `1af`, `2il`, `3ydd`, `5ed`, `7fed`, `11eg`, `21ain`.
+    Welsh,
+    /// Irish: `1ú`, `3ú`, spelled `chéad`, `tríú`.
+    Irish,
+    /// Catalan: `1r`, `2n`, `3r`, `4t`, `5è`, `1a`.
+    Catalan,
+    /// Romanian: `primul`, `al 3-lea`, `a 3-a`.
+    Romanian,
+    /// Hungarian: `3.`, spelled `harmadik`, `huszonegyedik`.
+    Hungarian,
+    /// Greek: `1ος`, `1η`, `1ο` by gender, spelled `πρώτος`, `εικοστός πρώτος`.
+    Greek,
+    /// Turkish: `1'inci`, `3'üncü`, `6'ncı`, or `3.` in the period style.
+    Turkish,
+    /// Hebrew: the bare numeral `3`, spelled `שלישי`/`שלישית`.
+    Hebrew,
+    /// Arabic: the bare numeral `3` or `٣`, spelled `الثالث`/`الثالثة`.
+    Arabic,
+    /// Hindi: `3वाँ` or `३वाँ`, spelled `तीसरा`/`तीसरी`.
+    Hindi,
+    /// Bengali: `১ম`, `২য়`, `৪র্থ`, `২১তম`.
+    Bengali,
+    /// Japanese: `第3`, `3番目`, `第三`, `三番目`.
+    ///
+    /// Needs the `cjk` feature. Without it, this formats as English, the end of its
+    /// [`Locale::fallbacks`] chain; check [`Locale::resolve`] to see which was used.
+    /// It has no word forms either way.
+    Japanese,
+    /// Chinese: `第3`, `第三`.
+    ///
+    /// Needs the `cjk` feature. Without it, this formats as English, the end of its
+    /// [`Locale::fallbacks`] chain; check [`Locale::resolve`] to see which was used.
+    /// It has no word forms either way.
+    Chinese,
+    /// Korean: `첫 번째`, `스무 번째`, `21번째`.
+    ///
+    /// Needs the `cjk` feature. Without it, this formats as English, the end of its
+    /// [`Locale::fallbacks`] chain; check [`Locale::resolve`] to see which was used.
+    /// It has no word forms either way.
+    Korean,
+    /// Vietnamese: `thứ nhất`, `thứ tư`, `thứ 21`.
+    Vietnamese,
+    /// Thai: `ที่ 3`, `ที่ ๓`, `ที่สาม`.
+    Thai,
+    /// Indonesian: `ke-3`, spelled `ketiga`, `pertama`.
+    Indonesian,
+    /// Filipino: `ika-3`, spelled `ikatlo`, `una`.
+    Filipino,
+    /// Persian: `3م` or `۳م`, spelled `سوم`, `بیست‌ویکم`.
+    Persian,
+    /// Esperanto: `3-a`, spelled `tria`, `dudek-unua`.
+    Esperanto,
+    /// Latin: `XXI.`, spelled `vicesimus primus`.
+    Latin,
+    /// French: `1er`, `1re`, `2e`.
+    French,
+    /// Icelandic: `3.`, spelled `þriðji`, `tuttugasti og fyrsti`.
+    Icelandic,
+    /// Croatian: `21.`, spelled `dvadeset prvi`, `prva`, `prvo`.
+    Croatian,
+    /// Serbian: `3.`, spelled `трећи` or `treći`, `двадесет први` or `dvadeset prvi`.
+    Serbian,
+    /// Bulgarian: `1-ви`, `2-ра`, `3-то` by gender, spelled `първи`, `двадесет и първи`.
+    Bulgarian,
+    /// Lithuanian: `1-asis`, `1-oji`, or `1-as`, `1-a`, spelled `pirmasis`, `dvidešimt pirmas`.
+    Lithuanian,
+    /// Latvian: `3.`, spelled `trešais`, `trešā`, `divdesmit pirmais`.
+    Latvian,
+    /// Estonian: `3.`, spelled `kolmas`, `kahekümne esimene`.
+    Estonian,
+    /// Slovenian: `3.`, spelled `tretji`, `enaindvajseti`.
+    Slovenian,
+    /// Scottish Gaelic: `1d`, `2na`, `3mh`, `11d`, `21mh`, spelled `a' chiad`, `an dàrna`.
+    ScottishGaelic,
+    /// Basque: `3.`, spelled `hirugarren`, `hogeita batgarren`.
+    Basque,
+    /// Galician: `1.º`, `1.ª`, spelled `primeiro`, `vixésimo primeiro`.
+    Galician,
+    /// Swahili: `wa 3`, spelled `wa kwanza`, `wa ishirini na moja`, by noun class.
+    Swahili,
+    /// Urdu: `7واں` or `۷واں`, spelled `تیسرا`/`تیسری`.
+    Urdu,
+    /// Tamil: `3வது` or `௩வது`, with `முதல்` for first, spelled `மூன்றாவது`.
+    Tamil,
+}
+
+impl Locale {
+    pub(crate) fn format(self, number: &Number, options: &OrdinalOptions) -> String {
+        match self.resolve() {
+            Locale::English => format!("{}{}", number, english::suffix(number.last_two())),
+            Locale::Spanish => spanish::format(number, options),
+            Locale::German
+            | Locale::Norwegian
+            | Locale::Danish
+            | Locale::Finnish
+            | Locale::Polish
+            | Locale::Czech
+            | Locale::Slovak
+            | Locale::Hungarian
+            | Locale::Icelandic
+            | Locale::Croatian
+            | Locale::Serbian
+            | Locale::Latvian
+            | Locale::Estonian
+            | Locale::Slovenian
+            | Locale::Basque => period::format(number),
+            Locale::Dutch => dutch::format(number, options),
+            Locale::Italian => italian::format(number, options),
+            Locale::Portuguese => portuguese::format(number, options),
+            Locale::Swedish => swedish::format(number),
+            Locale::Russian => russian::format(number, options),
+            Locale::Ukrainian => ukrainian::format(number, options),
+            Locale::Welsh => welsh::format(number),
+            Locale::Irish => irish::format(number),
+            Locale::Catalan => catalan::format(number, options),
+            Locale::Romanian => romanian::format(number, options),
+            Locale::Greek => greek::format(number, options),
+            Locale::Turkish => turkish::format(number, options),
+            Locale::Hebrew => hebrew::format(number, options),
+            Locale::Arabic => arabic::format(number, options),
+            Locale::Hindi => hindi::format(number, options),
+            Locale::Bengali => bengali::format(number, options),
+            #[cfg(feature = "cjk")]
+            Locale::Japanese => japanese::format(number, options),
+            #[cfg(feature = "cjk")]
+            Locale::Chinese => chinese::format(number, options),
+            #[cfg(feature = "cjk")]
+            Locale::Korean => korean::format(number, options),
+            #[cfg(not(feature = "cjk"))]
+            Locale::Japanese | Locale::Chinese | Locale::Korean => {
+                unreachable!("unavailable locales resolve to a fallback")
+            }
+            Locale::Vietnamese => vietnamese::format(number, options),
+            Locale::Thai => thai::format(number, options),
+            Locale::Indonesian => indonesian::format(number),
+            Locale::Filipino => filipino::format(number),
+            Locale::Persian => persian::format(number, options),
+            Locale::Esperanto => esperanto::format(number, options),
+            Locale::Latin => latin::format(number),
+            Locale::French => french::format(number, options),
+            Locale::Bulgarian => bulgarian::format(number, options),
+            Locale::Lithuanian => lithuanian::format(number, options),
+            Locale::ScottishGaelic => scottish_gaelic::format(number),
+            Locale::Galician => galician::format(number, options),
+            Locale::Swahili => swahili::format(number, options),
+            Locale::Urdu => urdu::format(number, options),
+            Locale::Tamil => tamil::format(number, options),
+        }
+    }
+
+    pub(crate) fn category(self, number: &Number) -> OrdinalCategory {
+        match self.resolve() {
+            Locale::English => english::category(number),
+            Locale::Italian => italian::category(number),
+            Locale::Swedish => swedish::category(number),
+            Locale::Ukrainian => ukrainian::category(number),
+            Locale::Welsh => welsh::category(number),
+            Locale::Irish => irish::category(number),
+            Locale::ScottishGaelic => scottish_gaelic::category(number),
+            Locale::Catalan => catalan::category(number),
+            Locale::Romanian => romanian::category(number),
+            Locale::Hungarian => hungarian::category(number),
+            Locale::Hindi => hindi::category(number),
+            Locale::Bengali => bengali::category(number),
+            Locale::Filipino => filipino::category(number),
+            Locale::Vietnamese => vietnamese::category(number),
+            Locale::French => french::category(number),
+            // CLDR gives these a single ordinal category. It has no ordinal data for
+            // Esperanto and Latin, so they take the root rule, which is the same.
+            Locale::Spanish
+            | Locale::German
+            | Locale::Dutch
+            | Locale::Portuguese
+            | Locale::Norwegian
+            | Locale::Danish
+            | Locale::Finnish
+            | Locale::Russian
+            | Locale::Polish
+            | Locale::Czech
+            | Locale::Slovak
+            | Locale::Greek
+            | Locale::Turkish
+            | Locale::Hebrew
+            | Locale::Arabic
+            | Locale::Japanese
+            | Locale::Chinese
+            | Locale::Korean
+            | Locale::Thai
+            | Locale::Indonesian
+            | Locale::Persian
+            | Locale::Esperanto
+            | Locale::Latin
+            | Locale::Icelandic
+            | Locale::Croatian
+            | Locale::Serbian
+            | Locale::Bulgarian
+            | Locale::Lithuanian
+            | Locale::Latvian
+            | Locale::Estonian
+            | Locale::Slovenian
+            | Locale::Basque
+            | Locale::Galician
+            | Locale::Swahili
+            | Locale::Urdu
+            | Locale::Tamil => OrdinalCategory::Other,
+        }
+    }
+
+    pub(crate) fn words(
+        self,
+        number: &Number,
+        options: &OrdinalOptions,
+    ) -> Result<String, LocaleUnsupported> {
+        // Not resolved: a fallback's words are another language's, so a locale that
+        // isn't compiled in has none.
+        let words = match self {
+            Locale::German => number.value().and_then(|n| german::words(n, options)),
+            Locale::Spanish => number.value().and_then(|n| spanish::words(n, options)),
+            Locale::French => number.value().and_then(|n| french::words(n, options)),
+            Locale::Italian => number.value().and_then(|n| italian::words(n, options)),
+            Locale::Portuguese => number.value().and_then(|n| portuguese::words(n, options)),
+            Locale::Swedish => number.value().and_then(swedish::words),
+            Locale::Norwegian => number.value().and_then(norwegian::words),
+            Locale::Danish => number.value().and_then(|n| danish::words(n, options)),
+            Locale::Finnish => number.value().and_then(|n| finnish::words(n, options)),
+            Locale::Polish => number.value().and_then(|n| polish::words(n, options)),
+            Locale::Czech => number.value().and_then(|n| czech::words(n, options)),
+            Locale::Slovak => number.value().and_then(|n| slovak::words(n, options)),
+            Locale::Irish => number.value().and_then(irish::words),
+            Locale::Hungarian => number.value().and_then(hungarian::words),
+            Locale::Greek => number.value().and_then(|n| greek::words(n, options)),
+            Locale::Hebrew => number.value().and_then(|n| hebrew::words(n, options)),
+            Locale::Arabic => number.value().and_then(|n| arabic::words(n, options)),
+            Locale::Indonesian => number.value().and_then(|n| indonesian::words(n, options)),
+            Locale::Filipino => number.value().and_then(|n| filipino::words(n, options)),
+            Locale::Persian => number.value().and_then(persian::words),
+            Locale::Esperanto => number.value().and_then(|n| esperanto::words(n, options)),
+            Locale::Latin => number.value().and_then(|n| latin::words(n, options)),
+            Locale::Hindi => number.value().and_then(|n| hindi::words(n, options)),
+            Locale::Icelandic => number.value().and_then(|n| icelandic::words(n, options)),
+            Locale::Croatian => number.value().and_then(|n| croatian::words(n, options)),
+            Locale::Serbian => number.value().and_then(|n| serbian::words(n, options)),
+            Locale::Bulgarian => number.value().and_then(|n| bulgarian::words(n, options)),
+            Locale::Lithuanian => number.value().and_then(|n| lithuanian::words(n, options)),
+            Locale::Latvian => number.value().and_then(|n| latvian::words(n, options)),
+            Locale::Estonian => number.value().and_then(estonian::words),
+            Locale::Slovenian => number.value().and_then(|n| slovenian::words(n, options)),
+            Locale::ScottishGaelic => number.value().and_then(scottish_gaelic::words),
+            Locale::Basque => number.value().and_then(|n| basque::words(n, options)),
+            Locale::Galician => number.value().and_then(|n| galician::words(n, options)),
+            Locale::Swahili => number.value().and_then(|n| swahili::words(n, options)),
+            Locale::Urdu => number.value().and_then(|n| urdu::words(n, options)),
+            Locale::Tamil => number.value().and_then(|n| tamil::words(n, options)),
+            Locale::English => number.value().and_then(english::words),
+            _ => None,
+        };
+        words.ok_or_else(|| LocaleUnsupported {
+            locale: self,
+            value: number.to_string(),
+        })
+    }
+}
+
+/// Error returned when a locale has no word form for a value,
+/// either because it only supports digits or because the value is out of its range.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct LocaleUnsupported {
+    locale: Locale,
+    value: String,
+}
+
+impl LocaleUnsupported {
+    /// Returns the locale that was asked for words.
+    pub fn locale(&self) -> Locale {
+        self.locale
+    }
+
+    /// Returns the value that had no word form, in decimal digits.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl Display for LocaleUnsupported {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{:?} has no word form for {}", self.locale, self.value)
+    }
+}
+
+impl core::error::Error for LocaleUnsupported {}
+
+/// CLDR plural category of an ordinal, used to select among translated message variants.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum OrdinalCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+impl OrdinalCategory {
+    /// Returns the CLDR keyword of the category, as used in ICU `selectordinal` branches.
+    /// ```rust
+    /// use ordinal_type::{Locale, Ordinal};
+    ///
+    /// assert_eq!(Ordinal(23).plural_category(Locale::English).keyword(), "few");
+    /// ```
+    pub fn keyword(self) -> &'static str {
+        match self {
+            OrdinalCategory::Zero => "zero",
+            OrdinalCategory::One => "one",
+            OrdinalCategory::Two => "two",
+            OrdinalCategory::Few => "few",
+            OrdinalCategory::Many => "many",
+            OrdinalCategory::Other => "other",
+        }
+    }
+}
+
+/// Grammatical gender of the noun an ordinal agrees with.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum Gender {
+    #[default]
+    Masculine,
+    Feminine,
+    Neuter,
+    /// The merged masculine/feminine gender of Danish, Swedish and Dutch.
+    Common,
+}
+
+/// Grammatical case of the noun an ordinal agrees with.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum Case {
+    #[default]
+    Nominative,
+    Accusative,
+    Dative,
+    Genitive,
+    Instrumental,
+    Prepositional,
+}
+
+/// Grammatical number of the noun an ordinal agrees with.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum GrammaticalNumber {
+    #[default]
+    Singular,
+    Plural,
+}
+
+/// Writing system of word forms, for locales written in more than one.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum Script {
+    Cyrillic,
+    Latin,
+}
+
+/// Options for locale-aware formatting.
+/// Locales ignore the options that don't apply to them.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct OrdinalOptions {
+    pub(crate) gender: Gender,
+    pub(crate) case: Case,
+    pub(crate) number: GrammaticalNumber,
+    pub(crate) period: bool,
+    pub(crate) dutch_style: DutchStyle,
+    pub(crate) turkish_style: TurkishStyle,
+    pub(crate) direction_marks: bool,
+    pub(crate) digits: Digits,
+    pub(crate) definite: bool,
+    pub(crate) japanese_style: JapaneseStyle,
+    pub(crate) japanese_numerals: JapaneseNumerals,
+    pub(crate) chinese_numerals: ChineseNumerals,
+    pub(crate) chinese_script: ChineseScript,
+    pub(crate) korean_native_limit: u8,
+    pub(crate) vietnamese_numerals: VietnameseNumerals,
+    pub(crate) thai_numerals: ThaiNumerals,
+    pub(crate) thai_space: bool,
+    pub(crate) indonesian_first: IndonesianFirst,
+    pub(crate) filipino_first: FilipinoFirst,
+    pub(crate) apocope: bool,
+    pub(crate) grouping: Option<char>,
+    pub(crate) script: Option<Script>,
+    pub(crate) basque_first: BasqueFirst,
+    pub(crate) swahili_prefix: SwahiliPrefix,
+    pub(crate) tamil_first: TamilFirst,
+}
+
+impl Default for OrdinalOptions {
+    fn default() -> Self {
+        OrdinalOptions {
+            gender: Gender::default(),
+            case: Case::default(),
+            number: GrammaticalNumber::default(),
+            period: true,
+            dutch_style: DutchStyle::default(),
+            turkish_style: TurkishStyle::default(),
+            direction_marks: false,
+            digits: Digits::default(),
+            definite: true,
+            japanese_style: JapaneseStyle::default(),
+            japanese_numerals: JapaneseNumerals::default(),
+            chinese_numerals: ChineseNumerals::default(),
+            chinese_script: ChineseScript::default(),
+            korean_native_limit: 20,
+            vietnamese_numerals: VietnameseNumerals::default(),
+            thai_numerals: ThaiNumerals::default(),
+            thai_space: true,
+            indonesian_first: IndonesianFirst::default(),
+            filipino_first: FilipinoFirst::default(),
+            apocope: false,
+            grouping: None,
+            script: None,
+            basque_first: BasqueFirst::default(),
+            swahili_prefix: SwahiliPrefix::default(),
+            tamil_first: TamilFirst::default(),
+        }
+    }
+}
+
+impl OrdinalOptions {
+    /// Returns the default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the grammatical gender. Defaults to [`Gender::Masculine`].
+    pub fn gender(mut self, gender: Gender) -> Self {
+        self.gender = gender;
+        self
+    }
+
+    /// Sets the grammatical case. Defaults to [`Case::Nominative`].
+    pub fn case(mut self, case: Case) -> Self {
+        self.case = case;
+        self
+    }
+
+    /// Sets the grammatical number. Defaults to [`GrammaticalNumber::Singular`].
+    pub fn number(mut self, number: GrammaticalNumber) -> Self {
+        self.number = number;
+        self
+    }
+
+    /// Sets whether a period separates the number from the ordinal indicator
+    /// in locales that use one (Spanish, Portuguese and Galician `1.º`). Defaults to `true`.
+    pub fn period(mut self, period: bool) -> Self {
+        self.period = period;
+        self
+    }
+
+    /// Sets the Dutch suffix style. Defaults to [`DutchStyle::Short`].
+    pub fn dutch_style(mut self, style: DutchStyle) -> Self {
+        self.dutch_style = style;
+        self
+    }
+
+    /// Sets the Turkish suffix style. Defaults to [`TurkishStyle::Suffix`].
+    pub fn turkish_style(mut self, style: TurkishStyle) -> Self {
+        self.turkish_style = style;
+        self
+    }
+
+    /// Sets whether right-to-left locales (Hebrew, Arabic) surround their output with
+    /// right-to-left marks, which keeps a numeral and its sign with the surrounding
+    /// right-to-left text when it's mixed with left-to-right text. Defaults to `false`.
+    pub fn direction_marks(mut self, marks: bool) -> Self {
+        self.direction_marks = marks;
+        self
+    }
+
+    /// Sets the digits used by locales that support digit shaping (Arabic, Hindi, Bengali,
+    /// Persian, Urdu, Tamil). Defaults to [`Digits::Latin`].
+    pub fn digits(mut self, digits: Digits) -> Self {
+        self.digits = digits;
+        self
+    }
+
+    /// Sets whether word forms take the definite article in locales that mark it
+    /// on the ordinal (Arabic `الثالث` rather than `ثالث`), or the pronominal forms in
+    /// Lithuanian (`pirmasis` rather than `pirmas`). Defaults to `true`.
+    pub fn definite(mut self, definite: bool) -> Self {
+        self.definite = definite;
+        self
+    }
+
+    /// Sets the Japanese affix style. Defaults to [`JapaneseStyle::DaiPrefix`].
+    pub fn japanese_style(mut self, style: JapaneseStyle) -> Self {
+        self.japanese_style = style;
+        self
+    }
+
+    /// Sets the Japanese numerals. Defaults to [`JapaneseNumerals::Arabic`].
+    pub fn japanese_numerals(mut self, numerals: JapaneseNumerals) -> Self {
+        self.japanese_numerals = numerals;
+        self
+    }
+
+    /// Sets the Chinese numerals. Defaults to [`ChineseNumerals::Arabic`].
+    pub fn chinese_numerals(mut self, numerals: ChineseNumerals) -> Self {
+        self.chinese_numerals = numerals;
+        self
+    }
+
+    /// Sets the script of Chinese numerals. Defaults to [`ChineseScript::Simplified`].
+    pub fn chinese_script(mut self, script: ChineseScript) -> Self {
+        self.chinese_script = script;
+        self
+    }
+
+    /// Sets the largest value Korean writes with native numerals (`스무 번째`) rather
+    /// than digits (`21번째`). Native numerals stop at 99. Defaults to 20.
+    pub fn korean_native_limit(mut self, limit: u8) -> Self {
+        self.korean_native_limit = limit;
+        self
+    }
+
+    /// Sets the Vietnamese numerals. Defaults to [`VietnameseNumerals::Words`].
+    pub fn vietnamese_numerals(mut self, numerals: VietnameseNumerals) -> Self {
+        self.vietnamese_numerals = numerals;
+        self
+    }
+
+    /// Sets the Thai numerals. Defaults to [`ThaiNumerals::Arabic`].
+    pub fn thai_numerals(mut self, numerals: ThaiNumerals) -> Self {
+        self.thai_numerals = numerals;
+        self
+    }
+
+    /// Sets whether a space follows the Thai `ที่`. Defaults to `true`.
+    pub fn thai_space(mut self, space: bool) -> Self {
+        self.thai_space = space;
+        self
+    }
+
+    /// Sets the Indonesian word for "first". Defaults to [`IndonesianFirst::Pertama`].
+    pub fn indonesian_first(mut self, first: IndonesianFirst) -> Self {
+        self.indonesian_first = first;
+        self
+    }
+
+    /// Sets the Filipino word for "first". Defaults to [`FilipinoFirst::Una`].
+    pub fn filipino_first(mut self, first: FilipinoFirst) -> Self {
+        self.filipino_first = first;
+        self
+    }
+
+    /// Sets whether Spanish word forms use the shortened `primer` and `tercer` taken
+    /// before a masculine singular noun. Defaults to `false`.
+    pub fn apocope(mut self, apocope: bool) -> Self {
+        self.apocope = apocope;
+        self
+    }
+
+    /// Sets the thousands separator used where a bare number is printed, as for `#` in
+    /// `icu::apply_selectordinal`. Ungrouped by default.
+    pub fn grouping(mut self, separator: char) -> Self {
+        self.grouping = Some(separator);
+        self
+    }
+
+    /// Sets the script of word forms in locales written in more than one (Serbian).
+    /// Each locale defaults to its usual script, Cyrillic for Serbian. Digits are the
+    /// same in every script.
+    pub fn script(mut self, script: Script) -> Self {
+        self.script = Some(script);
+        self
+    }
+
+    /// Sets the Basque word for "first" on its own. Defaults to [`BasqueFirst::Lehen`].
+    pub fn basque_first(mut self, first: BasqueFirst) -> Self {
+        self.basque_first = first;
+        self
+    }
+
+    /// Sets the Swahili agreement prefix, which follows the class of the noun the
+    /// ordinal qualifies. Defaults to [`SwahiliPrefix::Wa`].
+    pub fn swahili_prefix(mut self, prefix: SwahiliPrefix) -> Self {
+        self.swahili_prefix = prefix;
+        self
+    }
+
+    /// Sets the Tamil ordinal for "first". Defaults to [`TamilFirst::Muthal`].
+    pub fn tamil_first(mut self, first: TamilFirst) -> Self {
+        self.tamil_first = first;
+        self
+    }
+}
+
+/// Decimal rendering of an ordinal's inner value, shared by the locale formatters.
+pub(crate) struct Number {
+    negative: bool,
+    digits: String,
+}
+
+impl Number {
+    pub(crate) fn new<T: Display>(value: &T) -> Self {
+        let mut digits = value.to_string();
+        let negative = digits.starts_with('-');
+        if negative {
+            digits.remove(0);
+        }
+        Number { negative, digits }
+    }
+
+    /// Returns whether the value is below zero.
+    pub(crate) fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Returns `-` for negative values and nothing otherwise, for locales that put the
+    /// sign in front of a prefix rather than between it and the digits.
+    pub(crate) fn sign(&self) -> &'static str {
+        if self.negative {
+            "-"
+        } else {
+            ""
+        }
+    }
+
+    /// Returns the decimal digits without the sign.
+    pub(crate) fn digits(&self) -> &str {
+        &self.digits
+    }
+
+    /// Returns the value of the last two decimal digits.
+    pub(crate) fn last_two(&self) -> u8 {
+        let start = self.digits.len().saturating_sub(2);
+        self.digits[start..].parse().unwrap_or(0)
+    }
+
+    /// Returns the absolute value if it fits in a `u128`.
+    pub(crate) fn magnitude(&self) -> Option<u128> {
+        self.digits.parse().ok()
+    }
+
+    /// Returns the value if it is non-negative and fits in a `u128`.
+    pub(crate) fn value(&self) -> Option<u128> {
+        if self.negative {
+            None
+        } else {
+            self.magnitude()
+        }
+    }
+}
+
+impl Display for Number {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if self.negative {
+            f.write_str("-")?;
+        }
+        f.write_str(&self.digits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::locale::OrdinalCategory;
+    use crate::{Locale, Ordinal};
+    use std::collections::HashMap;
+
+    /// Asserts that every value in the same plural category gets the same suffix,
+    /// so a message catalog keyed by category can't disagree with the formatter.
+    fn assert_consistent(locale: Locale, values: impl IntoIterator<Item = u32>) {
+        let mut suffixes: HashMap<OrdinalCategory, String> = HashMap::new();
+        for value in values {
+            let ordinal = Ordinal(value);
+            let formatted = ordinal.to_string_with_locale(locale);
+            // Skip the numeral, which may be in native digits.
+            let suffix = formatted
+                .trim_start_matches(|c: char| c.is_numeric())
+                .to_string();
+            let expected = suffixes
+                .entry(ordinal.plural_category(locale))
+                .or_insert_with(|| suffix.clone());
+            assert_eq!(*expected, suffix, "{:?} {}", locale, value);
+        }
+    }
+
+    #[test]
+    fn test_category_consistency() {
+        assert_consistent(Locale::Welsh, 0..=9);
+        assert_consistent(Locale::English, 0..=1000);
+        assert_consistent(Locale::Swedish, 0..=1000);
+        assert_consistent(Locale::Irish, 0..=1000);
+        assert_consistent(Locale::ScottishGaelic, 0..=1000);
+        assert_consistent(Locale::Catalan, 0..=1000);
+        assert_consistent(Locale::Hindi, 0..=1000);
+        assert_consistent(Locale::Bengali, 0..=1000);
+        assert_consistent(Locale::French, 0..=1000);
+    }
+
+    #[test]
+    fn test_categories() {
+        let category = |locale: Locale, n: i32| Ordinal(n).plural_category(locale);
+
+        assert_eq!(OrdinalCategory::One, category(Locale::English, 21));
+        assert_eq!(OrdinalCategory::Two, category(Locale::English, -2));
+        assert_eq!(OrdinalCategory::Few, category(Locale::English, 103));
+        assert_eq!(OrdinalCategory::Other, category(Locale::English, 113));
+        assert_eq!(OrdinalCategory::Many, category(Locale::Italian, 8));
+        assert_eq!(OrdinalCategory::Many, category(Locale::Italian, 800));
+        assert_eq!(OrdinalCategory::Other, category(Locale::Italian, 88));
+        assert_eq!(OrdinalCategory::One, category(Locale::Swedish, 22));
+        assert_eq!(OrdinalCategory::Other, category(Locale::Swedish, 12));
+        assert_eq!(OrdinalCategory::Few, category(Locale::Ukrainian, 23));
+        assert_eq!(OrdinalCategory::Other, category(Locale::Ukrainian, 13));
+        assert_eq!(OrdinalCategory::One, category(Locale::Catalan, 3));
+        assert_eq!(OrdinalCategory::Two, category(Locale::Catalan, 2));
+        assert_eq!(OrdinalCategory::Few, category(Locale::Catalan, 4));
+        assert_eq!(OrdinalCategory::Other, category(Locale::Catalan, 21));
+        assert_eq!(OrdinalCategory::One, category(Locale::Bengali, 5));
+        assert_eq!(OrdinalCategory::Two, category(Locale::Bengali, 3));
+        assert_eq!(OrdinalCategory::Few, category(Locale::Bengali, 4));
+        assert_eq!(OrdinalCategory::Many, category(Locale::Bengali, 6));
+        assert_eq!(OrdinalCategory::Other, category(Locale::Bengali, 11));
+        assert_eq!(OrdinalCategory::Other, category(Locale::German, 1));
+    }
+
+    #[test]
+    fn test_single_category_locales() {
+        for locale in [
+            Locale::German,
+            Locale::Spanish,
+            Locale::Russian,
+            Locale::Latin,
+        ] {
+            for n in 0..=10 {
+                assert_eq!(OrdinalCategory::Other, Ordinal(n).plural_category(locale));
+            }
+        }
+    }
+}
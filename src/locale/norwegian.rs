@@ -0,0 +1,120 @@
+//! Norwegian (Bokmål) ordinals are written as the number followed by a period: `3.`.
+//! Word forms cover 0 to 100, enough for dates and rankings.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+
+const TENS: [&str; 10] = [
+    "", "", "tjue", "tretti", "førti", "femti", "seksti", "sytti", "åtti", "nitti",
+];
+
+const ORDINALS: [&str; 20] = [
+    "nullte",
+    "første",
+    "andre",
+    "tredje",
+    "fjerde",
+    "femte",
+    "sjette",
+    "sjuende",
+    "åttende",
+    "niende",
+    "tiende",
+    "ellevte",
+    "tolvte",
+    "trettende",
+    "fjortende",
+    "femtende",
+    "sekstende",
+    "syttende",
+    "attende",
+    "nittende",
+];
+
+/// Spells out `n` for values from 0 to 100.
+pub(crate) fn words(n: u128) -> Option<String> {
+    match n {
+        0..=19 => Some(ORDINALS[n as usize].to_string()),
+        20..=99 => Some(match n % 10 {
+            0 => format!("{}ende", TENS[(n / 10) as usize].trim_end_matches('e')),
+            unit => format!("{}{}", TENS[(n / 10) as usize], ORDINALS[unit as usize]),
+        }),
+        100 => Some("hundrede".to_string()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Locale, Ordinal, OrdinalOptions};
+
+    #[test]
+    fn test_norwegian_digits() {
+        for i in [1, 2, 3, 11, 17, 21, 100, 1000] {
+            assert_eq!(
+                format!("{}.", i),
+                Ordinal(i).to_string_with_locale(Locale::Norwegian)
+            );
+        }
+        assert_eq!(
+            Ordinal(3).to_string_with_locale(Locale::German),
+            Ordinal(3).to_string_with_locale(Locale::Norwegian)
+        );
+    }
+
+    #[test]
+    fn test_norwegian_words() {
+        let norwegian_ordinals: [&str; 31] = [
+            "første",
+            "andre",
+            "tredje",
+            "fjerde",
+            "femte",
+            "sjette",
+            "sjuende",
+            "åttende",
+            "niende",
+            "tiende",
+            "ellevte",
+            "tolvte",
+            "trettende",
+            "fjortende",
+            "femtende",
+            "sekstende",
+            "syttende",
+            "attende",
+            "nittende",
+            "tjuende",
+            "tjueførste",
+            "tjueandre",
+            "tjuetredje",
+            "tjuefjerde",
+            "tjuefemte",
+            "tjuesjette",
+            "tjuesjuende",
+            "tjueåttende",
+            "tjueniende",
+            "trettiende",
+            "trettiførste",
+        ];
+
+        let options = OrdinalOptions::new();
+        for (i, expected) in norwegian_ordinals.iter().enumerate() {
+            assert_eq!(
+                *expected,
+                Ordinal(i + 1)
+                    .to_words_locale(Locale::Norwegian, &options)
+                    .unwrap()
+            );
+        }
+        assert_eq!(
+            "hundrede",
+            Ordinal(100)
+                .to_words_locale(Locale::Norwegian, &options)
+                .unwrap()
+        );
+        assert!(Ordinal(101)
+            .to_words_locale(Locale::Norwegian, &options)
+            .is_err());
+    }
+}
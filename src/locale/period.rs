@@ -0,0 +1,10 @@
+//! The period style shared by the locales that write ordinals as the number
+//! followed by a period: `3.`.
+
+use super::Number;
+use alloc::format;
+use alloc::string::String;
+
+pub(crate) fn format(number: &Number) -> String {
+    format!("{}.", number)
+}
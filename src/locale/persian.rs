@@ -0,0 +1,161 @@
+//! Persian ordinals add `م` to the numeral: `3م`, or `۳م` in Persian digits, which are
+//! the Extended Arabic-Indic ones chosen with [`OrdinalOptions::digits`].
+//!
+//! Word forms add `م` to the last word of the cardinal (`سوم`, `بیست‌ویکم`), with `ام`
+//! after a final `ی` (`سی‌ام`) and the irregular `اول` for first on its own. Compound
+//! parts are joined by `و` after a zero-width non-joiner, which keeps the compound one
+//! word. Values from 1 to 999 are supported.
+
+use super::{digits, Number, OrdinalOptions};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Zero-width non-joiner (U+200C).
+const ZWNJ: char = '\u{200c}';
+
+const UNITS: [&str; 20] = [
+    "",
+    "یک",
+    "دو",
+    "سه",
+    "چهار",
+    "پنج",
+    "شش",
+    "هفت",
+    "هشت",
+    "نه",
+    "ده",
+    "یازده",
+    "دوازده",
+    "سیزده",
+    "چهارده",
+    "پانزده",
+    "شانزده",
+    "هفده",
+    "هجده",
+    "نوزده",
+];
+
+const TENS: [&str; 10] = [
+    "",
+    "",
+    "بیست",
+    "سی",
+    "چهل",
+    "پنجاه",
+    "شصت",
+    "هفتاد",
+    "هشتاد",
+    "نود",
+];
+
+const HUNDREDS: [&str; 10] = [
+    "",
+    "صد",
+    "دویست",
+    "سیصد",
+    "چهارصد",
+    "پانصد",
+    "ششصد",
+    "هفتصد",
+    "هشتصد",
+    "نهصد",
+];
+
+pub(crate) fn format(number: &Number, options: &OrdinalOptions) -> String {
+    format!("{}م", digits::shape(&number.to_string(), options.digits))
+}
+
+/// Spells out `n` for values from 1 to 999.
+pub(crate) fn words(n: u128) -> Option<String> {
+    let n = match n {
+        1 => return Some("اول".to_string()),
+        2..=999 => n as usize,
+        _ => return None,
+    };
+    let rest = n % 100;
+    let mut parts = vec![HUNDREDS[n / 100]];
+    if rest < 20 {
+        parts.push(UNITS[rest]);
+    } else {
+        parts.extend([TENS[rest / 10], UNITS[rest % 10]]);
+    }
+    let cardinal = parts
+        .into_iter()
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join(&format!("{}و", ZWNJ));
+    Some(if let Some(stem) = cardinal.strip_suffix("سه") {
+        format!("{}سوم", stem)
+    } else if cardinal.ends_with('ی') {
+        format!("{}{}ام", cardinal, ZWNJ)
+    } else {
+        format!("{}م", cardinal)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Digits, Locale, Ordinal, OrdinalOptions};
+
+    #[test]
+    fn test_persian_digits() {
+        assert_eq!("3م", Ordinal(3).to_string_with_locale(Locale::Persian));
+
+        let options = OrdinalOptions::new().digits(Digits::ExtendedArabicIndic);
+        assert_eq!(
+            "\u{6f3}م",
+            Ordinal(3).to_string_with_locale_opts(Locale::Persian, &options)
+        );
+        // ۴, ۵ and ۶ differ from the Arabic ٤, ٥ and ٦.
+        assert_eq!(
+            "\u{6f4}\u{6f5}\u{6f6}م",
+            Ordinal(456).to_string_with_locale_opts(Locale::Persian, &options)
+        );
+    }
+
+    #[test]
+    fn test_persian_words() {
+        let persian: [&str; 12] = [
+            "اول",
+            "دوم",
+            "سوم",
+            "چهارم",
+            "پنجم",
+            "ششم",
+            "هفتم",
+            "هشتم",
+            "نهم",
+            "دهم",
+            "یازدهم",
+            "دوازدهم",
+        ];
+
+        let options = OrdinalOptions::new();
+        for (i, expected) in persian.iter().enumerate() {
+            assert_eq!(
+                *expected,
+                Ordinal(i + 1)
+                    .to_words_locale(Locale::Persian, &options)
+                    .unwrap()
+            );
+        }
+
+        let words = |n: u32| {
+            Ordinal(n)
+                .to_words_locale(Locale::Persian, &options)
+                .unwrap()
+        };
+        assert_eq!("بیستم", words(20));
+        assert_eq!("بیست\u{200c}ویکم", words(21));
+        assert_eq!("بیست\u{200c}وسوم", words(23));
+        assert_eq!("سی\u{200c}ام", words(30));
+        assert_eq!("صدم", words(100));
+        assert_eq!("صد\u{200c}ویکم", words(101));
+        assert!(Ordinal(1000)
+            .to_words_locale(Locale::Persian, &options)
+            .is_err());
+    }
+}
@@ -0,0 +1,269 @@
+//! Polish ordinals are written as the number followed by a period: `3.`.
+//! In word forms the tens and units are both ordinal (`dwudziesty pierwszy`),
+//! while hundreds and thousands before them stay cardinal (`sto pierwszy`).
+
+use super::{slavic, Gender, OrdinalOptions};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+
+const UNITS: [&str; 20] = [
+    "",
+    "pierwszy",
+    "drugi",
+    "trzeci",
+    "czwarty",
+    "piąty",
+    "szósty",
+    "siódmy",
+    "ósmy",
+    "dziewiąty",
+    "dziesiąty",
+    "jedenasty",
+    "dwunasty",
+    "trzynasty",
+    "czternasty",
+    "piętnasty",
+    "szesnasty",
+    "siedemnasty",
+    "osiemnasty",
+    "dziewiętnasty",
+];
+
+const TENS: [&str; 10] = [
+    "",
+    "",
+    "dwudziesty",
+    "trzydziesty",
+    "czterdziesty",
+    "pięćdziesiąty",
+    "sześćdziesiąty",
+    "siedemdziesiąty",
+    "osiemdziesiąty",
+    "dziewięćdziesiąty",
+];
+
+const HUNDREDS: [&str; 10] = [
+    "",
+    "setny",
+    "dwusetny",
+    "trzechsetny",
+    "czterechsetny",
+    "pięćsetny",
+    "sześćsetny",
+    "siedemsetny",
+    "osiemsetny",
+    "dziewięćsetny",
+];
+
+const CARDINAL_HUNDREDS: [&str; 10] = [
+    "",
+    "sto",
+    "dwieście",
+    "trzysta",
+    "czterysta",
+    "pięćset",
+    "sześćset",
+    "siedemset",
+    "osiemset",
+    "dziewięćset",
+];
+
+/// Spells out `n` for values from 1 to 1999.
+pub(crate) fn words(n: u128, options: &OrdinalOptions) -> Option<String> {
+    if n == 0 || n >= 2000 {
+        return None;
+    }
+    let n = n as usize;
+    let (hundreds, rest) = (n / 100 % 10, n % 100);
+    let mut cardinal = Vec::new();
+    if n > 1000 {
+        cardinal.push("tysiąc");
+    }
+    let ordinal = match rest {
+        0 if hundreds == 0 => vec!["tysięczny"],
+        0 => vec![HUNDREDS[hundreds]],
+        _ => {
+            if hundreds > 0 {
+                cardinal.push(CARDINAL_HUNDREDS[hundreds]);
+            }
+            match (rest, rest % 10) {
+                (1..=19, _) => vec![UNITS[rest]],
+                (_, 0) => vec![TENS[rest / 10]],
+                (_, unit) => vec![TENS[rest / 10], UNITS[unit]],
+            }
+        }
+    };
+    Some(slavic::compose(&cardinal, &ordinal, |word| {
+        inflect(word, options.gender)
+    }))
+}
+
+fn inflect(masculine: &str, gender: Gender) -> String {
+    let feminine = gender == Gender::Feminine;
+    match gender {
+        Gender::Feminine | Gender::Neuter => {
+            if let Some(stem) = masculine.strip_suffix("gi") {
+                format!("{}g{}", stem, if feminine { "a" } else { "ie" })
+            } else if masculine.ends_with("ci") {
+                format!("{}{}", masculine, if feminine { "a" } else { "e" })
+            } else {
+                let stem = masculine.strip_suffix('y').unwrap_or(masculine);
+                format!("{}{}", stem, if feminine { "a" } else { "e" })
+            }
+        }
+        _ => masculine.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Gender, Locale, Ordinal, OrdinalOptions};
+
+    #[test]
+    fn test_polish_digits() {
+        for i in [1, 3, 21, 100, 1410] {
+            assert_eq!(
+                format!("{}.", i),
+                Ordinal(i).to_string_with_locale(Locale::Polish)
+            );
+        }
+    }
+
+    #[test]
+    fn test_polish_words() {
+        let masculine: [&str; 30] = [
+            "pierwszy",
+            "drugi",
+            "trzeci",
+            "czwarty",
+            "piąty",
+            "szósty",
+            "siódmy",
+            "ósmy",
+            "dziewiąty",
+            "dziesiąty",
+            "jedenasty",
+            "dwunasty",
+            "trzynasty",
+            "czternasty",
+            "piętnasty",
+            "szesnasty",
+            "siedemnasty",
+            "osiemnasty",
+            "dziewiętnasty",
+            "dwudziesty",
+            "dwudziesty pierwszy",
+            "dwudziesty drugi",
+            "dwudziesty trzeci",
+            "dwudziesty czwarty",
+            "dwudziesty piąty",
+            "dwudziesty szósty",
+            "dwudziesty siódmy",
+            "dwudziesty ósmy",
+            "dwudziesty dziewiąty",
+            "trzydziesty",
+        ];
+        let feminine: [&str; 30] = [
+            "pierwsza",
+            "druga",
+            "trzecia",
+            "czwarta",
+            "piąta",
+            "szósta",
+            "siódma",
+            "ósma",
+            "dziewiąta",
+            "dziesiąta",
+            "jedenasta",
+            "dwunasta",
+            "trzynasta",
+            "czternasta",
+            "piętnasta",
+            "szesnasta",
+            "siedemnasta",
+            "osiemnasta",
+            "dziewiętnasta",
+            "dwudziesta",
+            "dwudziesta pierwsza",
+            "dwudziesta druga",
+            "dwudziesta trzecia",
+            "dwudziesta czwarta",
+            "dwudziesta piąta",
+            "dwudziesta szósta",
+            "dwudziesta siódma",
+            "dwudziesta ósma",
+            "dwudziesta dziewiąta",
+            "trzydziesta",
+        ];
+        let neuter: [&str; 30] = [
+            "pierwsze",
+            "drugie",
+            "trzecie",
+            "czwarte",
+            "piąte",
+            "szóste",
+            "siódme",
+            "ósme",
+            "dziewiąte",
+            "dziesiąte",
+            "jedenaste",
+            "dwunaste",
+            "trzynaste",
+            "czternaste",
+            "piętnaste",
+            "szesnaste",
+            "siedemnaste",
+            "osiemnaste",
+            "dziewiętnaste",
+            "dwudzieste",
+            "dwudzieste pierwsze",
+            "dwudzieste drugie",
+            "dwudzieste trzecie",
+            "dwudzieste czwarte",
+            "dwudzieste piąte",
+            "dwudzieste szóste",
+            "dwudzieste siódme",
+            "dwudzieste ósme",
+            "dwudzieste dziewiąte",
+            "trzydzieste",
+        ];
+
+        let words = |n: usize, gender: Gender| {
+            let options = OrdinalOptions::new().gender(gender);
+            Ordinal(n)
+                .to_words_locale(Locale::Polish, &options)
+                .unwrap()
+        };
+        for i in 0..30 {
+            assert_eq!(masculine[i], words(i + 1, Gender::Masculine));
+            assert_eq!(feminine[i], words(i + 1, Gender::Feminine));
+            assert_eq!(neuter[i], words(i + 1, Gender::Neuter));
+        }
+    }
+
+    #[test]
+    fn test_polish_words_hundreds() {
+        let words = |n: u32, gender: Gender| {
+            let options = OrdinalOptions::new().gender(gender);
+            Ordinal(n)
+                .to_words_locale(Locale::Polish, &options)
+                .unwrap()
+        };
+
+        assert_eq!("setny", words(100, Gender::Masculine));
+        assert_eq!("setna", words(100, Gender::Feminine));
+        assert_eq!("setne", words(100, Gender::Neuter));
+        assert_eq!("sto pierwszy", words(101, Gender::Masculine));
+        assert_eq!("sto pierwsza", words(101, Gender::Feminine));
+        assert_eq!("sto pierwsze", words(101, Gender::Neuter));
+        assert_eq!("sto dwudziesty pierwszy", words(121, Gender::Masculine));
+        assert_eq!("sto dwudziesta pierwsza", words(121, Gender::Feminine));
+        assert_eq!("sto dwudzieste pierwsze", words(121, Gender::Neuter));
+        assert_eq!("dwusetny", words(200, Gender::Masculine));
+        assert_eq!("tysięczny", words(1000, Gender::Masculine));
+        assert_eq!("tysiąc pierwszy", words(1001, Gender::Masculine));
+        assert_eq!("tysiąc czterysta dziesiąty", words(1410, Gender::Masculine));
+    }
+}
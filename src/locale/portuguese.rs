@@ -0,0 +1,117 @@
+//! Portuguese ordinals use the ordinal indicators with a period (`1.º`, `1.ª`),
+//! the same in European and Brazilian Portuguese.
+//! Word forms are composed part by part, each agreeing in gender:
+//! `vigésimo primeiro`, `vigésima primeira`.
+
+use super::{romance, Number, OrdinalOptions};
+use alloc::string::String;
+
+const UNITS: [&str; 10] = [
+    "", "primeiro", "segundo", "terceiro", "quarto", "quinto", "sexto", "sétimo", "oitavo", "nono",
+];
+
+const TENS: [&str; 10] = [
+    "",
+    "décimo",
+    "vigésimo",
+    "trigésimo",
+    "quadragésimo",
+    "quinquagésimo",
+    "sexagésimo",
+    "septuagésimo",
+    "octogésimo",
+    "nonagésimo",
+];
+
+const HUNDREDS: [&str; 10] = [
+    "",
+    "centésimo",
+    "ducentésimo",
+    "trecentésimo",
+    "quadringentésimo",
+    "quingentésimo",
+    "sexcentésimo",
+    "septingentésimo",
+    "octingentésimo",
+    "nongentésimo",
+];
+
+pub(crate) fn format(number: &Number, options: &OrdinalOptions) -> String {
+    romance::indicator(number, options.gender, options.period)
+}
+
+/// Spells out `n` for values from 1 to 1999.
+pub(crate) fn words(n: u128, options: &OrdinalOptions) -> Option<String> {
+    romance::words(n, options.gender, "milésimo", &HUNDREDS, &TENS, &UNITS)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Gender, Locale, Ordinal, OrdinalOptions};
+
+    #[test]
+    fn test_portuguese_indicator() {
+        let feminine = OrdinalOptions::new().gender(Gender::Feminine);
+        let masculine_short = OrdinalOptions::new().period(false);
+        let feminine_short = OrdinalOptions::new().gender(Gender::Feminine).period(false);
+
+        for i in 1..=100 {
+            let ordinal = Ordinal(i);
+            assert_eq!(
+                format!("{}.\u{ba}", i),
+                ordinal.to_string_with_locale(Locale::Portuguese)
+            );
+            assert_eq!(
+                format!("{}.\u{aa}", i),
+                ordinal.to_string_with_locale_opts(Locale::Portuguese, &feminine)
+            );
+            assert_eq!(
+                format!("{}\u{ba}", i),
+                ordinal.to_string_with_locale_opts(Locale::Portuguese, &masculine_short)
+            );
+            assert_eq!(
+                format!("{}\u{aa}", i),
+                ordinal.to_string_with_locale_opts(Locale::Portuguese, &feminine_short)
+            );
+            assert_eq!(
+                ordinal.to_string_with_locale_opts(Locale::Spanish, &feminine_short),
+                ordinal.to_string_with_locale_opts(Locale::Portuguese, &feminine_short)
+            );
+        }
+    }
+
+    #[test]
+    fn test_portuguese_words() {
+        let masculine = OrdinalOptions::new();
+        let feminine = OrdinalOptions::new().gender(Gender::Feminine);
+        let words = |n: u32, options: &OrdinalOptions| {
+            Ordinal(n)
+                .to_words_locale(Locale::Portuguese, options)
+                .unwrap()
+        };
+
+        assert_eq!("primeiro", words(1, &masculine));
+        assert_eq!("primeira", words(1, &feminine));
+        assert_eq!("sétimo", words(7, &masculine));
+        assert_eq!("décimo", words(10, &masculine));
+        assert_eq!("décimo primeiro", words(11, &masculine));
+        assert_eq!("vigésimo primeiro", words(21, &masculine));
+        assert_eq!("vigésima primeira", words(21, &feminine));
+        assert_eq!("nonagésimo nono", words(99, &masculine));
+        assert_eq!("centésimo", words(100, &masculine));
+        assert_eq!("centésima", words(100, &feminine));
+        assert_eq!("centésimo primeiro", words(101, &masculine));
+        assert_eq!(
+            "quadringentésimo quadragésimo quarto",
+            words(444, &masculine)
+        );
+        assert_eq!("milésimo", words(1000, &masculine));
+        assert_eq!(
+            "milésima nongentésima nonagésima nona",
+            words(1999, &feminine)
+        );
+        assert!(Ordinal(2000)
+            .to_words_locale(Locale::Portuguese, &masculine)
+            .is_err());
+    }
+}
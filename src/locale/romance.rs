@@ -0,0 +1,64 @@
+//! Ordinal indicators shared by the Romance locales: the masculine `º` (U+00BA)
+//! and feminine `ª` (U+00AA), optionally preceded by a period.
+
+use super::{Gender, Number};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+pub(crate) fn indicator(number: &Number, gender: Gender, period: bool) -> String {
+    let indicator = match gender {
+        Gender::Feminine => 'ª',
+        _ => 'º',
+    };
+    if period {
+        format!("{}.{}", number, indicator)
+    } else {
+        format!("{}{}", number, indicator)
+    }
+}
+
+/// Spells out `n` for values from 1 to 1999 from the masculine tables of a locale
+/// whose word forms are composed part by part, each agreeing in gender:
+/// Portuguese `vigésimo primeiro`, Galician `vixésima primeira`.
+pub(crate) fn words(
+    n: u128,
+    gender: Gender,
+    thousand: &str,
+    hundreds: &[&str; 10],
+    tens: &[&str; 10],
+    units: &[&str; 10],
+) -> Option<String> {
+    if n == 0 || n >= 2000 {
+        return None;
+    }
+    let n = n as usize;
+    let parts = [
+        if n >= 1000 { thousand } else { "" },
+        hundreds[n / 100 % 10],
+        tens[n / 10 % 10],
+        units[n % 10],
+    ];
+    let words = parts
+        .iter()
+        .filter(|part| !part.is_empty())
+        .copied()
+        .collect::<Vec<_>>()
+        .join(" ");
+    Some(match gender {
+        Gender::Feminine => feminine(&words),
+        _ => words,
+    })
+}
+
+/// Turns a masculine `-o` word form into the feminine `-a` one, word by word.
+pub(crate) fn feminine(words: &str) -> String {
+    words
+        .split(' ')
+        .map(|word| match word.strip_suffix('o') {
+            Some(stem) => format!("{}a", stem),
+            None => word.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
@@ -0,0 +1,76 @@
+//! Romanian ordinals wrap the number: `al 3-lea` in the masculine (and neuter),
+//! `a 3-a` in the feminine. The first is the word `primul`/`prima` rather than a numeral;
+//! every other value, compounds of one included, takes the regular form (`al 21-lea`).
+//! A minus sign goes in front of the whole form (`-al 3-lea`), and CLDR puts only 1,
+//! the `primul` form, in the one category.
+
+use super::{Gender, Number, OrdinalCategory, OrdinalOptions};
+use alloc::format;
+use alloc::string::{String, ToString};
+
+pub(crate) fn format(number: &Number, options: &OrdinalOptions) -> String {
+    let feminine = options.gender == Gender::Feminine;
+    match number.value() {
+        Some(1) if feminine => "prima".to_string(),
+        Some(1) => "primul".to_string(),
+        _ if feminine => format!("{}a {}-a", number.sign(), number.digits()),
+        _ => format!("{}al {}-lea", number.sign(), number.digits()),
+    }
+}
+
+pub(crate) fn category(number: &Number) -> OrdinalCategory {
+    match number.magnitude() {
+        Some(1) => OrdinalCategory::One,
+        _ => OrdinalCategory::Other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Gender, Locale, Ordinal, OrdinalCategory, OrdinalOptions};
+
+    #[test]
+    fn test_romanian() {
+        let feminine = OrdinalOptions::new().gender(Gender::Feminine);
+        let romanian = |n: u32, options: &OrdinalOptions| {
+            Ordinal(n).to_string_with_locale_opts(Locale::Romanian, options)
+        };
+
+        assert_eq!("primul", romanian(1, &OrdinalOptions::new()));
+        assert_eq!("prima", romanian(1, &feminine));
+        for i in 2..=30 {
+            assert_eq!(format!("al {}-lea", i), romanian(i, &OrdinalOptions::new()));
+            assert_eq!(format!("a {}-a", i), romanian(i, &feminine));
+        }
+        assert_eq!("al 21-lea", romanian(21, &OrdinalOptions::new()));
+        assert_eq!("al 101-lea", romanian(101, &OrdinalOptions::new()));
+        assert_eq!("a 101-a", romanian(101, &feminine));
+    }
+
+    #[test]
+    fn test_romanian_negative() {
+        let romanian = |n: i32, options: &OrdinalOptions| {
+            Ordinal(n).to_string_with_locale_opts(Locale::Romanian, options)
+        };
+
+        assert_eq!("-al 1-lea", romanian(-1, &OrdinalOptions::new()));
+        assert_eq!(
+            "-a 21-a",
+            romanian(-21, &OrdinalOptions::new().gender(Gender::Feminine))
+        );
+    }
+
+    #[test]
+    fn test_romanian_category() {
+        assert_eq!(
+            OrdinalCategory::One,
+            Ordinal(1).plural_category(Locale::Romanian)
+        );
+        for n in [0, 2, 11, 21, 101] {
+            assert_eq!(
+                OrdinalCategory::Other,
+                Ordinal(n).plural_category(Locale::Romanian)
+            );
+        }
+    }
+}
@@ -0,0 +1,71 @@
+//! Russian abbreviates ordinals with a hyphen and the adjective ending: `1-й`, `1-я`, `1-е`.
+//! The ending is the last letter of the full word when it follows a vowel and the last two
+//! when it follows a consonant, which gives the same abbreviation for every ordinal
+//! (`первого`, `третьего` and `двадцать пятого` are all `-го`), so it depends only on
+//! gender and case. The masculine accusative uses the inanimate form (`1-й`).
+
+use super::{hyphen, Case, Gender, Number, OrdinalOptions};
+use alloc::string::String;
+
+pub(crate) fn format(number: &Number, options: &OrdinalOptions) -> String {
+    let feminine = options.gender == Gender::Feminine;
+    let ending = match options.case {
+        Case::Nominative if feminine => "я",
+        Case::Nominative if options.gender == Gender::Neuter => "е",
+        Case::Nominative => "й",
+        Case::Accusative if feminine => "ю",
+        Case::Accusative if options.gender == Gender::Neuter => "е",
+        Case::Accusative => "й",
+        _ if feminine => "й",
+        Case::Genitive => "го",
+        Case::Dative => "му",
+        Case::Instrumental | Case::Prepositional => "м",
+    };
+    hyphen::format(number, ending)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Case, Gender, Locale, Ordinal, OrdinalOptions};
+
+    #[test]
+    fn test_russian_nominative() {
+        let masculine = OrdinalOptions::new();
+        let feminine = OrdinalOptions::new().gender(Gender::Feminine);
+        let neuter = OrdinalOptions::new().gender(Gender::Neuter);
+
+        for i in 1..=30 {
+            let ordinal = Ordinal(i);
+            assert_eq!(
+                format!("{}-й", i),
+                ordinal.to_string_with_locale_opts(Locale::Russian, &masculine)
+            );
+            assert_eq!(
+                format!("{}-я", i),
+                ordinal.to_string_with_locale_opts(Locale::Russian, &feminine)
+            );
+            assert_eq!(
+                format!("{}-е", i),
+                ordinal.to_string_with_locale_opts(Locale::Russian, &neuter)
+            );
+        }
+    }
+
+    #[test]
+    fn test_russian_cases() {
+        let russian = |n: u32, gender: Gender, case: Case| {
+            let options = OrdinalOptions::new().gender(gender).case(case);
+            Ordinal(n).to_string_with_locale_opts(Locale::Russian, &options)
+        };
+
+        assert_eq!("3-го", russian(3, Gender::Masculine, Case::Genitive));
+        assert_eq!("21-го", russian(21, Gender::Neuter, Case::Genitive));
+        assert_eq!("3-й", russian(3, Gender::Feminine, Case::Genitive));
+        assert_eq!("1-му", russian(1, Gender::Masculine, Case::Dative));
+        assert_eq!("8-й", russian(8, Gender::Feminine, Case::Dative));
+        assert_eq!("5-м", russian(5, Gender::Masculine, Case::Instrumental));
+        assert_eq!("2-м", russian(2, Gender::Neuter, Case::Prepositional));
+        assert_eq!("7-ю", russian(7, Gender::Feminine, Case::Accusative));
+        assert_eq!("100-й", russian(100, Gender::Masculine, Case::Accusative));
+    }
+}
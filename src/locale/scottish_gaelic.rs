@@ -0,0 +1,112 @@
+//! Scottish Gaelic suffixes follow the CLDR ordinal categories, which are exact: one
+//! for 1 and 11 (`1d`, `11d`), two for 2 and 12 (`2na`), few for 3 and 13 (`3mh`), and
+//! other for everything else (`4mh`, `21mh`). Few and other share `mh`.
+//!
+//! Word forms cover 1–10 with their article: `a' chiad`, `an dàrna`, `an treas`,
+//! `an t-ochdamh`. Compounds from 11 up wrap the noun (`an t-aonamh latha deug`) and
+//! aren't supported.
+
+use super::{Number, OrdinalCategory};
+use alloc::format;
+use alloc::string::{String, ToString};
+
+const UNITS: [&str; 11] = [
+    "",
+    "a' chiad",
+    "an dàrna",
+    "an treas",
+    "an ceathramh",
+    "an còigeamh",
+    "an siathamh",
+    "an seachdamh",
+    "an t-ochdamh",
+    "an naoidheamh",
+    "an deicheamh",
+];
+
+pub(crate) fn format(number: &Number) -> String {
+    let suffix = match category(number) {
+        OrdinalCategory::One => "d",
+        OrdinalCategory::Two => "na",
+        _ => "mh",
+    };
+    format!("{}{}", number, suffix)
+}
+
+pub(crate) fn category(number: &Number) -> OrdinalCategory {
+    match number.magnitude() {
+        Some(1 | 11) => OrdinalCategory::One,
+        Some(2 | 12) => OrdinalCategory::Two,
+        Some(3 | 13) => OrdinalCategory::Few,
+        _ => OrdinalCategory::Other,
+    }
+}
+
+/// Spells out 1–10.
+pub(crate) fn words(n: u128) -> Option<String> {
+    match n {
+        1..=10 => Some(UNITS[n as usize].to_string()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::locale::OrdinalCategory::{self, Few, One, Other, Two};
+    use crate::{Locale, Ordinal, OrdinalOptions};
+
+    #[test]
+    fn test_scottish_gaelic_suffixes() {
+        let scottish_gaelic_ordinals: [&str; 40] = [
+            "1d", "2na", "3mh", "4mh", "5mh", "6mh", "7mh", "8mh", "9mh", "10mh", "11d", "12na",
+            "13mh", "14mh", "15mh", "16mh", "17mh", "18mh", "19mh", "20mh", "21mh", "22mh", "23mh",
+            "24mh", "25mh", "26mh", "27mh", "28mh", "29mh", "30mh", "31mh", "32mh", "33mh", "34mh",
+            "35mh", "36mh", "37mh", "38mh", "39mh", "40mh",
+        ];
+
+        for (i, expected) in scottish_gaelic_ordinals.iter().enumerate() {
+            assert_eq!(
+                *expected,
+                Ordinal(i + 1).to_string_with_locale(Locale::ScottishGaelic)
+            );
+        }
+        assert_eq!(
+            "-11d",
+            Ordinal(-11).to_string_with_locale(Locale::ScottishGaelic)
+        );
+        assert_eq!(
+            "111mh",
+            Ordinal(111).to_string_with_locale(Locale::ScottishGaelic)
+        );
+    }
+
+    #[test]
+    fn test_scottish_gaelic_categories() {
+        let scottish_gaelic_categories: [OrdinalCategory; 40] = [
+            One, Two, Few, Other, Other, Other, Other, Other, Other, Other, One, Two, Few, Other,
+            Other, Other, Other, Other, Other, Other, Other, Other, Other, Other, Other, Other,
+            Other, Other, Other, Other, Other, Other, Other, Other, Other, Other, Other, Other,
+            Other, Other,
+        ];
+
+        for (i, expected) in scottish_gaelic_categories.iter().enumerate() {
+            assert_eq!(
+                *expected,
+                Ordinal(i + 1).plural_category(Locale::ScottishGaelic)
+            );
+        }
+    }
+
+    #[test]
+    fn test_scottish_gaelic_words() {
+        let options = OrdinalOptions::new();
+        let words = |n: u32| Ordinal(n).to_words_locale(Locale::ScottishGaelic, &options);
+        assert_eq!("a' chiad", words(1).unwrap());
+        assert_eq!("an dàrna", words(2).unwrap());
+        assert_eq!("an treas", words(3).unwrap());
+        assert_eq!("an t-ochdamh", words(8).unwrap());
+        assert_eq!("an deicheamh", words(10).unwrap());
+        assert!(words(0).is_err());
+        assert!(words(11).is_err());
+    }
+}
@@ -0,0 +1,223 @@
+//! Serbian ordinals are written as the number followed by a period: `3.`.
+//! Word forms cover 1 to 100 in the nominative, in Cyrillic unless the Latin script is
+//! asked for. Only the last word of a compound is ordinal, `двадесет први`, and it
+//! inflects like an adjective: `-и`, `-а`, `-о`, with `-е` for the neuter of `трећ-`.
+//! The Cyrillic forms are transliterated from the Latin ones, letter for letter.
+
+use super::{Gender, OrdinalOptions, Script};
+use alloc::format;
+use alloc::string::String;
+
+/// The masculine forms from 1 to 19, in Latin.
+const UNITS: [&str; 20] = [
+    "",
+    "prvi",
+    "drugi",
+    "treći",
+    "četvrti",
+    "peti",
+    "šesti",
+    "sedmi",
+    "osmi",
+    "deveti",
+    "deseti",
+    "jedanaesti",
+    "dvanaesti",
+    "trinaesti",
+    "četrnaesti",
+    "petnaesti",
+    "šesnaesti",
+    "sedamnaesti",
+    "osamnaesti",
+    "devetnaesti",
+];
+
+/// The cardinal tens, which lead a compound, in Latin.
+const TENS: [&str; 10] = [
+    "",
+    "",
+    "dvadeset",
+    "trideset",
+    "četrdeset",
+    "pedeset",
+    "šezdeset",
+    "sedamdeset",
+    "osamdeset",
+    "devedeset",
+];
+
+/// Latin letters and digraphs with their Cyrillic counterparts. Digraphs come first so
+/// `lj`, `nj` and `dž` aren't taken as two letters.
+const CYRILLIC: [(&str, char); 30] = [
+    ("lj", 'љ'),
+    ("nj", 'њ'),
+    ("dž", 'џ'),
+    ("a", 'а'),
+    ("b", 'б'),
+    ("c", 'ц'),
+    ("č", 'ч'),
+    ("ć", 'ћ'),
+    ("d", 'д'),
+    ("đ", 'ђ'),
+    ("e", 'е'),
+    ("f", 'ф'),
+    ("g", 'г'),
+    ("h", 'х'),
+    ("i", 'и'),
+    ("j", 'ј'),
+    ("k", 'к'),
+    ("l", 'л'),
+    ("m", 'м'),
+    ("n", 'н'),
+    ("o", 'о'),
+    ("p", 'п'),
+    ("r", 'р'),
+    ("s", 'с'),
+    ("š", 'ш'),
+    ("t", 'т'),
+    ("u", 'у'),
+    ("v", 'в'),
+    ("z", 'з'),
+    ("ž", 'ж'),
+];
+
+/// Spells out `n` for values from 1 to 100.
+pub(crate) fn words(n: u128, options: &OrdinalOptions) -> Option<String> {
+    let gender = options.gender;
+    let latin = match n {
+        1..=19 => inflect(UNITS[n as usize], gender),
+        20..=99 => {
+            let tens = TENS[(n / 10) as usize];
+            match n % 10 {
+                0 => inflect(&format!("{}i", tens), gender),
+                unit => format!("{} {}", tens, inflect(UNITS[unit as usize], gender)),
+            }
+        }
+        100 => inflect("stoti", gender),
+        _ => return None,
+    };
+    Some(match options.script.unwrap_or(Script::Cyrillic) {
+        Script::Latin => latin,
+        _ => to_cyrillic(&latin),
+    })
+}
+
+fn inflect(masculine: &str, gender: Gender) -> String {
+    let stem = masculine.strip_suffix('i').unwrap_or(masculine);
+    let ending = match gender {
+        Gender::Feminine => "a",
+        Gender::Neuter if stem.ends_with('ć') => "e",
+        Gender::Neuter => "o",
+        Gender::Masculine | Gender::Common => "i",
+    };
+    format!("{}{}", stem, ending)
+}
+
+fn to_cyrillic(latin: &str) -> String {
+    let mut out = String::with_capacity(latin.len() * 2);
+    let mut rest = latin;
+    while let Some(c) = rest.chars().next() {
+        match CYRILLIC
+            .iter()
+            .find(|(letters, _)| rest.starts_with(letters))
+        {
+            Some((letters, cyrillic)) => {
+                out.push(*cyrillic);
+                rest = &rest[letters.len()..];
+            }
+            None => {
+                out.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Gender, Locale, Ordinal, OrdinalOptions, Script};
+
+    #[test]
+    fn test_serbian_digits() {
+        for i in [1, 3, 21, 100] {
+            for script in [Script::Cyrillic, Script::Latin] {
+                let options = OrdinalOptions::new().script(script);
+                assert_eq!(
+                    format!("{}.", i),
+                    Ordinal(i).to_string_with_locale_opts(Locale::Serbian, &options)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_serbian_words() {
+        // Masculine and feminine, in Cyrillic and in Latin.
+        let serbian_ordinals: [(&str, &str, &str, &str); 21] = [
+            ("први", "прва", "prvi", "prva"),
+            ("други", "друга", "drugi", "druga"),
+            ("трећи", "трећа", "treći", "treća"),
+            ("четврти", "четврта", "četvrti", "četvrta"),
+            ("пети", "пета", "peti", "peta"),
+            ("шести", "шеста", "šesti", "šesta"),
+            ("седми", "седма", "sedmi", "sedma"),
+            ("осми", "осма", "osmi", "osma"),
+            ("девети", "девета", "deveti", "deveta"),
+            ("десети", "десета", "deseti", "deseta"),
+            ("једанаести", "једанаеста", "jedanaesti", "jedanaesta"),
+            ("дванаести", "дванаеста", "dvanaesti", "dvanaesta"),
+            ("тринаести", "тринаеста", "trinaesti", "trinaesta"),
+            ("четрнаести", "четрнаеста", "četrnaesti", "četrnaesta"),
+            ("петнаести", "петнаеста", "petnaesti", "petnaesta"),
+            ("шеснаести", "шеснаеста", "šesnaesti", "šesnaesta"),
+            ("седамнаести", "седамнаеста", "sedamnaesti", "sedamnaesta"),
+            ("осамнаести", "осамнаеста", "osamnaesti", "osamnaesta"),
+            ("деветнаести", "деветнаеста", "devetnaesti", "devetnaesta"),
+            ("двадесети", "двадесета", "dvadeseti", "dvadeseta"),
+            (
+                "двадесет први",
+                "двадесет прва",
+                "dvadeset prvi",
+                "dvadeset prva",
+            ),
+        ];
+
+        for (i, forms) in serbian_ordinals.iter().enumerate() {
+            let cases = [
+                (Gender::Masculine, Script::Cyrillic, forms.0),
+                (Gender::Feminine, Script::Cyrillic, forms.1),
+                (Gender::Masculine, Script::Latin, forms.2),
+                (Gender::Feminine, Script::Latin, forms.3),
+            ];
+            for (gender, script, expected) in cases {
+                let options = OrdinalOptions::new().gender(gender).script(script);
+                assert_eq!(
+                    expected,
+                    Ordinal(i + 1)
+                        .to_words_locale(Locale::Serbian, &options)
+                        .unwrap()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_serbian_defaults_and_range() {
+        let words =
+            |n: u32, options: &OrdinalOptions| Ordinal(n).to_words_locale(Locale::Serbian, options);
+        let options = OrdinalOptions::new();
+        assert_eq!("трећи", words(3, &options).unwrap());
+        assert_eq!("деведесет девети", words(99, &options).unwrap());
+        assert_eq!("стоти", words(100, &options).unwrap());
+        let neuter = OrdinalOptions::new().gender(Gender::Neuter);
+        assert_eq!("треће", words(3, &neuter).unwrap());
+        assert_eq!("двадесет прво", words(21, &neuter).unwrap());
+        assert_eq!(
+            "šezdeseto",
+            words(60, &neuter.clone().script(Script::Latin)).unwrap()
+        );
+        assert!(words(0, &options).is_err());
+        assert!(words(101, &options).is_err());
+    }
+}
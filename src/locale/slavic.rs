@@ -0,0 +1,48 @@
+//! Composition shared by the Slavic word forms, where a compound ordinal is a run of
+//! cardinal words followed by ordinal adjectives that agree in gender:
+//! Polish `sto dwudziesty pierwszy`, Czech `dvacátý první`.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// Joins the cardinal parts with the ordinal parts, inflecting each ordinal part.
+pub(crate) fn compose(
+    cardinal: &[&str],
+    ordinal: &[&str],
+    inflect: impl Fn(&str) -> String,
+) -> String {
+    cardinal
+        .iter()
+        .map(|word| word.to_string())
+        .chain(ordinal.iter().map(|word| inflect(word)))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Splits `n` (below 10 000) into its thousands, hundreds, tens and units words,
+/// for languages where every component of a compound is ordinal (`stý dvacátý první`).
+/// The caller supplies the already built thousands and hundreds words.
+pub(crate) fn ordinal_parts<'a>(
+    n: usize,
+    units: &[&'a str; 20],
+    tens: &[&'a str; 10],
+    hundreds: &'a str,
+    thousands: &'a str,
+) -> Vec<&'a str> {
+    let mut parts = Vec::new();
+    if n >= 1000 {
+        parts.push(thousands);
+    }
+    let hundreds_digit = n / 100 % 10;
+    if hundreds_digit > 0 {
+        parts.push(hundreds);
+    }
+    let rest = n % 100;
+    match (rest, rest % 10) {
+        (0, _) => {}
+        (1..=19, _) => parts.push(units[rest]),
+        (_, 0) => parts.push(tens[rest / 10]),
+        (_, unit) => parts.extend([tens[rest / 10], units[unit]]),
+    }
+    parts
+}
@@ -0,0 +1,215 @@
+//! Slovak ordinals are written as the number followed by a period: `3.`.
+//! As in Czech, every component of a compound word form is ordinal (`dvadsiaty prvý`),
+//! but many stems differ (`štvrtý`, `siedmy`, `ôsmy`) and adjectives ending in a short
+//! `-y` after a long syllable inflect to `-a`/`-e` (`piaty`, `piata`, `piate`).
+
+use super::{slavic, Gender, OrdinalOptions};
+use alloc::format;
+use alloc::string::{String, ToString};
+
+const UNITS: [&str; 20] = [
+    "",
+    "prvý",
+    "druhý",
+    "tretí",
+    "štvrtý",
+    "piaty",
+    "šiesty",
+    "siedmy",
+    "ôsmy",
+    "deviaty",
+    "desiaty",
+    "jedenásty",
+    "dvanásty",
+    "trinásty",
+    "štrnásty",
+    "pätnásty",
+    "šestnásty",
+    "sedemnásty",
+    "osemnásty",
+    "devätnásty",
+];
+
+const TENS: [&str; 10] = [
+    "",
+    "",
+    "dvadsiaty",
+    "tridsiaty",
+    "štyridsiaty",
+    "päťdesiaty",
+    "šesťdesiaty",
+    "sedemdesiaty",
+    "osemdesiaty",
+    "deväťdesiaty",
+];
+
+/// Multiplier prefixes for `stý` and `tisíci`.
+const MULTIPLIERS: [&str; 10] = [
+    "", "", "dvoj", "troj", "štvor", "päť", "šesť", "sedem", "osem", "deväť",
+];
+
+/// Spells out `n` for values from 1 to 9999.
+pub(crate) fn words(n: u128, options: &OrdinalOptions) -> Option<String> {
+    if n == 0 || n >= 10_000 {
+        return None;
+    }
+    let n = n as usize;
+    let thousands = format!("{}tisíci", MULTIPLIERS[n / 1000]);
+    let hundreds = format!("{}stý", MULTIPLIERS[n / 100 % 10]);
+    let parts = slavic::ordinal_parts(n, &UNITS, &TENS, &hundreds, &thousands);
+    Some(slavic::compose(&[], &parts, |word| {
+        inflect(word, options.gender)
+    }))
+}
+
+fn inflect(masculine: &str, gender: Gender) -> String {
+    let feminine = gender == Gender::Feminine;
+    if gender != Gender::Feminine && gender != Gender::Neuter {
+        return masculine.to_string();
+    }
+    let (stem, ending) = if let Some(stem) = masculine.strip_suffix('ý') {
+        (stem, if feminine { "á" } else { "é" })
+    } else if let Some(stem) = masculine.strip_suffix('í') {
+        (stem, if feminine { "ia" } else { "ie" })
+    } else {
+        let stem = masculine.strip_suffix(['y', 'i']).unwrap_or(masculine);
+        (stem, if feminine { "a" } else { "e" })
+    };
+    format!("{}{}", stem, ending)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Gender, Locale, Ordinal, OrdinalOptions};
+
+    #[test]
+    fn test_slovak_digits() {
+        for i in [1, 3, 9, 21, 100, 1993] {
+            assert_eq!(
+                format!("{}.", i),
+                Ordinal(i).to_string_with_locale(Locale::Slovak)
+            );
+        }
+    }
+
+    #[test]
+    fn test_slovak_words() {
+        let masculine: [&str; 31] = [
+            "prvý",
+            "druhý",
+            "tretí",
+            "štvrtý",
+            "piaty",
+            "šiesty",
+            "siedmy",
+            "ôsmy",
+            "deviaty",
+            "desiaty",
+            "jedenásty",
+            "dvanásty",
+            "trinásty",
+            "štrnásty",
+            "pätnásty",
+            "šestnásty",
+            "sedemnásty",
+            "osemnásty",
+            "devätnásty",
+            "dvadsiaty",
+            "dvadsiaty prvý",
+            "dvadsiaty druhý",
+            "dvadsiaty tretí",
+            "dvadsiaty štvrtý",
+            "dvadsiaty piaty",
+            "dvadsiaty šiesty",
+            "dvadsiaty siedmy",
+            "dvadsiaty ôsmy",
+            "dvadsiaty deviaty",
+            "tridsiaty",
+            "tridsiaty prvý",
+        ];
+        let feminine: [&str; 31] = [
+            "prvá",
+            "druhá",
+            "tretia",
+            "štvrtá",
+            "piata",
+            "šiesta",
+            "siedma",
+            "ôsma",
+            "deviata",
+            "desiata",
+            "jedenásta",
+            "dvanásta",
+            "trinásta",
+            "štrnásta",
+            "pätnásta",
+            "šestnásta",
+            "sedemnásta",
+            "osemnásta",
+            "devätnásta",
+            "dvadsiata",
+            "dvadsiata prvá",
+            "dvadsiata druhá",
+            "dvadsiata tretia",
+            "dvadsiata štvrtá",
+            "dvadsiata piata",
+            "dvadsiata šiesta",
+            "dvadsiata siedma",
+            "dvadsiata ôsma",
+            "dvadsiata deviata",
+            "tridsiata",
+            "tridsiata prvá",
+        ];
+        let neuter: [&str; 31] = [
+            "prvé",
+            "druhé",
+            "tretie",
+            "štvrté",
+            "piate",
+            "šieste",
+            "siedme",
+            "ôsme",
+            "deviate",
+            "desiate",
+            "jedenáste",
+            "dvanáste",
+            "trináste",
+            "štrnáste",
+            "pätnáste",
+            "šestnáste",
+            "sedemnáste",
+            "osemnáste",
+            "devätnáste",
+            "dvadsiate",
+            "dvadsiate prvé",
+            "dvadsiate druhé",
+            "dvadsiate tretie",
+            "dvadsiate štvrté",
+            "dvadsiate piate",
+            "dvadsiate šieste",
+            "dvadsiate siedme",
+            "dvadsiate ôsme",
+            "dvadsiate deviate",
+            "tridsiate",
+            "tridsiate prvé",
+        ];
+        let words = |n: usize, gender: Gender| {
+            let options = OrdinalOptions::new().gender(gender);
+            Ordinal(n)
+                .to_words_locale(Locale::Slovak, &options)
+                .unwrap()
+        };
+        for i in 0..31 {
+            assert_eq!(masculine[i], words(i + 1, Gender::Masculine));
+            assert_eq!(feminine[i], words(i + 1, Gender::Feminine));
+            assert_eq!(neuter[i], words(i + 1, Gender::Neuter));
+        }
+
+        assert_eq!("stý", words(100, Gender::Masculine));
+        assert_eq!("stá", words(100, Gender::Feminine));
+        assert_eq!("sté", words(100, Gender::Neuter));
+        assert_eq!("tisíci", words(1000, Gender::Masculine));
+        assert_eq!("tisíca", words(1000, Gender::Feminine));
+        assert_eq!("dvojstý štyridsiaty piaty", words(245, Gender::Masculine));
+    }
+}
@@ -0,0 +1,179 @@
+//! Slovenian ordinals are written as the number followed by a period: `3.`.
+//!
+//! Word forms cover 1 to 1000 in the nominative. Units come before the tens, joined
+//! with `in` into one word like in German: `enaindvajseti`. Only the last word of a
+//! compound is ordinal, `sto prvi`, and it inflects like an adjective: `-i`, `-a`,
+//! `-o`, with `-e` for the neuter of soft stems like `tretj-` and `tisoč-`.
+
+use super::{Gender, OrdinalOptions};
+use alloc::format;
+use alloc::string::{String, ToString};
+
+/// The masculine forms from 1 to 19.
+const UNITS: [&str; 20] = [
+    "",
+    "prvi",
+    "drugi",
+    "tretji",
+    "četrti",
+    "peti",
+    "šesti",
+    "sedmi",
+    "osmi",
+    "deveti",
+    "deseti",
+    "enajsti",
+    "dvanajsti",
+    "trinajsti",
+    "štirinajsti",
+    "petnajsti",
+    "šestnajsti",
+    "sedemnajsti",
+    "osemnajsti",
+    "devetnajsti",
+];
+
+/// The cardinal units, which lead the tens and the hundreds.
+const CARDINALS: [&str; 10] = [
+    "", "ena", "dva", "tri", "štiri", "pet", "šest", "sedem", "osem", "devet",
+];
+
+/// The cardinal tens.
+const TENS: [&str; 10] = [
+    "",
+    "",
+    "dvajset",
+    "trideset",
+    "štirideset",
+    "petdeset",
+    "šestdeset",
+    "sedemdeset",
+    "osemdeset",
+    "devetdeset",
+];
+
+/// Spells out `n` for values from 1 to 1000.
+pub(crate) fn words(n: u128, options: &OrdinalOptions) -> Option<String> {
+    let n = match n {
+        1..=999 => n as usize,
+        1000 => return Some(inflect("tisoči", options.gender)),
+        _ => return None,
+    };
+    let (hundreds, rest) = (n / 100, n % 100);
+    let hundred = match hundreds {
+        0 => String::new(),
+        1 => "sto".to_string(),
+        2 => "dvesto".to_string(),
+        _ => format!("{}sto", CARDINALS[hundreds]),
+    };
+    let masculine = match (hundreds, rest) {
+        (_, 0) => format!("{}ti", hundred),
+        (0, _) => below_hundred(rest),
+        _ => format!("{} {}", hundred, below_hundred(rest)),
+    };
+    Some(inflect(&masculine, options.gender))
+}
+
+fn below_hundred(n: usize) -> String {
+    let (tens, units) = (n / 10, n % 10);
+    match (tens, units) {
+        (0 | 1, _) => UNITS[n].to_string(),
+        (_, 0) => format!("{}i", TENS[tens]),
+        _ => format!("{}in{}i", CARDINALS[units], TENS[tens]),
+    }
+}
+
+fn inflect(masculine: &str, gender: Gender) -> String {
+    let stem = masculine.strip_suffix('i').unwrap_or(masculine);
+    let ending = match gender {
+        Gender::Feminine => "a",
+        Gender::Neuter if stem.ends_with(['j', 'č']) => "e",
+        Gender::Neuter => "o",
+        Gender::Masculine | Gender::Common => "i",
+    };
+    format!("{}{}", stem, ending)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Gender, Locale, Ordinal, OrdinalOptions};
+
+    #[test]
+    fn test_slovenian_digits() {
+        for i in [1, 2, 3, 21, 100, 1000] {
+            assert_eq!(
+                format!("{}.", i),
+                Ordinal(i).to_string_with_locale(Locale::Slovenian)
+            );
+        }
+    }
+
+    #[test]
+    fn test_slovenian_words() {
+        // Masculine, feminine and neuter.
+        let slovenian_ordinals: [(&str, &str, &str); 30] = [
+            ("prvi", "prva", "prvo"),
+            ("drugi", "druga", "drugo"),
+            ("tretji", "tretja", "tretje"),
+            ("četrti", "četrta", "četrto"),
+            ("peti", "peta", "peto"),
+            ("šesti", "šesta", "šesto"),
+            ("sedmi", "sedma", "sedmo"),
+            ("osmi", "osma", "osmo"),
+            ("deveti", "deveta", "deveto"),
+            ("deseti", "deseta", "deseto"),
+            ("enajsti", "enajsta", "enajsto"),
+            ("dvanajsti", "dvanajsta", "dvanajsto"),
+            ("trinajsti", "trinajsta", "trinajsto"),
+            ("štirinajsti", "štirinajsta", "štirinajsto"),
+            ("petnajsti", "petnajsta", "petnajsto"),
+            ("šestnajsti", "šestnajsta", "šestnajsto"),
+            ("sedemnajsti", "sedemnajsta", "sedemnajsto"),
+            ("osemnajsti", "osemnajsta", "osemnajsto"),
+            ("devetnajsti", "devetnajsta", "devetnajsto"),
+            ("dvajseti", "dvajseta", "dvajseto"),
+            ("enaindvajseti", "enaindvajseta", "enaindvajseto"),
+            ("dvaindvajseti", "dvaindvajseta", "dvaindvajseto"),
+            ("triindvajseti", "triindvajseta", "triindvajseto"),
+            ("štiriindvajseti", "štiriindvajseta", "štiriindvajseto"),
+            ("petindvajseti", "petindvajseta", "petindvajseto"),
+            ("šestindvajseti", "šestindvajseta", "šestindvajseto"),
+            ("sedemindvajseti", "sedemindvajseta", "sedemindvajseto"),
+            ("osemindvajseti", "osemindvajseta", "osemindvajseto"),
+            ("devetindvajseti", "devetindvajseta", "devetindvajseto"),
+            ("trideseti", "trideseta", "trideseto"),
+        ];
+
+        let genders = [Gender::Masculine, Gender::Feminine, Gender::Neuter];
+        for (i, forms) in slovenian_ordinals.iter().enumerate() {
+            for (gender, expected) in genders.into_iter().zip([forms.0, forms.1, forms.2]) {
+                let options = OrdinalOptions::new().gender(gender);
+                assert_eq!(
+                    expected,
+                    Ordinal(i + 1)
+                        .to_words_locale(Locale::Slovenian, &options)
+                        .unwrap()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_slovenian_hundreds_and_thousand() {
+        let words = |n: u32, gender: Gender| {
+            let options = OrdinalOptions::new().gender(gender);
+            Ordinal(n).to_words_locale(Locale::Slovenian, &options)
+        };
+        assert_eq!("devetindevetdeseti", words(99, Gender::Masculine).unwrap());
+        assert_eq!("stoti", words(100, Gender::Masculine).unwrap());
+        assert_eq!("stota", words(100, Gender::Feminine).unwrap());
+        assert_eq!("sto prvi", words(101, Gender::Masculine).unwrap());
+        assert_eq!("dvestoti", words(200, Gender::Masculine).unwrap());
+        assert_eq!("tristo tretje", words(303, Gender::Neuter).unwrap());
+        assert_eq!("tisoči", words(1000, Gender::Masculine).unwrap());
+        assert_eq!("tisoča", words(1000, Gender::Feminine).unwrap());
+        assert_eq!("tisoče", words(1000, Gender::Neuter).unwrap());
+        assert!(words(0, Gender::Masculine).is_err());
+        assert!(words(1001, Gender::Masculine).is_err());
+    }
+}
@@ -0,0 +1,247 @@
+//! Spanish ordinals use the masculine (`º`, U+00BA) and feminine (`ª`, U+00AA)
+//! ordinal indicators, preceded by a period as recommended by the RAE: `1.º`, `1.ª`.
+//! The indicator is the same in every variety of Spanish.
+//!
+//! Word forms are composed part by part, each agreeing in gender and number:
+//! `vigésimo primero`, `vigésimas primeras`. Before a masculine noun `primero` and
+//! `tercero` shorten to `primer` and `tercer`, which [`OrdinalOptions::apocope`] asks for.
+
+use super::{romance, Gender, GrammaticalNumber, Number, OrdinalOptions};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+const UNITS: [&str; 20] = [
+    "",
+    "primero",
+    "segundo",
+    "tercero",
+    "cuarto",
+    "quinto",
+    "sexto",
+    "séptimo",
+    "octavo",
+    "noveno",
+    "décimo",
+    "undécimo",
+    "duodécimo",
+    "decimotercero",
+    "decimocuarto",
+    "decimoquinto",
+    "decimosexto",
+    "decimoséptimo",
+    "decimoctavo",
+    "decimonoveno",
+];
+
+const TENS: [&str; 10] = [
+    "",
+    "",
+    "vigésimo",
+    "trigésimo",
+    "cuadragésimo",
+    "quincuagésimo",
+    "sexagésimo",
+    "septuagésimo",
+    "octogésimo",
+    "nonagésimo",
+];
+
+const HUNDREDS: [&str; 10] = [
+    "",
+    "centésimo",
+    "ducentésimo",
+    "tricentésimo",
+    "cuadringentésimo",
+    "quingentésimo",
+    "sexcentésimo",
+    "septingentésimo",
+    "octingentésimo",
+    "noningentésimo",
+];
+
+/// Cardinal prefixes of `milésimo`: `dosmilésimo`, `diezmilésimo`.
+const THOUSANDS: [&str; 11] = [
+    "", "", "dos", "tres", "cuatro", "cinco", "seis", "siete", "ocho", "nueve", "diez",
+];
+
+pub(crate) fn format(number: &Number, options: &OrdinalOptions) -> String {
+    romance::indicator(number, options.gender, options.period)
+}
+
+/// Spells out `n` for values from 1 to 10 999, as far as `milésimo` takes a one-word
+/// prefix.
+pub(crate) fn words(n: u128, options: &OrdinalOptions) -> Option<String> {
+    if n == 0 || n >= 11_000 {
+        return None;
+    }
+    let n = n as usize;
+    let thousands = match n / 1000 {
+        0 => String::new(),
+        count => format!("{}milésimo", THOUSANDS[count]),
+    };
+    let rest = n % 100;
+    let mut parts = vec![thousands.as_str(), HUNDREDS[n / 100 % 10]];
+    if rest < 20 {
+        parts.push(UNITS[rest]);
+    } else {
+        parts.extend([TENS[rest / 10], UNITS[rest % 10]]);
+    }
+    let mut words = parts
+        .into_iter()
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let singular = options.number == GrammaticalNumber::Singular;
+    match options.gender {
+        Gender::Feminine => words = romance::feminine(&words),
+        _ if options.apocope
+            && singular
+            && (words.ends_with("primero") || words.ends_with("tercero")) =>
+        {
+            words.pop();
+        }
+        _ => {}
+    }
+    if !singular {
+        words = words.replace(' ', "s ") + "s";
+    }
+    Some(words)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Gender, GrammaticalNumber, Locale, Ordinal, OrdinalOptions};
+
+    #[test]
+    fn test_spanish_default() {
+        assert_eq!("1.º", Ordinal(1).to_string_with_locale(Locale::Spanish));
+        assert_eq!("-3.º", Ordinal(-3).to_string_with_locale(Locale::Spanish));
+    }
+
+    #[test]
+    fn test_spanish_genders_and_period() {
+        let masculine = OrdinalOptions::new();
+        let feminine = OrdinalOptions::new().gender(Gender::Feminine);
+        let masculine_short = OrdinalOptions::new().period(false);
+        let feminine_short = OrdinalOptions::new().gender(Gender::Feminine).period(false);
+
+        for i in 1..=100 {
+            let ordinal = Ordinal(i);
+            assert_eq!(
+                format!("{}.\u{ba}", i),
+                ordinal.to_string_with_locale_opts(Locale::Spanish, &masculine)
+            );
+            assert_eq!(
+                format!("{}.\u{aa}", i),
+                ordinal.to_string_with_locale_opts(Locale::Spanish, &feminine)
+            );
+            assert_eq!(
+                format!("{}\u{ba}", i),
+                ordinal.to_string_with_locale_opts(Locale::Spanish, &masculine_short)
+            );
+            assert_eq!(
+                format!("{}\u{aa}", i),
+                ordinal.to_string_with_locale_opts(Locale::Spanish, &feminine_short)
+            );
+        }
+    }
+
+    #[test]
+    fn test_spanish_words() {
+        let spanish: [&str; 31] = [
+            "primero",
+            "segundo",
+            "tercero",
+            "cuarto",
+            "quinto",
+            "sexto",
+            "séptimo",
+            "octavo",
+            "noveno",
+            "décimo",
+            "undécimo",
+            "duodécimo",
+            "decimotercero",
+            "decimocuarto",
+            "decimoquinto",
+            "decimosexto",
+            "decimoséptimo",
+            "decimoctavo",
+            "decimonoveno",
+            "vigésimo",
+            "vigésimo primero",
+            "vigésimo segundo",
+            "vigésimo tercero",
+            "vigésimo cuarto",
+            "vigésimo quinto",
+            "vigésimo sexto",
+            "vigésimo séptimo",
+            "vigésimo octavo",
+            "vigésimo noveno",
+            "trigésimo",
+            "trigésimo primero",
+        ];
+
+        let options = OrdinalOptions::new();
+        for (i, expected) in spanish.iter().enumerate() {
+            assert_eq!(
+                *expected,
+                Ordinal(i + 1)
+                    .to_words_locale(Locale::Spanish, &options)
+                    .unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_spanish_words_boundaries() {
+        let options = OrdinalOptions::new();
+        let words = |n: u32, options: &OrdinalOptions| {
+            Ordinal(n)
+                .to_words_locale(Locale::Spanish, options)
+                .unwrap()
+        };
+
+        assert_eq!("nonagésimo noveno", words(99, &options));
+        assert_eq!("centésimo", words(100, &options));
+        assert_eq!("centésimo primero", words(101, &options));
+        assert_eq!("ducentésimo", words(200, &options));
+        assert_eq!("noningentésimo nonagésimo noveno", words(999, &options));
+        assert_eq!("milésimo", words(1000, &options));
+        assert_eq!("milésimo primero", words(1001, &options));
+        assert_eq!("dosmilésimo vigésimo cuarto", words(2024, &options));
+        assert_eq!(
+            "nuevemilésimo noningentésimo nonagésimo noveno",
+            words(9999, &options)
+        );
+        assert_eq!("diezmilésimo", words(10_000, &options));
+        assert!(Ordinal(11_000)
+            .to_words_locale(Locale::Spanish, &options)
+            .is_err());
+
+        let feminine = OrdinalOptions::new().gender(Gender::Feminine);
+        assert_eq!("primera", words(1, &feminine));
+        assert_eq!("vigésima primera", words(21, &feminine));
+        assert_eq!("decimotercera", words(13, &feminine));
+
+        let apocope = OrdinalOptions::new().apocope(true);
+        assert_eq!("primer", words(1, &apocope));
+        assert_eq!("tercer", words(3, &apocope));
+        assert_eq!("vigésimo primer", words(21, &apocope));
+        assert_eq!("decimotercer", words(13, &apocope));
+        assert_eq!("segundo", words(2, &apocope));
+        assert_eq!(
+            "primera",
+            words(1, &apocope.clone().gender(Gender::Feminine))
+        );
+
+        let plural = OrdinalOptions::new().number(GrammaticalNumber::Plural);
+        assert_eq!("primeros", words(1, &plural.clone().apocope(true)));
+        assert_eq!(
+            "vigésimas primeras",
+            words(21, &plural.gender(Gender::Feminine))
+        );
+    }
+}
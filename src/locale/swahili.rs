@@ -0,0 +1,151 @@
+//! Swahili ordinals put the `-a` connective, with the agreement prefix of the noun's
+//! class, before the number: `wa 3`, `ya 3`. Word forms cover 1 to 100 and use the
+//! cardinal except for `kwanza` and `pili`: `wa kwanza`, `wa pili`, `wa tatu`,
+//! `wa ishirini na moja`.
+
+use super::{Number, OrdinalOptions};
+use alloc::format;
+use alloc::string::{String, ToString};
+
+/// The agreement prefix of the `-a` connective, which follows the noun class.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum SwahiliPrefix {
+    /// `wa`, for the m-wa and m-mi classes (`mtu wa kwanza`).
+    #[default]
+    Wa,
+    /// `ya`, for the mi and n classes and the ma plural (`siku ya kwanza`).
+    Ya,
+    /// `la`, for the ji class (`somo la kwanza`).
+    La,
+    /// `cha`, for the ki class (`kitabu cha kwanza`).
+    Cha,
+    /// `vya`, for the vi plural (`vitabu vya kwanza`).
+    Vya,
+    /// `za`, for the n plural (`nyumba za kwanza`).
+    Za,
+    /// `kwa`, for the ku infinitive and the indefinite locative.
+    Kwa,
+    /// `pa`, for the definite locative (`mahali pa kwanza`).
+    Pa,
+    /// `mwa`, for the inner locative.
+    Mwa,
+}
+
+impl SwahiliPrefix {
+    fn as_str(self) -> &'static str {
+        match self {
+            SwahiliPrefix::Wa => "wa",
+            SwahiliPrefix::Ya => "ya",
+            SwahiliPrefix::La => "la",
+            SwahiliPrefix::Cha => "cha",
+            SwahiliPrefix::Vya => "vya",
+            SwahiliPrefix::Za => "za",
+            SwahiliPrefix::Kwa => "kwa",
+            SwahiliPrefix::Pa => "pa",
+            SwahiliPrefix::Mwa => "mwa",
+        }
+    }
+}
+
+const UNITS: [&str; 10] = [
+    "", "moja", "mbili", "tatu", "nne", "tano", "sita", "saba", "nane", "tisa",
+];
+
+const TENS: [&str; 10] = [
+    "",
+    "kumi",
+    "ishirini",
+    "thelathini",
+    "arobaini",
+    "hamsini",
+    "sitini",
+    "sabini",
+    "themanini",
+    "tisini",
+];
+
+pub(crate) fn format(number: &Number, options: &OrdinalOptions) -> String {
+    format!("{} {}", options.swahili_prefix.as_str(), number)
+}
+
+/// Spells out `n` for values from 1 to 100.
+pub(crate) fn words(n: u128, options: &OrdinalOptions) -> Option<String> {
+    let number = match n {
+        1 => "kwanza".to_string(),
+        2 => "pili".to_string(),
+        3..=9 => UNITS[n as usize].to_string(),
+        10..=99 => match (TENS[(n / 10) as usize], n % 10) {
+            (tens, 0) => tens.to_string(),
+            (tens, unit) => format!("{} na {}", tens, UNITS[unit as usize]),
+        },
+        100 => "mia moja".to_string(),
+        _ => return None,
+    };
+    Some(format!("{} {}", options.swahili_prefix.as_str(), number))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::locale::SwahiliPrefix;
+    use crate::{Locale, Ordinal, OrdinalOptions};
+
+    #[test]
+    fn test_swahili_digits() {
+        assert_eq!("wa 3", Ordinal(3).to_string_with_locale(Locale::Swahili));
+        let options = OrdinalOptions::new().swahili_prefix(SwahiliPrefix::Cha);
+        assert_eq!(
+            "cha 21",
+            Ordinal(21).to_string_with_locale_opts(Locale::Swahili, &options)
+        );
+    }
+
+    #[test]
+    fn test_swahili_words() {
+        let swahili_ordinals: [&str; 12] = [
+            "kwanza",
+            "pili",
+            "tatu",
+            "nne",
+            "tano",
+            "sita",
+            "saba",
+            "nane",
+            "tisa",
+            "kumi",
+            "kumi na moja",
+            "kumi na mbili",
+        ];
+        let numbers = (1..=12).chain([20, 21, 100]);
+        let spelled = ["ishirini", "ishirini na moja", "mia moja"];
+        let expected = swahili_ordinals.iter().copied().chain(spelled);
+
+        for prefix in [SwahiliPrefix::Wa, SwahiliPrefix::Ya, SwahiliPrefix::Cha] {
+            let options = OrdinalOptions::new().swahili_prefix(prefix);
+            for (n, word) in numbers.clone().zip(expected.clone()) {
+                assert_eq!(
+                    format!("{} {}", prefix.as_str(), word),
+                    Ordinal(n)
+                        .to_words_locale(Locale::Swahili, &options)
+                        .unwrap()
+                );
+            }
+        }
+        let options = OrdinalOptions::new();
+        assert_eq!(
+            "wa kwanza",
+            Ordinal(1)
+                .to_words_locale(Locale::Swahili, &options)
+                .unwrap()
+        );
+        assert!(Ordinal(0)
+            .to_words_locale(Locale::Swahili, &options)
+            .is_err());
+        assert!(Ordinal(101)
+            .to_words_locale(Locale::Swahili, &options)
+            .is_err());
+        assert!(Ordinal(u64::MAX as u128 + 2)
+            .to_words_locale(Locale::Swahili, &options)
+            .is_err());
+    }
+}
@@ -0,0 +1,176 @@
+//! Swedish ordinals attach `:a` after numbers ending in 1 or 2, except 11 and 12,
+//! and `:e` after everything else: `1:a`, `2:a`, `3:e`, `11:e`, `21:a`.
+
+use super::{Number, OrdinalCategory};
+use alloc::format;
+use alloc::string::{String, ToString};
+
+const UNITS: [&str; 20] = [
+    "", "en", "två", "tre", "fyra", "fem", "sex", "sju", "åtta", "nio", "tio", "elva", "tolv",
+    "tretton", "fjorton", "femton", "sexton", "sjutton", "arton", "nitton",
+];
+
+const TENS: [&str; 10] = [
+    "", "", "tjugo", "trettio", "fyrtio", "femtio", "sextio", "sjuttio", "åttio", "nittio",
+];
+
+const ORDINALS: [&str; 20] = [
+    "nollte",
+    "första",
+    "andra",
+    "tredje",
+    "fjärde",
+    "femte",
+    "sjätte",
+    "sjunde",
+    "åttonde",
+    "nionde",
+    "tionde",
+    "elfte",
+    "tolfte",
+    "trettonde",
+    "fjortonde",
+    "femtonde",
+    "sextonde",
+    "sjuttonde",
+    "artonde",
+    "nittonde",
+];
+
+pub(crate) fn format(number: &Number) -> String {
+    let suffix = match category(number) {
+        OrdinalCategory::One => ":a",
+        _ => ":e",
+    };
+    format!("{}{}", number, suffix)
+}
+
+pub(crate) fn category(number: &Number) -> OrdinalCategory {
+    let last_two = number.last_two();
+    match (last_two % 10, last_two) {
+        (_, 11 | 12) => OrdinalCategory::Other,
+        (1 | 2, _) => OrdinalCategory::One,
+        _ => OrdinalCategory::Other,
+    }
+}
+
+/// Spells out `n` for values below one million.
+pub(crate) fn words(n: u128) -> Option<String> {
+    if n >= 1_000_000 {
+        return None;
+    }
+    let n = n as u32;
+    let low = n % 100;
+    let mut words = cardinal(n - low);
+    if low == 0 && n > 0 {
+        words.push_str("de");
+    } else if low < 20 {
+        words.push_str(ORDINALS[low as usize]);
+    } else {
+        words.push_str(TENS[(low / 10) as usize]);
+        match low % 10 {
+            0 => words.push_str("nde"),
+            unit => words.push_str(ORDINALS[unit as usize]),
+        }
+    }
+    Some(words)
+}
+
+fn cardinal(n: u32) -> String {
+    let thousands = n / 1000;
+    let mut words = match thousands {
+        0 => String::new(),
+        1 => "tusen".to_string(),
+        _ => below_thousand(thousands) + "tusen",
+    };
+    words.push_str(&below_thousand(n % 1000));
+    words
+}
+
+fn below_thousand(n: u32) -> String {
+    let mut words = String::new();
+    let hundreds = n / 100;
+    if hundreds > 1 {
+        words.push_str(UNITS[hundreds as usize]);
+    }
+    if hundreds > 0 {
+        words.push_str("hundra");
+    }
+    let rest = n % 100;
+    if rest < 20 {
+        words.push_str(UNITS[rest as usize]);
+    } else {
+        words.push_str(TENS[(rest / 10) as usize]);
+        words.push_str(UNITS[(rest % 10) as usize]);
+    }
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Locale, Ordinal, OrdinalOptions};
+
+    #[test]
+    fn test_swedish_digits() {
+        let swedish_ordinals: [&str; 125] = [
+            "1:a", "2:a", "3:e", "4:e", "5:e", "6:e", "7:e", "8:e", "9:e", "10:e", "11:e", "12:e",
+            "13:e", "14:e", "15:e", "16:e", "17:e", "18:e", "19:e", "20:e", "21:a", "22:a", "23:e",
+            "24:e", "25:e", "26:e", "27:e", "28:e", "29:e", "30:e", "31:a", "32:a", "33:e", "34:e",
+            "35:e", "36:e", "37:e", "38:e", "39:e", "40:e", "41:a", "42:a", "43:e", "44:e", "45:e",
+            "46:e", "47:e", "48:e", "49:e", "50:e", "51:a", "52:a", "53:e", "54:e", "55:e", "56:e",
+            "57:e", "58:e", "59:e", "60:e", "61:a", "62:a", "63:e", "64:e", "65:e", "66:e", "67:e",
+            "68:e", "69:e", "70:e", "71:a", "72:a", "73:e", "74:e", "75:e", "76:e", "77:e", "78:e",
+            "79:e", "80:e", "81:a", "82:a", "83:e", "84:e", "85:e", "86:e", "87:e", "88:e", "89:e",
+            "90:e", "91:a", "92:a", "93:e", "94:e", "95:e", "96:e", "97:e", "98:e", "99:e",
+            "100:e", "101:a", "102:a", "103:e", "104:e", "105:e", "106:e", "107:e", "108:e",
+            "109:e", "110:e", "111:e", "112:e", "113:e", "114:e", "115:e", "116:e", "117:e",
+            "118:e", "119:e", "120:e", "121:a", "122:a", "123:e", "124:e", "125:e",
+        ];
+
+        for (i, expected) in swedish_ordinals.iter().enumerate() {
+            assert_eq!(
+                *expected,
+                Ordinal(i + 1).to_string_with_locale(Locale::Swedish)
+            );
+        }
+    }
+
+    #[test]
+    fn test_swedish_teen_exceptions() {
+        let swedish = |n: u32| Ordinal(n).to_string_with_locale(Locale::Swedish);
+
+        assert_eq!("11:e", swedish(11));
+        assert_eq!("12:e", swedish(12));
+        assert_eq!("21:a", swedish(21));
+        assert_eq!("22:a", swedish(22));
+        assert_eq!("111:e", swedish(111));
+        assert_eq!("112:e", swedish(112));
+        assert_eq!("-1:a", Ordinal(-1).to_string_with_locale(Locale::Swedish));
+    }
+
+    #[test]
+    fn test_swedish_words() {
+        let options = OrdinalOptions::new();
+        let words = |n: u32| {
+            Ordinal(n)
+                .to_words_locale(Locale::Swedish, &options)
+                .unwrap()
+        };
+
+        assert_eq!("första", words(1));
+        assert_eq!("andra", words(2));
+        assert_eq!("tredje", words(3));
+        assert_eq!("fjärde", words(4));
+        assert_eq!("åttonde", words(8));
+        assert_eq!("elfte", words(11));
+        assert_eq!("tolfte", words(12));
+        assert_eq!("tjugonde", words(20));
+        assert_eq!("tjugoförsta", words(21));
+        assert_eq!("trettioandra", words(32));
+        assert_eq!("hundrade", words(100));
+        assert_eq!("hundraförsta", words(101));
+        assert_eq!("tvåhundrade", words(200));
+        assert_eq!("tusende", words(1000));
+        assert_eq!("tusenandra", words(1002));
+    }
+}
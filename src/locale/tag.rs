@@ -0,0 +1,155 @@
+//! Mapping from BCP 47 and POSIX locale names to [`Locale`].
+
+use super::Locale;
+
+impl Locale {
+    /// Returns the locale for a BCP 47 tag (`pt-BR`) or POSIX locale name
+    /// (`fr_FR.UTF-8`), matching on the language alone. Returns `None` for
+    /// unsupported languages and for the `C` and `POSIX` locales.
+    /// ```rust
+    /// use ordinal_type::Locale;
+    ///
+    /// assert_eq!(Locale::from_tag("fr_FR.UTF-8"), Some(Locale::French));
+    /// assert_eq!(Locale::from_tag("pt-BR"), Some(Locale::Portuguese));
+    /// assert_eq!(Locale::from_tag("C"), None);
+    /// ```
+    pub fn from_tag(tag: &str) -> Option<Locale> {
+        let name = tag.split(['.', '@']).next()?;
+        let language = name.split(['-', '_']).next()?.to_ascii_lowercase();
+        Some(match language.as_str() {
+            "en" => Locale::English,
+            "es" => Locale::Spanish,
+            "de" => Locale::German,
+            "nl" => Locale::Dutch,
+            "it" => Locale::Italian,
+            "pt" => Locale::Portuguese,
+            "sv" => Locale::Swedish,
+            "no" | "nb" | "nn" => Locale::Norwegian,
+            "da" => Locale::Danish,
+            "fi" => Locale::Finnish,
+            "ru" => Locale::Russian,
+            "uk" => Locale::Ukrainian,
+            "pl" => Locale::Polish,
+            "cs" => Locale::Czech,
+            "sk" => Locale::Slovak,
+            "cy" => Locale::Welsh,
+            "ga" => Locale::Irish,
+            "ca" => Locale::Catalan,
+            "ro" => Locale::Romanian,
+            "hu" => Locale::Hungarian,
+            "el" => Locale::Greek,
+            "tr" => Locale::Turkish,
+            "he" | "iw" => Locale::Hebrew,
+            "ar" => Locale::Arabic,
+            "hi" => Locale::Hindi,
+            "bn" => Locale::Bengali,
+            "ja" => Locale::Japanese,
+            "zh" => Locale::Chinese,
+            "ko" => Locale::Korean,
+            "vi" => Locale::Vietnamese,
+            "th" => Locale::Thai,
+            "id" | "in" => Locale::Indonesian,
+            "fil" | "tl" => Locale::Filipino,
+            "fa" => Locale::Persian,
+            "eo" => Locale::Esperanto,
+            "la" => Locale::Latin,
+            "fr" => Locale::French,
+            "is" => Locale::Icelandic,
+            "hr" => Locale::Croatian,
+            "sr" => Locale::Serbian,
+            "bg" => Locale::Bulgarian,
+            "lt" => Locale::Lithuanian,
+            "lv" => Locale::Latvian,
+            "et" => Locale::Estonian,
+            "sl" => Locale::Slovenian,
+            "gd" => Locale::ScottishGaelic,
+            "eu" => Locale::Basque,
+            "gl" => Locale::Galician,
+            "sw" => Locale::Swahili,
+            "ur" => Locale::Urdu,
+            "ta" => Locale::Tamil,
+            _ => return None,
+        })
+    }
+
+    /// Returns the locale of the user's messages from `LC_ALL`, `LC_MESSAGES` or `LANG`,
+    /// whichever is set first, as [`Locale::from_tag`] maps it. Needs the `std` feature.
+    #[cfg(feature = "std")]
+    pub fn from_env() -> Option<Locale> {
+        from_vars(|name| std::env::var(name).ok())
+    }
+}
+
+#[cfg(any(feature = "std", test))]
+fn from_vars(var: impl Fn(&str) -> Option<String>) -> Option<Locale> {
+    ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .into_iter()
+        .filter_map(var)
+        .find(|value| !value.is_empty())
+        .and_then(|value| Locale::from_tag(&value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::from_vars;
+    use crate::Locale;
+
+    #[test]
+    fn test_from_tag() {
+        let tags: [(&str, Option<Locale>); 12] = [
+            ("en", Some(Locale::English)),
+            ("en-US", Some(Locale::English)),
+            ("fr_FR.UTF-8", Some(Locale::French)),
+            ("pt-BR", Some(Locale::Portuguese)),
+            ("de_AT@euro", Some(Locale::German)),
+            ("nb_NO", Some(Locale::Norwegian)),
+            ("zh-Hant-TW", Some(Locale::Chinese)),
+            ("FIL", Some(Locale::Filipino)),
+            ("C", None),
+            ("POSIX", None),
+            ("C.UTF-8", None),
+            ("", None),
+        ];
+        for (tag, expected) in tags {
+            assert_eq!(expected, Locale::from_tag(tag), "{}", tag);
+        }
+    }
+
+    #[test]
+    fn test_from_vars() {
+        let vars = |values: [(&'static str, &'static str); 3]| {
+            move |name: &str| {
+                values
+                    .iter()
+                    .find(|(var, _)| *var == name)
+                    .map(|(_, value)| value.to_string())
+            }
+        };
+
+        assert_eq!(
+            Some(Locale::German),
+            from_vars(vars([
+                ("LC_ALL", "de_DE.UTF-8"),
+                ("LC_MESSAGES", "fr_FR"),
+                ("LANG", "en_US")
+            ]))
+        );
+        assert_eq!(
+            Some(Locale::French),
+            from_vars(vars([
+                ("LC_ALL", ""),
+                ("LC_MESSAGES", "fr_FR"),
+                ("LANG", "en_US")
+            ]))
+        );
+        assert_eq!(
+            Some(Locale::Polish),
+            from_vars(vars([("", ""), ("", ""), ("LANG", "pl_PL.UTF-8")]))
+        );
+        assert_eq!(
+            None,
+            from_vars(vars([("LC_ALL", "C"), ("", ""), ("LANG", "en_US")]))
+        );
+        assert_eq!(None, from_vars(vars([("", ""), ("", ""), ("", "")])));
+    }
+}
@@ -0,0 +1,154 @@
+//! Tamil ordinals add `வது` to the numeral (`3வது`), which can be written in Tamil
+//! digits with [`OrdinalOptions::digits`] (`௩வது`). First is the irregular `முதல்`
+//! unless [`TamilFirst::Regular`] is asked for.
+//!
+//! Word forms cover 1 to 20 and replace the final `-u` of the cardinal with `ஆவது`:
+//! `மூன்று` gives `மூன்றாவது`.
+
+use super::{digits, Number, OrdinalOptions};
+use alloc::format;
+use alloc::string::{String, ToString};
+
+/// The ordinal for "first" in Tamil.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum TamilFirst {
+    /// `முதல்`.
+    #[default]
+    Muthal,
+    /// `1வது`, spelled `ஒன்றாவது`.
+    Regular,
+}
+
+const CARDINALS: [&str; 21] = [
+    "",
+    "ஒன்று",
+    "இரண்டு",
+    "மூன்று",
+    "நான்கு",
+    "ஐந்து",
+    "ஆறு",
+    "ஏழு",
+    "எட்டு",
+    "ஒன்பது",
+    "பத்து",
+    "பதினொன்று",
+    "பன்னிரண்டு",
+    "பதின்மூன்று",
+    "பதினான்கு",
+    "பதினைந்து",
+    "பதினாறு",
+    "பதினேழு",
+    "பதினெட்டு",
+    "பத்தொன்பது",
+    "இருபது",
+];
+
+pub(crate) fn format(number: &Number, options: &OrdinalOptions) -> String {
+    if number.value() == Some(1) && options.tamil_first == TamilFirst::Muthal {
+        return "முதல்".to_string();
+    }
+    format!("{}வது", digits::shape(&number.to_string(), options.digits))
+}
+
+/// Spells out `n` for values from 1 to 20.
+pub(crate) fn words(n: u128, options: &OrdinalOptions) -> Option<String> {
+    match n {
+        1 if options.tamil_first == TamilFirst::Muthal => Some("முதல்".to_string()),
+        1..=20 => {
+            let cardinal = CARDINALS[n as usize];
+            // Every cardinal here ends in the vowel sign `ு`, which gives way to `ா`.
+            let stem = cardinal.strip_suffix('ு').unwrap_or(cardinal);
+            Some(format!("{}ாவது", stem))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::locale::TamilFirst;
+    use crate::{Digits, Locale, Ordinal, OrdinalOptions};
+
+    #[test]
+    fn test_tamil_digits() {
+        let regular = OrdinalOptions::new().tamil_first(TamilFirst::Regular);
+        assert_eq!("முதல்", Ordinal(1).to_string_with_locale(Locale::Tamil));
+        assert_eq!(
+            "1வது",
+            Ordinal(1).to_string_with_locale_opts(Locale::Tamil, &regular)
+        );
+        for (n, expected) in [(2, "2வது"), (3, "3வது"), (11, "11வது"), (21, "21வது")]
+        {
+            assert_eq!(expected, Ordinal(n).to_string_with_locale(Locale::Tamil));
+        }
+        assert_eq!("100வது", Ordinal(100).to_string_with_locale(Locale::Tamil));
+        assert_eq!("-1வது", Ordinal(-1).to_string_with_locale(Locale::Tamil));
+
+        let tamil = OrdinalOptions::new().digits(Digits::Tamil);
+        assert_eq!(
+            "௩வது",
+            Ordinal(3).to_string_with_locale_opts(Locale::Tamil, &tamil)
+        );
+        assert_eq!(
+            "௨௧வது",
+            Ordinal(21).to_string_with_locale_opts(Locale::Tamil, &tamil)
+        );
+        assert_eq!(
+            "௧௦௦வது",
+            Ordinal(100).to_string_with_locale_opts(Locale::Tamil, &tamil)
+        );
+        assert_eq!(
+            "௧வது",
+            Ordinal(1).to_string_with_locale_opts(
+                Locale::Tamil,
+                &tamil.clone().tamil_first(TamilFirst::Regular)
+            )
+        );
+    }
+
+    #[test]
+    fn test_tamil_words() {
+        let tamil_ordinals: [&str; 20] = [
+            "முதல்",
+            "இரண்டாவது",
+            "மூன்றாவது",
+            "நான்காவது",
+            "ஐந்தாவது",
+            "ஆறாவது",
+            "ஏழாவது",
+            "எட்டாவது",
+            "ஒன்பதாவது",
+            "பத்தாவது",
+            "பதினொன்றாவது",
+            "பன்னிரண்டாவது",
+            "பதின்மூன்றாவது",
+            "பதினான்காவது",
+            "பதினைந்தாவது",
+            "பதினாறாவது",
+            "பதினேழாவது",
+            "பதினெட்டாவது",
+            "பத்தொன்பதாவது",
+            "இருபதாவது",
+        ];
+
+        let options = OrdinalOptions::new();
+        for (i, expected) in tamil_ordinals.iter().enumerate() {
+            assert_eq!(
+                *expected,
+                Ordinal(i + 1)
+                    .to_words_locale(Locale::Tamil, &options)
+                    .unwrap()
+            );
+        }
+        let regular = OrdinalOptions::new().tamil_first(TamilFirst::Regular);
+        assert_eq!(
+            "ஒன்றாவது",
+            Ordinal(1).to_words_locale(Locale::Tamil, &regular).unwrap()
+        );
+        assert!(Ordinal(0).to_words_locale(Locale::Tamil, &options).is_err());
+        assert!(Ordinal(21)
+            .to_words_locale(Locale::Tamil, &options)
+            .is_err());
+    }
+}
@@ -0,0 +1,141 @@
+//! Thai ordinals put `ที่` before the number, in Arabic digits (`ที่ 3`), Thai digits
+//! (`ที่ ๓`) or spelled out (`ที่สาม`). Whether a space follows `ที่` varies between
+//! style guides, so it's left to [`OrdinalOptions::thai_space`].
+//!
+//! Spelled numerals use `เอ็ด` for a final one after the tens (`ยี่สิบเอ็ด`) and `ยี่`
+//! for two tens (`ยี่สิบ`). Negative values keep Arabic digits.
+
+use super::{digits, Digits, Number, OrdinalOptions};
+use alloc::format;
+use alloc::string::{String, ToString};
+
+/// Numerals for Thai ordinals.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum ThaiNumerals {
+    /// `ที่ 3`.
+    #[default]
+    Arabic,
+    /// `ที่ ๓`.
+    ThaiDigits,
+    /// `ที่สาม`.
+    Words,
+}
+
+const UNITS: [&str; 10] = [
+    "ศูนย์",
+    "หนึ่ง",
+    "สอง",
+    "สาม",
+    "สี่",
+    "ห้า",
+    "หก",
+    "เจ็ด",
+    "แปด",
+    "เก้า",
+];
+
+/// Place names from the units up to the hundred thousands.
+const PLACES: [&str; 6] = ["", "สิบ", "ร้อย", "พัน", "หมื่น", "แสน"];
+
+pub(crate) fn format(number: &Number, options: &OrdinalOptions) -> String {
+    let numeral = match (options.thai_numerals, number.value()) {
+        (ThaiNumerals::Words, Some(n)) => spell(n),
+        (ThaiNumerals::ThaiDigits, _) => digits::shape(&number.to_string(), Digits::Thai),
+        _ => number.to_string(),
+    };
+    let space = if options.thai_space { " " } else { "" };
+    format!("ที่{}{}", space, numeral)
+}
+
+fn spell(n: u128) -> String {
+    match n {
+        0 => UNITS[0].to_string(),
+        1..=999_999 => below_million(n as usize),
+        _ => {
+            let mut words = spell(n / 1_000_000);
+            words.push_str("ล้าน");
+            words.push_str(&below_million((n % 1_000_000) as usize));
+            words
+        }
+    }
+}
+
+fn below_million(n: usize) -> String {
+    let mut words = String::new();
+    for place in (0..6).rev() {
+        let digit = n / 10usize.pow(place as u32) % 10;
+        match (place, digit) {
+            (_, 0) => {}
+            (1, 1) => {}
+            (1, 2) => words.push_str("ยี่"),
+            (0, 1) if n % 100 > 10 => words.push_str("เอ็ด"),
+            _ => words.push_str(UNITS[digit]),
+        }
+        if digit > 0 {
+            words.push_str(PLACES[place]);
+        }
+    }
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::locale::ThaiNumerals;
+    use crate::{Locale, Ordinal, OrdinalOptions};
+
+    #[test]
+    fn test_thai() {
+        let values: [(u32, &str, &str); 6] = [
+            (1, "๑", "หนึ่ง"),
+            (2, "๒", "สอง"),
+            (11, "๑๑", "สิบเอ็ด"),
+            (21, "๒๑", "ยี่สิบเอ็ด"),
+            (25, "๒๕", "ยี่สิบห้า"),
+            (100, "๑๐๐", "หนึ่งร้อย"),
+        ];
+
+        let thai_digits = OrdinalOptions::new().thai_numerals(ThaiNumerals::ThaiDigits);
+        let words = OrdinalOptions::new().thai_numerals(ThaiNumerals::Words);
+        for (n, digits, spelled) in values {
+            let ordinal = Ordinal(n);
+            assert_eq!(
+                format!("ที่ {}", n),
+                ordinal.to_string_with_locale(Locale::Thai)
+            );
+            assert_eq!(
+                format!("ที่ {}", digits),
+                ordinal.to_string_with_locale_opts(Locale::Thai, &thai_digits)
+            );
+            assert_eq!(
+                format!("ที่ {}", spelled),
+                ordinal.to_string_with_locale_opts(Locale::Thai, &words)
+            );
+            assert_eq!(
+                format!("ที่{}", spelled),
+                ordinal.to_string_with_locale_opts(Locale::Thai, &words.clone().thai_space(false))
+            );
+        }
+    }
+
+    #[test]
+    fn test_thai_words_composition() {
+        let words = OrdinalOptions::new()
+            .thai_numerals(ThaiNumerals::Words)
+            .thai_space(false);
+        let format = |n: u64| Ordinal(n).to_string_with_locale_opts(Locale::Thai, &words);
+
+        assert_eq!("ที่สิบ", format(10));
+        assert_eq!("ที่ยี่สิบ", format(20));
+        assert_eq!("ที่หนึ่งร้อยหนึ่ง", format(101));
+        assert_eq!("ที่หนึ่งร้อยสิบเอ็ด", format(111));
+        assert_eq!("ที่สองพันห้าร้อยหกสิบเจ็ด", format(2567));
+        assert_eq!("ที่หนึ่งแสน", format(100_000));
+        assert_eq!("ที่หนึ่งล้าน", format(1_000_000));
+        assert_eq!("ที่สิบเอ็ดล้านยี่สิบเอ็ด", format(11_000_021));
+        assert_eq!(
+            "ที่-3",
+            Ordinal(-3).to_string_with_locale_opts(Locale::Thai, &words)
+        );
+    }
+}
@@ -0,0 +1,130 @@
+//! Turkish ordinals attach `-(i)nci` to the numeral after an apostrophe, with the
+//! vowels following the harmony of the last spoken word of the number:
+//! `1'inci` (bir), `3'üncü` (üç), `6'ncı` (altı), `40'ıncı` (kırk), `100'üncü` (yüz).
+//! The suffix loses its first vowel after a word ending in a vowel: `2'nci` (iki).
+//! The period style (`3.`) is just as common in running text.
+
+use super::{period, Number, OrdinalOptions};
+use alloc::format;
+use alloc::string::String;
+
+/// Suffix style for Turkish ordinals.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum TurkishStyle {
+    /// `1'inci`, `2'nci`, `3'üncü`.
+    #[default]
+    Suffix,
+    /// `1.`, `2.`, `3.`.
+    Period,
+}
+
+/// Suffixes after each unit word: sıfır, bir, iki, üç, dört, beş, altı, yedi, sekiz, dokuz.
+const UNITS: [&str; 10] = [
+    "ıncı", "inci", "nci", "üncü", "üncü", "inci", "ncı", "nci", "inci", "uncu",
+];
+
+/// Suffixes after each tens word: on, yirmi, otuz, kırk, elli, altmış, yetmiş, seksen, doksan.
+const TENS: [&str; 10] = [
+    "", "uncu", "nci", "uncu", "ıncı", "nci", "ıncı", "inci", "inci", "ıncı",
+];
+
+pub(crate) fn format(number: &Number, options: &OrdinalOptions) -> String {
+    match options.turkish_style {
+        TurkishStyle::Suffix => format!("{}'{}", number, suffix(number.digits())),
+        TurkishStyle::Period => period::format(number),
+    }
+}
+
+/// Picks the suffix from the last non-zero digit, which decides the last word spoken.
+fn suffix(digits: &str) -> &'static str {
+    let Some((position, digit)) = digits
+        .bytes()
+        .rev()
+        .enumerate()
+        .find(|(_, digit)| *digit != b'0')
+    else {
+        return UNITS[0];
+    };
+    match position {
+        0 => UNITS[(digit - b'0') as usize],
+        1 => TENS[(digit - b'0') as usize],
+        // yüz
+        2 => "üncü",
+        // bin
+        3..=5 => "inci",
+        // milyar
+        9..=11 => "ıncı",
+        // milyon, trilyon, katrilyon and the other -lyon scales
+        _ => "uncu",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::locale::TurkishStyle;
+    use crate::{Locale, Ordinal, OrdinalOptions};
+
+    #[test]
+    fn test_turkish_suffix() {
+        let units: [&str; 10] = [
+            "1'inci", "2'nci", "3'üncü", "4'üncü", "5'inci", "6'ncı", "7'nci", "8'inci", "9'uncu",
+            "10'uncu",
+        ];
+        for (i, expected) in units.iter().enumerate() {
+            assert_eq!(
+                *expected,
+                Ordinal(i + 1).to_string_with_locale(Locale::Turkish)
+            );
+        }
+
+        let tens: [&str; 9] = [
+            "10'uncu",
+            "20'nci",
+            "30'uncu",
+            "40'ıncı",
+            "50'nci",
+            "60'ıncı",
+            "70'inci",
+            "80'inci",
+            "90'ıncı",
+        ];
+        for (i, expected) in tens.iter().enumerate() {
+            assert_eq!(
+                *expected,
+                Ordinal((i + 1) * 10).to_string_with_locale(Locale::Turkish)
+            );
+        }
+
+        let others: [(u64, &str); 10] = [
+            (0, "0'ıncı"),
+            (21, "21'inci"),
+            (100, "100'üncü"),
+            (156, "156'ncı"),
+            (200, "200'üncü"),
+            (1000, "1000'inci"),
+            (21_000, "21000'inci"),
+            (300_000, "300000'inci"),
+            (1_000_000, "1000000'uncu"),
+            (2_000_000_000, "2000000000'ıncı"),
+        ];
+        for (n, expected) in others {
+            assert_eq!(expected, Ordinal(n).to_string_with_locale(Locale::Turkish));
+        }
+        assert_eq!(
+            "-3'üncü",
+            Ordinal(-3).to_string_with_locale(Locale::Turkish)
+        );
+    }
+
+    #[test]
+    fn test_turkish_period() {
+        let options = OrdinalOptions::new().turkish_style(TurkishStyle::Period);
+        for i in [1, 3, 21, 156, 1000] {
+            assert_eq!(
+                format!("{}.", i),
+                Ordinal(i).to_string_with_locale_opts(Locale::Turkish, &options)
+            );
+        }
+    }
+}
@@ -0,0 +1,133 @@
+//! Ukrainian abbreviates ordinals with a hyphen and the adjective ending,
+//! one letter after a vowel and two after a consonant.
+//! Unlike Russian, the feminine and neuter forms keep the consonant of the last
+//! component's stem: `1-ша` (`перша`), `2-га` (`друга`), `3-тя` (`третя`), `7-ма` (`сьома`).
+//! The prepositional case is the Ukrainian locative (`на 1-му`).
+
+use super::{hyphen, Case, Gender, Number, OrdinalCategory, OrdinalOptions};
+use alloc::format;
+use alloc::string::{String, ToString};
+
+pub(crate) fn format(number: &Number, options: &OrdinalOptions) -> String {
+    let (consonant, soft) = stem_consonant(number);
+    let ending = match (options.gender, options.case) {
+        (Gender::Feminine, Case::Nominative) => {
+            format!("{}{}", consonant, if soft { "я" } else { "а" })
+        }
+        (Gender::Feminine, Case::Accusative) => {
+            format!("{}{}", consonant, if soft { "ю" } else { "у" })
+        }
+        (Gender::Neuter, Case::Nominative | Case::Accusative) => {
+            format!("{}{}", consonant, if soft { "є" } else { "е" })
+        }
+        (Gender::Feminine, Case::Genitive) => "ї".to_string(),
+        (Gender::Feminine, Case::Instrumental) => "ю".to_string(),
+        (Gender::Feminine, _) => "й".to_string(),
+        (_, Case::Nominative | Case::Accusative) => "й".to_string(),
+        (_, Case::Genitive) => "го".to_string(),
+        (_, Case::Dative | Case::Prepositional) => "му".to_string(),
+        (_, Case::Instrumental) => "м".to_string(),
+    };
+    hyphen::format(number, &ending)
+}
+
+pub(crate) fn category(number: &Number) -> OrdinalCategory {
+    let last_two = number.last_two();
+    if last_two % 10 == 3 && last_two != 13 {
+        OrdinalCategory::Few
+    } else {
+        OrdinalCategory::Other
+    }
+}
+
+/// Returns the consonant ending the stem of the number's last component,
+/// and whether the stem is soft (`третій`).
+fn stem_consonant(number: &Number) -> (&'static str, bool) {
+    let digits = number.digits().as_bytes();
+    let last_two = number.last_two();
+    let hundreds = digits.len() >= 3 && digits[digits.len() - 3] != b'0';
+    match (last_two / 10, last_two % 10) {
+        (1, 1..=9) => ("т", false),
+        (_, 1) => ("ш", false),
+        (_, 2) => ("г", false),
+        (_, 3) => ("т", true),
+        (_, 7 | 8) => ("м", false),
+        (_, 4..=9) => ("т", false),
+        (4, 0) => ("в", false),
+        (1..=9, 0) => ("т", false),
+        _ if number.digits() == "0" => ("в", false),
+        _ if hundreds => ("т", false),
+        _ => ("н", false),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Case, Gender, Locale, Ordinal, OrdinalOptions};
+
+    #[test]
+    fn test_ukrainian_nominative() {
+        let feminine_ordinals: [&str; 20] = [
+            "1-ша", "2-га", "3-тя", "4-та", "5-та", "6-та", "7-ма", "8-ма", "9-та", "10-та",
+            "11-та", "12-та", "13-та", "14-та", "15-та", "16-та", "17-та", "18-та", "19-та",
+            "20-та",
+        ];
+        let neuter_ordinals: [&str; 20] = [
+            "1-ше", "2-ге", "3-тє", "4-те", "5-те", "6-те", "7-ме", "8-ме", "9-те", "10-те",
+            "11-те", "12-те", "13-те", "14-те", "15-те", "16-те", "17-те", "18-те", "19-те",
+            "20-те",
+        ];
+
+        let masculine = OrdinalOptions::new();
+        let feminine = OrdinalOptions::new().gender(Gender::Feminine);
+        let neuter = OrdinalOptions::new().gender(Gender::Neuter);
+        for i in 0..20 {
+            let ordinal = Ordinal(i + 1);
+            assert_eq!(
+                format!("{}-й", i + 1),
+                ordinal.to_string_with_locale_opts(Locale::Ukrainian, &masculine)
+            );
+            assert_eq!(
+                feminine_ordinals[i],
+                ordinal.to_string_with_locale_opts(Locale::Ukrainian, &feminine)
+            );
+            assert_eq!(
+                neuter_ordinals[i],
+                ordinal.to_string_with_locale_opts(Locale::Ukrainian, &neuter)
+            );
+        }
+    }
+
+    #[test]
+    fn test_ukrainian_larger_values() {
+        let feminine = OrdinalOptions::new().gender(Gender::Feminine);
+        let ukrainian =
+            |n: u32| Ordinal(n).to_string_with_locale_opts(Locale::Ukrainian, &feminine);
+
+        assert_eq!("21-ша", ukrainian(21));
+        assert_eq!("22-га", ukrainian(22));
+        assert_eq!("40-ва", ukrainian(40));
+        assert_eq!("90-та", ukrainian(90));
+        assert_eq!("100-та", ukrainian(100));
+        assert_eq!("111-та", ukrainian(111));
+        assert_eq!("1000-на", ukrainian(1000));
+        assert_eq!("2000-на", ukrainian(2000));
+    }
+
+    #[test]
+    fn test_ukrainian_cases() {
+        let ukrainian = |n: u32, gender: Gender, case: Case| {
+            let options = OrdinalOptions::new().gender(gender).case(case);
+            Ordinal(n).to_string_with_locale_opts(Locale::Ukrainian, &options)
+        };
+
+        assert_eq!("21-го", ukrainian(21, Gender::Masculine, Case::Genitive));
+        assert_eq!("1-го", ukrainian(1, Gender::Neuter, Case::Genitive));
+        assert_eq!("3-му", ukrainian(3, Gender::Masculine, Case::Dative));
+        assert_eq!("5-му", ukrainian(5, Gender::Masculine, Case::Prepositional));
+        assert_eq!("2-м", ukrainian(2, Gender::Masculine, Case::Instrumental));
+        assert_eq!("1-ї", ukrainian(1, Gender::Feminine, Case::Genitive));
+        assert_eq!("1-шу", ukrainian(1, Gender::Feminine, Case::Accusative));
+        assert_eq!("3-тю", ukrainian(3, Gender::Feminine, Case::Accusative));
+    }
+}
@@ -0,0 +1,117 @@
+//! Urdu ordinals add `واں` to the numeral (`7واں`), which agrees like an adjective:
+//! `ویں` in the feminine and in the masculine plural. The numeral can be written in
+//! the Extended Arabic-Indic digits shared with Persian through [`OrdinalOptions::digits`]
+//! (`۷واں`).
+//!
+//! Word forms cover the irregular 1–4 and 6 (`پہلا`, `دوسرا`, `تیسرا`, `چوتھا`, `چھٹا`)
+//! and the regular 5 (`پانچواں`); from 7 on the word is the cardinal plus `واں`, and
+//! the cardinals aren't spelled out here.
+
+use super::{digits, Gender, GrammaticalNumber, Number, OrdinalOptions};
+use alloc::format;
+use alloc::string::{String, ToString};
+
+const WORDS: [&str; 7] = ["", "پہلا", "دوسرا", "تیسرا", "چوتھا", "پانچواں", "چھٹا"];
+
+pub(crate) fn format(number: &Number, options: &OrdinalOptions) -> String {
+    let numeral = digits::shape(&number.to_string(), options.digits);
+    inflect(&format!("{}واں", numeral), options)
+}
+
+/// Spells out 1–6.
+pub(crate) fn words(n: u128, options: &OrdinalOptions) -> Option<String> {
+    match n {
+        1..=6 => Some(inflect(WORDS[n as usize], options)),
+        _ => None,
+    }
+}
+
+/// Turns a masculine singular form (`-ا`, `-واں`) into the requested gender and number.
+fn inflect(masculine: &str, options: &OrdinalOptions) -> String {
+    let (feminine, plural) = match masculine.strip_suffix("واں") {
+        Some(stem) => (format!("{}ویں", stem), format!("{}ویں", stem)),
+        None => {
+            let stem = masculine.strip_suffix('ا').unwrap_or(masculine);
+            (format!("{}ی", stem), format!("{}ے", stem))
+        }
+    };
+    match (options.gender, options.number) {
+        (Gender::Feminine, _) => feminine,
+        (_, GrammaticalNumber::Plural) => plural,
+        _ => masculine.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Digits, Gender, GrammaticalNumber, Locale, Ordinal, OrdinalOptions};
+
+    #[test]
+    fn test_urdu_digits() {
+        let extended = OrdinalOptions::new().digits(Digits::ExtendedArabicIndic);
+        let feminine = OrdinalOptions::new().gender(Gender::Feminine);
+        let feminine_extended = feminine.clone().digits(Digits::ExtendedArabicIndic);
+        let cases = [
+            (7, "7واں", "۷واں", "7ویں", "۷ویں"),
+            (21, "21واں", "۲۱واں", "21ویں", "۲۱ویں"),
+            (100, "100واں", "۱۰۰واں", "100ویں", "۱۰۰ویں"),
+        ];
+
+        for (n, latin, shaped, feminine_latin, feminine_shaped) in cases {
+            let ordinal = Ordinal(n);
+            assert_eq!(latin, ordinal.to_string_with_locale(Locale::Urdu));
+            assert_eq!(
+                shaped,
+                ordinal.to_string_with_locale_opts(Locale::Urdu, &extended)
+            );
+            assert_eq!(
+                feminine_latin,
+                ordinal.to_string_with_locale_opts(Locale::Urdu, &feminine)
+            );
+            assert_eq!(
+                feminine_shaped,
+                ordinal.to_string_with_locale_opts(Locale::Urdu, &feminine_extended)
+            );
+        }
+        let plural = OrdinalOptions::new().number(GrammaticalNumber::Plural);
+        assert_eq!(
+            "7ویں",
+            Ordinal(7).to_string_with_locale_opts(Locale::Urdu, &plural)
+        );
+    }
+
+    #[test]
+    fn test_urdu_words() {
+        let masculine: [&str; 6] = ["پہلا", "دوسرا", "تیسرا", "چوتھا", "پانچواں", "چھٹا"];
+        let feminine: [&str; 6] = ["پہلی", "دوسری", "تیسری", "چوتھی", "پانچویں", "چھٹی"];
+        let plural: [&str; 6] = ["پہلے", "دوسرے", "تیسرے", "چوتھے", "پانچویں", "چھٹے"];
+
+        let masculine_options = OrdinalOptions::new();
+        let feminine_options = OrdinalOptions::new().gender(Gender::Feminine);
+        let plural_options = OrdinalOptions::new().number(GrammaticalNumber::Plural);
+        for i in 0..6 {
+            let ordinal = Ordinal(i + 1);
+            assert_eq!(
+                masculine[i],
+                ordinal
+                    .to_words_locale(Locale::Urdu, &masculine_options)
+                    .unwrap()
+            );
+            assert_eq!(
+                feminine[i],
+                ordinal
+                    .to_words_locale(Locale::Urdu, &feminine_options)
+                    .unwrap()
+            );
+            assert_eq!(
+                plural[i],
+                ordinal
+                    .to_words_locale(Locale::Urdu, &plural_options)
+                    .unwrap()
+            );
+        }
+        assert!(Ordinal(7)
+            .to_words_locale(Locale::Urdu, &masculine_options)
+            .is_err());
+    }
+}
@@ -0,0 +1,138 @@
+//! Vietnamese ordinals put `thứ` before the number: `thứ ba`, `thứ 21`. The spelled
+//! forms are the cardinals except for `nhất` (1), `nhì` (2) and `tư` (4). Spelled
+//! numerals go up to 999; larger and negative values keep Arabic digits.
+//! CLDR puts only 1, the irregular `thứ nhất`, in the one category.
+
+use super::{Number, OrdinalCategory, OrdinalOptions};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// Numerals for Vietnamese ordinals.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum VietnameseNumerals {
+    /// `thứ ba`.
+    #[default]
+    Words,
+    /// `thứ 3`.
+    Arabic,
+}
+
+const UNITS: [&str; 10] = [
+    "không", "một", "hai", "ba", "bốn", "năm", "sáu", "bảy", "tám", "chín",
+];
+
+pub(crate) fn format(number: &Number, options: &OrdinalOptions) -> String {
+    let numeral = match (options.vietnamese_numerals, number.value()) {
+        (VietnameseNumerals::Words, Some(1)) => "nhất".to_string(),
+        (VietnameseNumerals::Words, Some(2)) => "nhì".to_string(),
+        (VietnameseNumerals::Words, Some(4)) => "tư".to_string(),
+        (VietnameseNumerals::Words, Some(n @ 1..=999)) => cardinal(n as usize),
+        _ => number.to_string(),
+    };
+    format!("thứ {}", numeral)
+}
+
+pub(crate) fn category(number: &Number) -> OrdinalCategory {
+    match number.magnitude() {
+        Some(1) => OrdinalCategory::One,
+        _ => OrdinalCategory::Other,
+    }
+}
+
+fn cardinal(n: usize) -> String {
+    let (hundreds, tens, units) = (n / 100, n / 10 % 10, n % 10);
+    let mut words = Vec::new();
+    if hundreds > 0 {
+        words.extend([UNITS[hundreds], "trăm"]);
+        if tens == 0 && units > 0 {
+            words.push("lẻ");
+        }
+    }
+    match tens {
+        0 => {}
+        1 => words.push("mười"),
+        _ => words.extend([UNITS[tens], "mươi"]),
+    }
+    let unit = match (tens, units) {
+        (_, 0) => return words.join(" "),
+        (2.., 1) => "mốt",
+        (2.., 4) => "tư",
+        (1.., 5) => "lăm",
+        _ => UNITS[units],
+    };
+    words.push(unit);
+    words.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::locale::VietnameseNumerals;
+    use crate::{Locale, Ordinal, OrdinalCategory, OrdinalOptions};
+
+    #[test]
+    fn test_vietnamese_words() {
+        let vietnamese: [&str; 10] = [
+            "thứ nhất",
+            "thứ nhì",
+            "thứ ba",
+            "thứ tư",
+            "thứ năm",
+            "thứ sáu",
+            "thứ bảy",
+            "thứ tám",
+            "thứ chín",
+            "thứ mười",
+        ];
+        for (i, expected) in vietnamese.iter().enumerate() {
+            assert_eq!(
+                *expected,
+                Ordinal(i + 1).to_string_with_locale(Locale::Vietnamese)
+            );
+        }
+
+        let others: [(u32, &str); 9] = [
+            (14, "thứ mười bốn"),
+            (15, "thứ mười lăm"),
+            (21, "thứ hai mươi mốt"),
+            (24, "thứ hai mươi tư"),
+            (100, "thứ một trăm"),
+            (105, "thứ một trăm lẻ năm"),
+            (110, "thứ một trăm mười"),
+            (999, "thứ chín trăm chín mươi chín"),
+            (1000, "thứ 1000"),
+        ];
+        for (n, expected) in others {
+            assert_eq!(
+                expected,
+                Ordinal(n).to_string_with_locale(Locale::Vietnamese)
+            );
+        }
+    }
+
+    #[test]
+    fn test_vietnamese_digits() {
+        let options = OrdinalOptions::new().vietnamese_numerals(VietnameseNumerals::Arabic);
+        for i in (1..=10).chain([21, 100]) {
+            assert_eq!(
+                format!("thứ {}", i),
+                Ordinal(i).to_string_with_locale_opts(Locale::Vietnamese, &options)
+            );
+        }
+    }
+
+    #[test]
+    fn test_vietnamese_category() {
+        assert_eq!(
+            OrdinalCategory::One,
+            Ordinal(1).plural_category(Locale::Vietnamese)
+        );
+        for n in [0, 2, 4, 11, 21] {
+            assert_eq!(
+                OrdinalCategory::Other,
+                Ordinal(n).plural_category(Locale::Vietnamese)
+            );
+        }
+    }
+}
@@ -0,0 +1,73 @@
+//! Welsh ordinal suffixes follow the spoken forms: `1af`, `2il`, `3ydd`, `4ydd`, `5ed`,
+//! `6ed`, `7fed` to `10fed`, then `11eg`, `12fed`, and so on through `20fed`.
+//! Above twenty the vigesimal `ar hugain` forms take `ain` (`21ain`, `30ain`) except the
+//! round `40fed`, `50fed`, `60fed`, `80fed` and `100fed`, as in conventional date writing.
+//!
+//! The CLDR ordinal categories split 0–9 five ways: zero (0, 7, 8, 9), one (1), two (2),
+//! few (3, 4) and many (5, 6); everything else is other.
+
+use super::{Number, OrdinalCategory};
+use alloc::format;
+use alloc::string::String;
+
+const SUFFIXES: [&str; 21] = [
+    "fed", "af", "il", "ydd", "ydd", "ed", "ed", "fed", "fed", "fed", "fed", "eg", "fed", "eg",
+    "eg", "fed", "eg", "eg", "fed", "eg", "fed",
+];
+
+pub(crate) fn format(number: &Number) -> String {
+    let suffix = match number.magnitude() {
+        Some(n @ 0..=20) => SUFFIXES[n as usize],
+        Some(40 | 50 | 60 | 80 | 100) => "fed",
+        _ => "ain",
+    };
+    format!("{}{}", number, suffix)
+}
+
+pub(crate) fn category(number: &Number) -> OrdinalCategory {
+    match number.magnitude() {
+        Some(0 | 7 | 8 | 9) => OrdinalCategory::Zero,
+        Some(1) => OrdinalCategory::One,
+        Some(2) => OrdinalCategory::Two,
+        Some(3 | 4) => OrdinalCategory::Few,
+        Some(5 | 6) => OrdinalCategory::Many,
+        _ => OrdinalCategory::Other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::locale::OrdinalCategory::{self, Few, Many, One, Other, Two, Zero};
+    use crate::{Locale, Ordinal};
+
+    #[test]
+    fn test_welsh_suffixes() {
+        let welsh_ordinals: [&str; 41] = [
+            "0fed", "1af", "2il", "3ydd", "4ydd", "5ed", "6ed", "7fed", "8fed", "9fed", "10fed",
+            "11eg", "12fed", "13eg", "14eg", "15fed", "16eg", "17eg", "18fed", "19eg", "20fed",
+            "21ain", "22ain", "23ain", "24ain", "25ain", "26ain", "27ain", "28ain", "29ain",
+            "30ain", "31ain", "32ain", "33ain", "34ain", "35ain", "36ain", "37ain", "38ain",
+            "39ain", "40fed",
+        ];
+
+        for (i, expected) in welsh_ordinals.iter().enumerate() {
+            assert_eq!(*expected, Ordinal(i).to_string_with_locale(Locale::Welsh));
+        }
+        assert_eq!("100fed", Ordinal(100).to_string_with_locale(Locale::Welsh));
+        assert_eq!("-1af", Ordinal(-1).to_string_with_locale(Locale::Welsh));
+    }
+
+    #[test]
+    fn test_welsh_categories() {
+        let welsh_categories: [OrdinalCategory; 41] = [
+            Zero, One, Two, Few, Few, Many, Many, Zero, Zero, Zero, Other, Other, Other, Other,
+            Other, Other, Other, Other, Other, Other, Other, Other, Other, Other, Other, Other,
+            Other, Other, Other, Other, Other, Other, Other, Other, Other, Other, Other, Other,
+            Other, Other, Other,
+        ];
+
+        for (i, expected) in welsh_categories.iter().enumerate() {
+            assert_eq!(*expected, Ordinal(i).plural_category(Locale::Welsh));
+        }
+    }
+}
@@ -0,0 +1,356 @@
+//! Parsing ordinals back from their English forms: `22nd` through [`FromStr`]
+//! and `twenty-second` through [`Ordinal::from_words`].
+
+use crate::locale::english;
+use crate::{FromInteger, Ordinal, OrdinalInteger};
+use alloc::string::{String, ToString};
+use core::fmt::{self, Display, Formatter};
+use core::str::FromStr;
+
+/// Error returned when text isn't an English ordinal.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum ParseOrdinalError {
+    /// The text is empty.
+    Empty,
+    /// The text before the suffix isn't a number of the target type.
+    InvalidNumber { text: String },
+    /// The number has no suffix, as in `22`.
+    MissingSuffix { text: String },
+    /// The suffix doesn't belong to the number, as in `2st`.
+    WrongSuffix {
+        text: String,
+        expected: &'static str,
+        found: String,
+    },
+    /// The text isn't a spelled-out English ordinal.
+    InvalidWords { text: String },
+    /// The value doesn't fit in the target type.
+    OutOfRange { text: String },
+}
+
+impl Display for ParseOrdinalError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            ParseOrdinalError::Empty => f.write_str("cannot parse an ordinal from empty text"),
+            ParseOrdinalError::InvalidNumber { text } => write!(f, "invalid number `{}`", text),
+            ParseOrdinalError::MissingSuffix { text } => {
+                write!(f, "missing ordinal suffix after `{}`", text)
+            }
+            ParseOrdinalError::WrongSuffix {
+                text,
+                expected,
+                found,
+            } => write!(
+                f,
+                "wrong suffix `{}` in `{}`, expected `{}`",
+                found, text, expected
+            ),
+            ParseOrdinalError::InvalidWords { text } => {
+                write!(f, "`{}` is not a spelled-out English ordinal", text)
+            }
+            ParseOrdinalError::OutOfRange { text } => {
+                write!(f, "`{}` is out of range for the type", text)
+            }
+        }
+    }
+}
+
+impl core::error::Error for ParseOrdinalError {}
+
+/// Parses the form produced by [`Display`], such as `22nd` or `-1st`.
+/// The suffix must match the number but may be in any case.
+/// ```rust
+/// use ordinal_type::{Ordinal, ParseOrdinalError};
+///
+/// assert_eq!("22nd".parse(), Ok(Ordinal(22u8)));
+/// assert!(matches!(
+///     "2st".parse::<Ordinal<u8>>(),
+///     Err(ParseOrdinalError::WrongSuffix { expected: "nd", .. })
+/// ));
+/// ```
+impl<T> FromStr for Ordinal<T>
+where
+    T: OrdinalInteger + FromStr,
+{
+    type Err = ParseOrdinalError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let text = text.trim();
+        if text.is_empty() {
+            return Err(ParseOrdinalError::Empty);
+        }
+        let split = text
+            .trim_end_matches(|c: char| c.is_ascii_alphabetic())
+            .len();
+        let (number, suffix) = text.split_at(split);
+        let ordinal =
+            number
+                .parse()
+                .map(Ordinal)
+                .map_err(|_| ParseOrdinalError::InvalidNumber {
+                    text: number.to_string(),
+                })?;
+        if suffix.is_empty() {
+            return Err(ParseOrdinalError::MissingSuffix {
+                text: text.to_string(),
+            });
+        }
+        let expected = ordinal.suffix();
+        if !suffix.eq_ignore_ascii_case(expected) {
+            return Err(ParseOrdinalError::WrongSuffix {
+                text: text.to_string(),
+                expected,
+                found: suffix.to_string(),
+            });
+        }
+        Ok(ordinal)
+    }
+}
+
+impl<T> Ordinal<T>
+where
+    T: OrdinalInteger + FromStr,
+{
+    /// Parses an ordinal from the front of `text` and returns it with the rest of the
+    /// text, for use inside a larger parser. The rules are those of [`FromStr`] except
+    /// that surrounding whitespace isn't skipped and anything may follow the suffix:
+    /// every digit is taken, then the suffix must match the number.
+    ///
+    /// It doesn't allocate for primitive integers unless it fails.
+    /// ```rust
+    /// use ordinal_type::Ordinal;
+    ///
+    /// assert_eq!(Ordinal::<u8>::parse_partial("3rd of June"), Ok((Ordinal(3), " of June")));
+    /// assert_eq!(Ordinal::<u8>::parse_partial("21st2"), Ok((Ordinal(21), "2")));
+    /// assert!(Ordinal::<u8>::parse_partial("June 3rd").is_err());
+    /// ```
+    pub fn parse_partial(text: &str) -> Result<(Self, &str), ParseOrdinalError> {
+        if text.is_empty() {
+            return Err(ParseOrdinalError::Empty);
+        }
+        let sign = usize::from(text.starts_with(['+', '-']));
+        let digits = text[sign..].bytes().take_while(u8::is_ascii_digit).count();
+        let (number, rest) = text.split_at(sign + digits);
+        let ordinal = match digits {
+            0 => None,
+            _ => number.parse().ok().map(Ordinal),
+        }
+        .ok_or_else(|| ParseOrdinalError::InvalidNumber {
+            text: number.to_string(),
+        })?;
+        let expected = ordinal.suffix();
+        match rest.get(..expected.len()) {
+            Some(suffix) if suffix.eq_ignore_ascii_case(expected) => {
+                Ok((ordinal, &rest[expected.len()..]))
+            }
+            _ => {
+                let letters = rest.bytes().take_while(u8::is_ascii_alphabetic).count();
+                if letters == 0 {
+                    return Err(ParseOrdinalError::MissingSuffix {
+                        text: number.to_string(),
+                    });
+                }
+                Err(ParseOrdinalError::WrongSuffix {
+                    text: text[..number.len() + letters].to_string(),
+                    expected,
+                    found: rest[..letters.min(expected.len())].to_string(),
+                })
+            }
+        }
+    }
+
+    /// Like [`Ordinal::parse_partial`], but starts at byte offset `start` of `text` and
+    /// returns the byte offset just past the ordinal.
+    /// ```rust
+    /// use ordinal_type::Ordinal;
+    ///
+    /// let text = "on the 3rd of June";
+    /// assert_eq!(Ordinal::<u8>::parse_partial_at(text, 7), Ok((Ordinal(3), 10)));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start` is past the end of `text` or not on a character boundary.
+    pub fn parse_partial_at(text: &str, start: usize) -> Result<(Self, usize), ParseOrdinalError> {
+        let (ordinal, rest) = Self::parse_partial(&text[start..])?;
+        Ok((ordinal, text.len() - rest.len()))
+    }
+}
+
+impl<T: FromInteger> Ordinal<T> {
+    /// Parses a spelled-out English ordinal, the inverse of [`Ordinal::to_words`].
+    /// Case, spaces in place of hyphens and a British `and` are accepted.
+    /// ```rust
+    /// use ordinal_type::Ordinal;
+    ///
+    /// assert_eq!(Ordinal::<u8>::from_words("twenty-second"), Ok(Ordinal(22)));
+    /// assert!(Ordinal::<u8>::from_words("two hundred fifty-sixth").is_err());
+    /// ```
+    pub fn from_words(text: &str) -> Result<Self, ParseOrdinalError> {
+        if text.trim().is_empty() {
+            return Err(ParseOrdinalError::Empty);
+        }
+        let n = english::parse_words(text).ok_or_else(|| ParseOrdinalError::InvalidWords {
+            text: text.to_string(),
+        })?;
+        T::from_u128(n)
+            .map(Ordinal)
+            .ok_or_else(|| ParseOrdinalError::OutOfRange {
+                text: text.to_string(),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Ordinal, ParseOrdinalError};
+    #[cfg(feature = "num")]
+    use num_bigint::BigInt;
+
+    #[test]
+    fn test_parse_round_trip() {
+        for i in -200i32..=1200 {
+            let ordinal = Ordinal(i);
+            assert_eq!(Ok(ordinal), ordinal.to_string().parse());
+        }
+        for i in [0u8, 1, 11, 111, 255] {
+            let ordinal = Ordinal(i);
+            assert_eq!(Ok(ordinal), ordinal.to_string().parse());
+        }
+        assert_eq!(Ok(Ordinal(3)), " 3RD ".parse::<Ordinal<u8>>());
+    }
+
+    #[cfg(feature = "num")]
+    #[test]
+    fn test_parse_big_round_trip() {
+        let big = Ordinal("123456789012345678901234567890".parse::<BigInt>().unwrap());
+        assert_eq!(Ok(big.clone()), big.to_string().parse());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let parse = |text: &str| text.parse::<Ordinal<u8>>();
+        assert_eq!(Err(ParseOrdinalError::Empty), parse(" "));
+        let invalid = |text: &str| {
+            Err(ParseOrdinalError::InvalidNumber {
+                text: text.to_string(),
+            })
+        };
+        assert_eq!(invalid(""), parse("nd"));
+        assert_eq!(invalid("256"), parse("256th"));
+        assert_eq!(invalid("3.5"), parse("3.5th"));
+        assert_eq!(
+            Err(ParseOrdinalError::MissingSuffix {
+                text: "22".to_string()
+            }),
+            parse("22")
+        );
+        assert_eq!(
+            Err(ParseOrdinalError::WrongSuffix {
+                text: "11st".to_string(),
+                expected: "th",
+                found: "st".to_string()
+            }),
+            parse("11st")
+        );
+    }
+
+    #[test]
+    fn test_parse_partial() {
+        let parse = Ordinal::<u8>::parse_partial;
+        assert_eq!(Ok((Ordinal(3), ", then")), parse("3rd, then"));
+        assert_eq!(Ok((Ordinal(22), " of June")), parse("22ND of June"));
+        assert_eq!(Ok((Ordinal(21), "2")), parse("21st2"));
+        assert_eq!(Ok((Ordinal(11), "")), parse("11th"));
+        assert_eq!(Ok((Ordinal(1), "stly")), parse("1ststly"));
+        assert_eq!(Ok((Ordinal(4), "\u{e9}")), parse("4th\u{e9}"));
+        assert_eq!(
+            Ok((Ordinal(-1), ".")),
+            Ordinal::<i8>::parse_partial("-1st.")
+        );
+
+        for i in -200i32..=1200 {
+            let text = format!("{}!", Ordinal(i));
+            assert_eq!(Ok((Ordinal(i), "!")), Ordinal::parse_partial(&text));
+        }
+    }
+
+    #[test]
+    fn test_parse_partial_errors() {
+        let parse = Ordinal::<u8>::parse_partial;
+        let invalid = |text: &str| {
+            Err(ParseOrdinalError::InvalidNumber {
+                text: text.to_string(),
+            })
+        };
+        assert_eq!(Err(ParseOrdinalError::Empty), parse(""));
+        assert_eq!(invalid(""), parse(" 3rd"));
+        assert_eq!(invalid(""), parse("third"));
+        assert_eq!(invalid("-"), parse("-rd"));
+        assert_eq!(invalid("256"), parse("256th"));
+        assert_eq!(
+            Err(ParseOrdinalError::MissingSuffix {
+                text: "22".to_string()
+            }),
+            parse("22 nd")
+        );
+        assert_eq!(
+            Err(ParseOrdinalError::WrongSuffix {
+                text: "11stuff".to_string(),
+                expected: "th",
+                found: "st".to_string()
+            }),
+            parse("11stuff")
+        );
+        assert_eq!(
+            Err(ParseOrdinalError::WrongSuffix {
+                text: "2n".to_string(),
+                expected: "nd",
+                found: "n".to_string()
+            }),
+            parse("2n")
+        );
+        assert_eq!(
+            Err(ParseOrdinalError::MissingSuffix {
+                text: "2".to_string()
+            }),
+            parse("2\u{e9}")
+        );
+    }
+
+    #[test]
+    fn test_parse_partial_at() {
+        let text = "from the 2nd to the 13th.";
+        let parse = |start| Ordinal::<u8>::parse_partial_at(text, start);
+        assert_eq!(Ok((Ordinal(2), 12)), parse(9));
+        assert_eq!(Ok((Ordinal(13), 24)), parse(20));
+        assert_eq!(&text[20..24], "13th");
+        assert!(parse(0).is_err());
+        assert_eq!(Err(ParseOrdinalError::Empty), parse(text.len()));
+    }
+
+    #[test]
+    fn test_from_words() {
+        for i in 0u16..=1200 {
+            let ordinal = Ordinal(i);
+            assert_eq!(
+                Ok(ordinal),
+                Ordinal::from_words(&ordinal.to_words().unwrap())
+            );
+        }
+        assert_eq!(
+            Err(ParseOrdinalError::OutOfRange {
+                text: "two hundred fifty-sixth".to_string()
+            }),
+            Ordinal::<u8>::from_words("two hundred fifty-sixth")
+        );
+        assert_eq!(
+            Err(ParseOrdinalError::InvalidWords {
+                text: "second first".to_string()
+            }),
+            Ordinal::<u8>::from_words("second first")
+        );
+        assert_eq!(Err(ParseOrdinalError::Empty), Ordinal::<u8>::from_words(""));
+    }
+}
@@ -0,0 +1,118 @@
+//! `ToSql` and `FromSql` for `Ordinal<i16>`, `Ordinal<i32>` and `Ordinal<i64>`,
+//! stored in `SMALLINT`, `INT` and `BIGINT` columns exactly like the bare integers.
+
+use crate::Ordinal;
+use postgres_types::{to_sql_checked, FromSql, IsNull, ToSql, Type};
+use std::error::Error;
+
+type BoxedError = Box<dyn Error + Sync + Send>;
+
+macro_rules! impl_sql_for_ordinals {
+    ($($t:ty),*) => {
+        $(
+            impl ToSql for Ordinal<$t> {
+                fn to_sql(
+                    &self,
+                    ty: &Type,
+                    out: &mut bytes::BytesMut,
+                ) -> Result<IsNull, BoxedError> {
+                    self.0.to_sql(ty, out)
+                }
+
+                fn accepts(ty: &Type) -> bool {
+                    <$t as ToSql>::accepts(ty)
+                }
+
+                to_sql_checked!();
+            }
+
+            impl<'a> FromSql<'a> for Ordinal<$t> {
+                fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, BoxedError> {
+                    <$t>::from_sql(ty, raw).map(Ordinal)
+                }
+
+                fn accepts(ty: &Type) -> bool {
+                    <$t as FromSql>::accepts(ty)
+                }
+            }
+        )*
+    };
+}
+
+impl_sql_for_ordinals!(i16, i32, i64);
+
+#[cfg(test)]
+mod tests {
+    use crate::Ordinal;
+    use bytes::BytesMut;
+    use postgres_types::{FromSql, ToSql, Type};
+
+    #[test]
+    fn test_postgres_round_trip() {
+        let mut buf = BytesMut::new();
+        Ordinal(-3i16)
+            .to_sql_checked(&Type::INT2, &mut buf)
+            .unwrap();
+        assert_eq!(&(-3i16).to_be_bytes()[..], &buf[..]);
+        assert_eq!(
+            Ordinal(-3),
+            Ordinal::<i16>::from_sql(&Type::INT2, &buf).unwrap()
+        );
+
+        let mut buf = BytesMut::new();
+        Ordinal(21i32)
+            .to_sql_checked(&Type::INT4, &mut buf)
+            .unwrap();
+        assert_eq!(
+            Ordinal(21),
+            Ordinal::<i32>::from_sql(&Type::INT4, &buf).unwrap()
+        );
+
+        let mut buf = BytesMut::new();
+        Ordinal(i64::MAX)
+            .to_sql_checked(&Type::INT8, &mut buf)
+            .unwrap();
+        assert_eq!(
+            Ordinal(i64::MAX),
+            Ordinal::<i64>::from_sql(&Type::INT8, &buf).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_postgres_accepts() {
+        for ty in [
+            Type::INT2,
+            Type::INT4,
+            Type::INT8,
+            Type::TEXT,
+            Type::NUMERIC,
+        ] {
+            assert_eq!(
+                <i16 as ToSql>::accepts(&ty),
+                <Ordinal<i16> as ToSql>::accepts(&ty)
+            );
+            assert_eq!(
+                <i32 as ToSql>::accepts(&ty),
+                <Ordinal<i32> as ToSql>::accepts(&ty)
+            );
+            assert_eq!(
+                <i64 as ToSql>::accepts(&ty),
+                <Ordinal<i64> as ToSql>::accepts(&ty)
+            );
+            assert_eq!(
+                <i32 as FromSql>::accepts(&ty),
+                <Ordinal<i32> as FromSql>::accepts(&ty)
+            );
+        }
+        let mut buf = BytesMut::new();
+        assert!(Ordinal(1i32).to_sql_checked(&Type::INT8, &mut buf).is_err());
+    }
+
+    #[test]
+    fn test_postgres_errors_match_primitive() {
+        let raw = 70_000i32.to_be_bytes();
+        let primitive = i16::from_sql(&Type::INT2, &raw).unwrap_err();
+        let ordinal = Ordinal::<i16>::from_sql(&Type::INT2, &raw).unwrap_err();
+        assert_eq!(primitive.to_string(), ordinal.to_string());
+    }
+}
@@ -0,0 +1,177 @@
+//! Ordinals of the `primitive-types` wide integers, `U256` and `U512`.
+//!
+//! These don't implement `num_integer::Integer`, and since the blanket `Display` impl is
+//! over every `T: Integer` the crate can't add another `Display` impl for them: num could
+//! implement `Integer` for them one day, and the two would overlap. The formatting is
+//! provided by the [`WideOrdinal`] trait instead, through [`WideOrdinal::display`].
+//!
+//! ```rust
+//! use ordinal_type::{ToOrdinal, WideOrdinal};
+//! use primitive_types::U256;
+//!
+//! let ordinal = U256::from(22).to_ordinal();
+//! assert_eq!(ordinal.suffix(), "nd");
+//! assert_eq!(ordinal.display().to_string(), "22nd");
+//! assert_eq!(ordinal.try_to_u128(), Ok(22));
+//! ```
+
+use crate::locale::english;
+use crate::{Ordinal, ToOrdinal};
+use core::fmt::{self, Display, Formatter};
+use primitive_types::{U256, U512};
+
+/// The error returned when a wide ordinal doesn't fit in the requested primitive.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct WideOverflow;
+
+impl Display for WideOverflow {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("ordinal out of range for the type")
+    }
+}
+
+impl core::error::Error for WideOverflow {}
+
+/// Suffix, formatting and conversions for ordinals of `U256` and `U512`.
+pub trait WideOrdinal {
+    /// Returns the English suffix, computed from the value modulo 100.
+    fn suffix(&self) -> &'static str;
+
+    /// Returns a value that formats as the number followed by its suffix, like `Display`
+    /// does for `Ordinal` of the primitive integers.
+    fn display(&self) -> WideDisplay<'_, Self> {
+        WideDisplay(self)
+    }
+
+    /// Writes the number followed by its suffix.
+    fn fmt_ordinal(&self, f: &mut Formatter) -> fmt::Result;
+
+    /// Returns the value as a `u64`, or an error if it doesn't fit.
+    fn try_to_u64(&self) -> Result<u64, WideOverflow>;
+
+    /// Returns the value as a `u128`, or an error if it doesn't fit.
+    fn try_to_u128(&self) -> Result<u128, WideOverflow>;
+}
+
+/// Formats a wide ordinal, returned by [`WideOrdinal::display`].
+#[derive(Copy, Clone, Debug)]
+pub struct WideDisplay<'a, T: ?Sized>(&'a T);
+
+impl<T: WideOrdinal + ?Sized> Display for WideDisplay<'_, T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.0.fmt_ordinal(f)
+    }
+}
+
+macro_rules! impl_wide_ordinal {
+    ($($t:ty),*) => {
+        $(
+            impl WideOrdinal for Ordinal<$t> {
+                fn suffix(&self) -> &'static str {
+                    english::suffix((self.0 % <$t>::from(100u64)).low_u64() as u8)
+                }
+
+                fn fmt_ordinal(&self, f: &mut Formatter) -> fmt::Result {
+                    write!(f, "{}{}", self.0, WideOrdinal::suffix(self))
+                }
+
+                fn try_to_u64(&self) -> Result<u64, WideOverflow> {
+                    u64::try_from(self.0).map_err(|_| WideOverflow)
+                }
+
+                fn try_to_u128(&self) -> Result<u128, WideOverflow> {
+                    u128::try_from(self.0).map_err(|_| WideOverflow)
+                }
+            }
+
+            impl ToOrdinal for $t {
+                fn to_ordinal(&self) -> Ordinal<$t> {
+                    Ordinal(*self)
+                }
+            }
+        )*
+    };
+}
+
+impl_wide_ordinal!(U256, U512);
+
+#[cfg(test)]
+mod tests {
+    use crate::{Ordinal, ToOrdinal, WideOrdinal, WideOverflow};
+    use primitive_types::{U256, U512};
+
+    #[test]
+    fn test_wide_suffix() {
+        let cases = [
+            (0u64, "th"),
+            (1, "st"),
+            (2, "nd"),
+            (3, "rd"),
+            (11, "th"),
+            (12, "th"),
+            (13, "th"),
+            (21, "st"),
+            (111, "th"),
+            (122, "nd"),
+        ];
+        for (value, suffix) in cases {
+            assert_eq!(Ordinal(U256::from(value)).suffix(), suffix);
+            assert_eq!(Ordinal(U512::from(value)).suffix(), suffix);
+        }
+    }
+
+    #[test]
+    fn test_wide_near_u128_max() {
+        // 340282366920938463463374607431768211455
+        let max = Ordinal(U256::from(u128::MAX));
+        assert_eq!(max.suffix(), "th");
+        assert_eq!(
+            max.display().to_string(),
+            "340282366920938463463374607431768211455th"
+        );
+        assert_eq!(max.try_to_u128(), Ok(u128::MAX));
+        assert_eq!(max.try_to_u64(), Err(WideOverflow));
+
+        let above = Ordinal(U256::from(u128::MAX) + 1);
+        assert_eq!(
+            above.display().to_string(),
+            "340282366920938463463374607431768211456th"
+        );
+        assert_eq!(above.try_to_u128(), Err(WideOverflow));
+
+        let below = Ordinal(U256::from(u128::MAX) - 3);
+        assert_eq!(below.suffix(), "nd");
+        assert_eq!(below.try_to_u128(), Ok(u128::MAX - 3));
+    }
+
+    #[test]
+    fn test_wide_hundreds_of_bits() {
+        let max = U256::MAX.to_ordinal();
+        assert_eq!(
+            max.display().to_string(),
+            "115792089237316195423570985008687907853269984665640564039457584007913129639935th"
+        );
+        let two = (U256::from(1) << 200) + 2;
+        assert_eq!(two.to_ordinal().suffix(), "th");
+        assert_eq!(
+            two.to_ordinal().display().to_string(),
+            "1606938044258990275541962092341162602522202993782792835301378th"
+        );
+        let wide = (U512::from(10).pow(U512::from(150))) + 23;
+        assert_eq!(wide.to_ordinal().suffix(), "rd");
+        assert!(wide.to_ordinal().display().to_string().ends_with("00023rd"));
+        assert_eq!(wide.to_ordinal().try_to_u128(), Err(WideOverflow));
+        assert_eq!(
+            U512::MAX.to_ordinal().display().to_string().len(),
+            U512::MAX.to_string().len() + 2
+        );
+    }
+
+    #[test]
+    fn test_wide_overflow_display() {
+        assert_eq!(
+            WideOverflow.to_string(),
+            "ordinal out of range for the type"
+        );
+    }
+}
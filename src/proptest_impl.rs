@@ -0,0 +1,78 @@
+//! proptest support: `any::<Ordinal<T>>()` for any `T: Arbitrary`, and [`ordinal_in`]
+//! to restrict the inner value to a range. Both shrink through the inner strategy,
+//! so failing cases shrink toward the low end of the range.
+
+use crate::Ordinal;
+use proptest::arbitrary::Arbitrary;
+use proptest::strategy::{Map, Strategy};
+
+/// Strategy wrapping the values of `S` in `Ordinal`.
+pub type OrdinalStrategy<S> = Map<S, fn(<S as Strategy>::Value) -> Ordinal<<S as Strategy>::Value>>;
+
+impl<T: Arbitrary> Arbitrary for Ordinal<T> {
+    type Parameters = T::Parameters;
+    type Strategy = OrdinalStrategy<T::Strategy>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        T::arbitrary_with(args).prop_map(Ordinal)
+    }
+}
+
+/// Generates ordinals whose inner value comes from `range`, or any other strategy.
+/// ```rust
+/// use ordinal_type::ordinal_in;
+/// use proptest::prelude::*;
+///
+/// proptest!(|(rank in ordinal_in(1u32..=100))| {
+///     prop_assert!(rank.0 >= 1 && rank.0 <= 100);
+/// });
+/// ```
+pub fn ordinal_in<S: Strategy>(range: S) -> OrdinalStrategy<S> {
+    range.prop_map(Ordinal)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ordinal_in, Ordinal};
+    use proptest::prelude::*;
+    use proptest::strategy::ValueTree;
+    use proptest::test_runner::TestRunner;
+
+    proptest! {
+        #[test]
+        fn test_suffix_rules(ordinal in any::<Ordinal<u64>>()) {
+            let expected = match (ordinal.0 % 10, ordinal.0 % 100) {
+                (_, 11..=13) => "th",
+                (1, _) => "st",
+                (2, _) => "nd",
+                (3, _) => "rd",
+                _ => "th",
+            };
+            prop_assert_eq!(expected, ordinal.suffix());
+            prop_assert_eq!(format!("{}{}", ordinal.0, expected), ordinal.to_string());
+        }
+
+        #[test]
+        fn test_signed_suffix_matches_magnitude(ordinal in any::<Ordinal<i32>>()) {
+            prop_assert_eq!(Ordinal(ordinal.0.unsigned_abs()).suffix(), ordinal.suffix());
+        }
+
+        #[test]
+        fn test_ordinal_in(rank in ordinal_in(1u16..=500)) {
+            prop_assert!((1..=500).contains(&rank.0));
+            prop_assert_eq!(Ok(rank), rank.to_string().parse());
+        }
+    }
+
+    #[test]
+    fn test_shrinks_toward_range_start() {
+        let mut runner = TestRunner::deterministic();
+        let mut tree = ordinal_in(1u32..1000).new_tree(&mut runner).unwrap();
+        while tree.simplify() {}
+        assert_eq!(Ordinal(1), tree.current());
+
+        let mut tree = any::<Ordinal<u8>>().new_tree(&mut runner).unwrap();
+        while tree.simplify() {}
+        assert_eq!(Ordinal(0), tree.current());
+    }
+}
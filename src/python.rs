@@ -0,0 +1,159 @@
+//! A PyO3 module, so Python code formats ordinals with exactly the rules used here.
+//! Build it as an extension module with maturin, or call [`ordinal_type`] from Rust
+//! to embed it.
+//!
+//! ```python
+//! import ordinal_type
+//!
+//! ordinal_type.format(22)              # "22nd"
+//! ordinal_type.format(10**30 + 3)      # "1000000000000000000000000000003rd"
+//! ordinal_type.to_words(101)           # "one hundred first"
+//! ordinal_type.format_locale(1, "fr")  # "1er"
+//! ordinal_type.format_locale(1, "xx")  # raises ValueError
+//! ```
+//!
+//! Python ints have no fixed width. Those that fit an `i64` are formatted directly, and
+//! any other goes through `BigInt`, so no value overflows.
+
+use crate::{Locale, Ordinal};
+use num_bigint::BigInt;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+/// A Python int, read as an `i64` when it fits.
+#[derive(FromPyObject)]
+enum Int {
+    Small(i64),
+    Big(BigInt),
+}
+
+/// Evaluates `$body` with `$ordinal` bound to `Ordinal` of whichever width `$n` holds.
+macro_rules! with_ordinal {
+    ($n:expr, |$ordinal:ident| $body:expr) => {
+        match $n {
+            Int::Small(n) => {
+                let $ordinal = Ordinal(n);
+                $body
+            }
+            Int::Big(n) => {
+                let $ordinal = Ordinal(n);
+                $body
+            }
+        }
+    };
+}
+
+/// Returns the English suffix of `n`, like `"nd"` for 22.
+#[pyfunction]
+fn suffix(n: Int) -> &'static str {
+    with_ordinal!(n, |ordinal| ordinal.suffix())
+}
+
+/// Formats `n` as an English ordinal, like `"22nd"`.
+#[pyfunction]
+fn format(n: Int) -> String {
+    with_ordinal!(n, |ordinal| ordinal.to_string())
+}
+
+/// Spells out `n` as an English ordinal, like `"twenty-second"`. Raises `ValueError`
+/// for values English has no word form for, like negative ones.
+#[pyfunction]
+fn to_words(n: Int) -> PyResult<String> {
+    with_ordinal!(n, |ordinal| ordinal.to_words())
+        .map_err(|error| PyValueError::new_err(error.to_string()))
+}
+
+/// Formats `n` as an ordinal in the locale of a BCP 47 tag like `"fr"` or `"pt-BR"`.
+/// Raises `ValueError` for a tag naming no supported locale.
+#[pyfunction]
+fn format_locale(n: Int, locale: &str) -> PyResult<String> {
+    let locale = Locale::from_tag(locale)
+        .ok_or_else(|| PyValueError::new_err(format!("unsupported locale `{}`", locale)))?;
+    Ok(with_ordinal!(n, |ordinal| ordinal.to_string_with_locale(locale)))
+}
+
+/// The `ordinal_type` Python module.
+#[pymodule]
+pub fn ordinal_type(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_function(wrap_pyfunction!(suffix, module)?)?;
+    module.add_function(wrap_pyfunction!(format, module)?)?;
+    module.add_function(wrap_pyfunction!(to_words, module)?)?;
+    module.add_function(wrap_pyfunction!(format_locale, module)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use pyo3::exceptions::PyValueError;
+    use pyo3::prelude::*;
+    use pyo3::types::PyModule;
+
+    /// Calls `function` of the module on the Python value of `argument`, an expression.
+    fn call(function: &str, argument: &str, locale: Option<&str>) -> PyResult<String> {
+        Python::initialize();
+        Python::attach(|py| {
+            let module = PyModule::new(py, "ordinal_type")?;
+            super::ordinal_type(&module)?;
+            let code = std::ffi::CString::new(argument).unwrap();
+            let n = py.eval(&code, None, None)?;
+            let function = module.getattr(function)?;
+            let result = match locale {
+                Some(locale) => function.call1((n, locale))?,
+                None => function.call1((n,))?,
+            };
+            result.extract()
+        })
+    }
+
+    #[test]
+    fn test_small_ints() {
+        assert_eq!("nd", call("suffix", "22", None).unwrap());
+        assert_eq!("-11th", call("format", "-11", None).unwrap());
+        assert_eq!(
+            "-9223372036854775808th",
+            call("format", "-2**63", None).unwrap()
+        );
+        assert_eq!("twenty-second", call("to_words", "22", None).unwrap());
+        assert_eq!("1er", call("format_locale", "1", Some("fr-FR")).unwrap());
+    }
+
+    #[test]
+    fn test_big_ints() {
+        let hundred_digits = format!("1{}", "0".repeat(98));
+        assert_eq!(
+            format!("{}2nd", hundred_digits),
+            call("format", &format!("{}2", hundred_digits), None).unwrap()
+        );
+        assert_eq!(
+            "rd",
+            call("suffix", &format!("-{}3", hundred_digits), None).unwrap()
+        );
+        assert_eq!(
+            "18446744073709551616th",
+            call("format", "2**64", None).unwrap()
+        );
+        assert_eq!(
+            format!("-{}13.º", hundred_digits),
+            call(
+                "format_locale",
+                &format!("-{}13", hundred_digits),
+                Some("es")
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_value_errors() {
+        Python::initialize();
+        let is_value_error =
+            |error: PyErr| Python::attach(|py| error.is_instance_of::<PyValueError>(py));
+        let error = call("format_locale", "1", Some("xx-YY")).unwrap_err();
+        assert!(is_value_error(error));
+        let error = call("to_words", "-3", None).unwrap_err();
+        assert!(is_value_error(error));
+        // A float isn't an int, so it's a TypeError instead.
+        let error = call("format", "1.5", None).unwrap_err();
+        assert!(!is_value_error(error));
+    }
+}
@@ -0,0 +1,290 @@
+//! Ordinals written in other bases, like `0x1Fst`. The suffix always follows the
+//! decimal value, so 31 is `0x1Fst` even though its last hex digit is an F.
+
+use crate::{FromInteger, Ordinal, OrdinalInteger, ParseOrdinalError};
+use alloc::string::{String, ToString};
+use core::num::IntErrorKind;
+
+/// Returns the conventional prefix for `radix`, if it has one.
+fn prefix_for(radix: u32) -> Option<&'static str> {
+    match radix {
+        2 => Some("0b"),
+        8 => Some("0o"),
+        16 => Some("0x"),
+        _ => None,
+    }
+}
+
+impl<T: OrdinalInteger> Ordinal<T> {
+    /// Writes the number in `radix`, from 2 to 36, with lowercase digits and the suffix
+    /// of its decimal value. With `prefix`, binary, octal and hex numbers get `0b`, `0o`
+    /// or `0x` after any sign. Other radices have no prefix, so it's ignored for them.
+    /// This allocates the returned `String`.
+    /// ```rust
+    /// use ordinal_type::Ordinal;
+    ///
+    /// assert_eq!(Ordinal(26).to_string_radix(16, true), "0x1ath");
+    /// assert_eq!(Ordinal(-3).to_string_radix(2, true), "-0b11rd");
+    /// assert_eq!(Ordinal(22).to_string_radix(8, false), "26nd");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is outside 2 to 36.
+    pub fn to_string_radix(&self, radix: u32, prefix: bool) -> String {
+        self.radix_string(radix, prefix, false)
+    }
+
+    /// Like [`Ordinal::to_string_radix`], but with uppercase digits. The prefix and the
+    /// suffix stay lowercase.
+    /// ```rust
+    /// use ordinal_type::Ordinal;
+    ///
+    /// assert_eq!(Ordinal(31).to_string_radix_upper(16, true), "0x1Fst");
+    /// assert_eq!(Ordinal(255u8).to_string_radix_upper(16, false), "FFth");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is outside 2 to 36.
+    pub fn to_string_radix_upper(&self, radix: u32, prefix: bool) -> String {
+        self.radix_string(radix, prefix, true)
+    }
+
+    fn radix_string(&self, radix: u32, prefix: bool, upper: bool) -> String {
+        assert!(
+            (2..=36).contains(&radix),
+            "radix must be from 2 to 36, got {}",
+            radix
+        );
+        let digits = self.0.radix_digits(radix);
+        let mut text = String::with_capacity(digits.len() + 5);
+        if self.0.is_negative() {
+            text.push('-');
+        }
+        if prefix {
+            text.push_str(prefix_for(radix).unwrap_or(""));
+        }
+        for &digit in digits.iter().rev() {
+            let c = char::from_digit(u32::from(digit), radix).expect("digit below the radix");
+            text.push(if upper { c.to_ascii_uppercase() } else { c });
+        }
+        text.push_str(self.suffix());
+        text
+    }
+}
+
+impl<T: OrdinalInteger + FromInteger> Ordinal<T> {
+    /// Parses the form written by [`Ordinal::to_string_radix`], such as `0x1Fst`. The
+    /// digits, prefix and suffix may be in any case, and the prefix is optional. The
+    /// suffix is always the last two letters and must match the decimal value. Values
+    /// past 128 bits are out of range, even for `BigInt`.
+    /// ```rust
+    /// use ordinal_type::{Ordinal, ParseOrdinalError};
+    ///
+    /// assert_eq!(Ordinal::from_str_radix("0x1Ath", 16), Ok(Ordinal(26u8)));
+    /// assert_eq!(Ordinal::from_str_radix("-11rd", 2), Ok(Ordinal(-3i8)));
+    /// assert!(matches!(
+    ///     Ordinal::<u8>::from_str_radix("0x1Ast", 16),
+    ///     Err(ParseOrdinalError::WrongSuffix { expected: "th", .. })
+    /// ));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is outside 2 to 36.
+    pub fn from_str_radix(text: &str, radix: u32) -> Result<Self, ParseOrdinalError> {
+        assert!(
+            (2..=36).contains(&radix),
+            "radix must be from 2 to 36, got {}",
+            radix
+        );
+        let text = text.trim();
+        if text.is_empty() {
+            return Err(ParseOrdinalError::Empty);
+        }
+        let invalid = || ParseOrdinalError::InvalidNumber {
+            text: text.to_string(),
+        };
+        let out_of_range = || ParseOrdinalError::OutOfRange {
+            text: text.to_string(),
+        };
+        let (negative, unsigned) = match text.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, text.strip_prefix('+').unwrap_or(text)),
+        };
+        let unsigned = match prefix_for(radix) {
+            // `get` rather than indexing, as the second byte may be inside a character.
+            Some(prefix)
+                if unsigned
+                    .get(..2)
+                    .is_some_and(|start| start.eq_ignore_ascii_case(prefix)) =>
+            {
+                &unsigned[2..]
+            }
+            _ => unsigned,
+        };
+        if !unsigned.starts_with(|c: char| c.is_ascii_alphanumeric()) {
+            return Err(invalid());
+        }
+        let split = unsigned.len().saturating_sub(2);
+        let (digits, suffix) = match unsigned.get(split..) {
+            Some(suffix)
+                if ["st", "nd", "rd", "th"]
+                    .iter()
+                    .any(|known| suffix.eq_ignore_ascii_case(known)) =>
+            {
+                (&unsigned[..split], suffix)
+            }
+            _ => {
+                return Err(match u128::from_str_radix(unsigned, radix) {
+                    Ok(_) => ParseOrdinalError::MissingSuffix {
+                        text: text.to_string(),
+                    },
+                    Err(_) => invalid(),
+                })
+            }
+        };
+        let magnitude =
+            u128::from_str_radix(digits, radix).map_err(|error| match error.kind() {
+                IntErrorKind::PosOverflow => out_of_range(),
+                _ => invalid(),
+            })?;
+        let value = if negative {
+            0i128.checked_sub_unsigned(magnitude).and_then(T::from_i128)
+        } else {
+            T::from_u128(magnitude)
+        };
+        let ordinal = value.map(Ordinal).ok_or_else(out_of_range)?;
+        let expected = ordinal.suffix();
+        if !suffix.eq_ignore_ascii_case(expected) {
+            return Err(ParseOrdinalError::WrongSuffix {
+                text: text.to_string(),
+                expected,
+                found: suffix.to_string(),
+            });
+        }
+        Ok(ordinal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Ordinal, ParseOrdinalError};
+
+    #[test]
+    fn test_suffix_follows_decimal_value() {
+        // The last hex digit would pick a different suffix in each of these.
+        assert_eq!("0x1ath", Ordinal(26).to_string_radix(16, true));
+        assert_eq!("0x1Fst", Ordinal(31).to_string_radix_upper(16, true));
+        assert_eq!("0xbth", Ordinal(11).to_string_radix(16, true));
+        assert_eq!("0x15st", Ordinal(21).to_string_radix(16, true));
+        assert_eq!("0b10nd", Ordinal(2).to_string_radix(2, true));
+        assert_eq!("0b1011th", Ordinal(11).to_string_radix(2, true));
+        assert_eq!("0o27rd", Ordinal(23).to_string_radix(8, true));
+        assert_eq!("0o15th", Ordinal(13).to_string_radix(8, true));
+    }
+
+    #[test]
+    fn test_radix_extremes() {
+        assert_eq!("0th", Ordinal(0).to_string_radix(16, false));
+        assert_eq!("-0x80th", Ordinal(i8::MIN).to_string_radix(16, true));
+        assert_eq!(
+            format!("0x{}th", "f".repeat(32)),
+            Ordinal(u128::MAX).to_string_radix(16, true)
+        );
+        assert_eq!(
+            format!("-0b1{}th", "0".repeat(127)),
+            Ordinal(i128::MIN).to_string_radix(2, true)
+        );
+        assert_eq!("zth", Ordinal(35).to_string_radix(36, false));
+    }
+
+    #[test]
+    fn test_radix_round_trip() {
+        for i in -1000i32..=1000 {
+            let ordinal = Ordinal(i);
+            for radix in [2, 8, 16] {
+                for prefix in [false, true] {
+                    let lower = ordinal.to_string_radix(radix, prefix);
+                    let upper = ordinal.to_string_radix_upper(radix, prefix);
+                    assert_eq!(Ok(ordinal), Ordinal::from_str_radix(&lower, radix));
+                    assert_eq!(Ok(ordinal), Ordinal::from_str_radix(&upper, radix));
+                }
+            }
+        }
+        for value in [i128::MIN, i128::MAX] {
+            let text = Ordinal(value).to_string_radix(16, true);
+            assert_eq!(Ok(Ordinal(value)), Ordinal::from_str_radix(&text, 16));
+        }
+        let text = Ordinal(u128::MAX).to_string_radix(2, true);
+        assert_eq!(Ok(Ordinal(u128::MAX)), Ordinal::from_str_radix(&text, 2));
+    }
+
+    #[test]
+    fn test_from_str_radix_errors() {
+        let parse = |text: &str| Ordinal::<u8>::from_str_radix(text, 16);
+        assert_eq!(Ok(Ordinal(26)), parse(" 0X1ATH "));
+        assert_eq!(Err(ParseOrdinalError::Empty), parse(""));
+        let invalid = |text: &str| {
+            Err(ParseOrdinalError::InvalidNumber {
+                text: text.to_string(),
+            })
+        };
+        let out_of_range = |text: &str| {
+            Err(ParseOrdinalError::OutOfRange {
+                text: text.to_string(),
+            })
+        };
+        assert_eq!(
+            Err(ParseOrdinalError::MissingSuffix {
+                text: "0x1a".to_string()
+            }),
+            parse("0x1a")
+        );
+        assert_eq!(invalid("0xth"), parse("0xth"));
+        assert_eq!(invalid("0x1gth"), parse("0x1gth"));
+        assert_eq!(invalid("--1st"), parse("--1st"));
+        assert_eq!(out_of_range("0x100th"), parse("0x100th"));
+        assert_eq!(out_of_range("-0x1st"), parse("-0x1st"));
+        let wide = format!("0x1{}th", "0".repeat(32));
+        assert_eq!(out_of_range(&wide), parse(&wide));
+        assert_eq!(
+            Err(ParseOrdinalError::WrongSuffix {
+                text: "0x1ast".to_string(),
+                expected: "th",
+                found: "st".to_string()
+            }),
+            parse("0x1ast")
+        );
+        // Found by fuzzing: a character straddling the end of where a prefix would be.
+        assert_eq!(invalid("1\u{2f8}"), parse("1\u{2f8}"));
+        assert_eq!(invalid("0\u{e9}1st"), parse("0\u{e9}1st"));
+        assert_eq!(Ok(Ordinal(5)), Ordinal::<u8>::from_str_radix("0b101th", 2));
+        assert_eq!(
+            invalid("0b102th"),
+            Ordinal::<u8>::from_str_radix("0b102th", 2)
+        );
+    }
+
+    #[cfg(feature = "num")]
+    #[test]
+    fn test_radix_big() {
+        use num_bigint::{BigInt, BigUint};
+
+        let big = BigUint::from(1u8) << 200u32;
+        assert_eq!(
+            format!("0x1{}th", "0".repeat(50)),
+            Ordinal(big).to_string_radix(16, true)
+        );
+        let negative = -(BigInt::from(1u8) << 130u32) - 1;
+        assert_eq!(
+            format!("-0o2{}1th", "0".repeat(42)),
+            Ordinal(negative).to_string_radix(8, true)
+        );
+        assert_eq!(
+            Ok(Ordinal(BigInt::from(-26))),
+            Ordinal::from_str_radix("-0x1ath", 16)
+        );
+    }
+}
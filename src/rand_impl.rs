@@ -0,0 +1,136 @@
+//! Sampling ordinals with rand: `rng.gen::<Ordinal<u8>>()` and
+//! `rng.gen_range(Ordinal(1)..=Ordinal(100))`, behaving exactly like the inner type.
+
+use crate::Ordinal;
+use rand::distributions::uniform::{SampleBorrow, SampleUniform, UniformSampler};
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+
+impl<T> Distribution<Ordinal<T>> for Standard
+where
+    Standard: Distribution<T>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Ordinal<T> {
+        Ordinal(self.sample(rng))
+    }
+}
+
+/// Uniform sampler for `Ordinal<T>`, wrapping the sampler of `T`.
+#[derive(Clone, Copy, Debug)]
+pub struct UniformOrdinal<T: SampleUniform>(T::Sampler);
+
+impl<T: SampleUniform> SampleUniform for Ordinal<T> {
+    type Sampler = UniformOrdinal<T>;
+}
+
+impl<T: SampleUniform> UniformSampler for UniformOrdinal<T> {
+    type X = Ordinal<T>;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        UniformOrdinal(T::Sampler::new(&low.borrow().0, &high.borrow().0))
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        UniformOrdinal(T::Sampler::new_inclusive(&low.borrow().0, &high.borrow().0))
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+        Ordinal(self.0.sample(rng))
+    }
+
+    fn sample_single<R: Rng + ?Sized, B1, B2>(low: B1, high: B2, rng: &mut R) -> Self::X
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Ordinal(T::Sampler::sample_single(
+            &low.borrow().0,
+            &high.borrow().0,
+            rng,
+        ))
+    }
+
+    fn sample_single_inclusive<R: Rng + ?Sized, B1, B2>(low: B1, high: B2, rng: &mut R) -> Self::X
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Ordinal(T::Sampler::sample_single_inclusive(
+            &low.borrow().0,
+            &high.borrow().0,
+            rng,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Ordinal;
+    use rand::distributions::{Distribution, Uniform};
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaCha8Rng;
+    use std::panic::catch_unwind;
+
+    #[test]
+    fn test_rand_gen() {
+        let mut rng = ChaCha8Rng::seed_from_u64(7);
+        let mut primitive = ChaCha8Rng::seed_from_u64(7);
+        for _ in 0..100 {
+            assert_eq!(Ordinal(primitive.gen::<u8>()), rng.gen::<Ordinal<u8>>());
+            assert_eq!(Ordinal(primitive.gen::<i64>()), rng.gen::<Ordinal<i64>>());
+        }
+    }
+
+    #[test]
+    fn test_rand_gen_range() {
+        let mut rng = ChaCha8Rng::seed_from_u64(42);
+        for _ in 0..1000 {
+            let ordinal = rng.gen_range(Ordinal(1u32)..=Ordinal(100u32));
+            assert!((1..=100).contains(&ordinal.0));
+            let ordinal = rng.gen_range(Ordinal(-5i8)..Ordinal(5i8));
+            assert!((-5..5).contains(&ordinal.0));
+        }
+
+        let mut seen = [false; 6];
+        let uniform = Uniform::new_inclusive(Ordinal(1usize), Ordinal(6usize));
+        for _ in 0..200 {
+            seen[uniform.sample(&mut rng).0 - 1] = true;
+        }
+        assert!(seen.iter().all(|&hit| hit));
+
+        let mut primitive = ChaCha8Rng::seed_from_u64(3);
+        let mut rng = ChaCha8Rng::seed_from_u64(3);
+        for _ in 0..100 {
+            assert_eq!(
+                Ordinal(primitive.gen_range(10u16..20)),
+                rng.gen_range(Ordinal(10u16)..Ordinal(20))
+            );
+        }
+    }
+
+    #[test]
+    fn test_rand_empty_ranges() {
+        let primitive = catch_unwind(|| ChaCha8Rng::seed_from_u64(0).gen_range(5u8..5)).is_err();
+        let ordinal =
+            catch_unwind(|| ChaCha8Rng::seed_from_u64(0).gen_range(Ordinal(5u8)..Ordinal(5)))
+                .is_err();
+        assert!(primitive);
+        assert_eq!(primitive, ordinal);
+
+        let inverted =
+            catch_unwind(|| ChaCha8Rng::seed_from_u64(0).gen_range(Ordinal(9u8)..=Ordinal(3)));
+        assert!(inverted.is_err());
+        assert_eq!(
+            Ordinal(4u8),
+            ChaCha8Rng::seed_from_u64(0).gen_range(Ordinal(4u8)..=Ordinal(4))
+        );
+    }
+}
@@ -0,0 +1,529 @@
+//! Turning scores into placings, with ties sharing a place.
+
+use crate::Ordinal;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt::{self, Display, Formatter};
+
+/// How tied items share whole places. Fractional ranking, where a tie can fall between
+/// two places, has its own [`rank_fractional`].
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum RankingMethod {
+    /// Standard competition ranking ("1224"): the place after a tie skips ahead by the
+    /// number that tied.
+    #[default]
+    Competition,
+    /// Dense ranking ("1223"): the place after a tie is the next one.
+    Dense,
+    /// Modified competition ranking ("1334"): a tie takes the last of the places it
+    /// covers, rather than the first.
+    ModifiedCompetition,
+}
+
+/// A place from fractional ranking, which is a whole place or halfway between two.
+///
+/// It displays like an `Ordinal` when whole, and with a `.5` and `th` otherwise, read
+/// as "two point five th": `1.5th`, `2.5th`.
+/// ```rust
+/// use ordinal_type::{rank_fractional, Ordinal};
+///
+/// let ranks = rank_fractional(&[9, 7, 7, 5]);
+/// assert_eq!(ranks[0].to_ordinal(), Some(Ordinal(1)));
+/// assert_eq!(ranks[1].value(), 2.5);
+/// assert_eq!(ranks[1].to_string(), "2.5th");
+/// assert_eq!(rank_fractional(&[1, 1])[0].to_string(), "1.5th");
+/// assert_eq!(ranks[3].to_string(), "4th");
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct FractionalRank {
+    doubled: usize,
+}
+
+impl FractionalRank {
+    /// Returns the place as a number.
+    pub fn value(&self) -> f64 {
+        self.doubled as f64 / 2.0
+    }
+
+    /// Returns whether the place is whole, rather than halfway between two.
+    pub fn is_whole(&self) -> bool {
+        self.doubled.is_multiple_of(2)
+    }
+
+    /// Returns the place if it's whole.
+    pub fn to_ordinal(&self) -> Option<Ordinal<usize>> {
+        self.is_whole().then_some(Ordinal(self.doubled / 2))
+    }
+}
+
+impl Display for FractionalRank {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let whole = self.doubled / 2;
+        if self.is_whole() {
+            Ordinal(whole).fmt(f)
+        } else {
+            write!(f, "{}.5th", whole)
+        }
+    }
+}
+
+/// Sorts the positions of `items` by `compare`, stably, and calls `each` with every run
+/// of equal items: their positions in `items`, and the 0-based place the run starts at.
+fn for_each_tie<T>(
+    items: &[T],
+    mut compare: impl FnMut(&T, &T) -> Ordering,
+    mut each: impl FnMut(&[usize], usize),
+) {
+    let mut order: Vec<usize> = (0..items.len()).collect();
+    order.sort_by(|&a, &b| compare(&items[a], &items[b]));
+    let mut start = 0;
+    while start < order.len() {
+        let first = &items[order[start]];
+        let len = order[start..]
+            .iter()
+            .take_while(|&&i| compare(first, &items[i]) == Ordering::Equal)
+            .count();
+        each(&order[start..start + len], start);
+        start += len;
+    }
+}
+
+/// Ranks `items` ordered by `compare` with `method`.
+fn rank<T>(
+    items: &[T],
+    compare: impl FnMut(&T, &T) -> Ordering,
+    method: RankingMethod,
+) -> Vec<Ordinal<usize>> {
+    let mut ranks = vec![Ordinal(0); items.len()];
+    let mut groups = 0;
+    for_each_tie(items, compare, |tied, start| {
+        groups += 1;
+        let place = match method {
+            RankingMethod::Competition => start + 1,
+            RankingMethod::Dense => groups,
+            RankingMethod::ModifiedCompetition => start + tied.len(),
+        };
+        for &i in tied {
+            ranks[i] = Ordinal(place);
+        }
+    });
+    ranks
+}
+
+fn competition<T>(items: &[T], compare: impl FnMut(&T, &T) -> Ordering) -> Vec<Ordinal<usize>> {
+    rank(items, compare, RankingMethod::Competition)
+}
+
+fn dense<T>(items: &[T], compare: impl FnMut(&T, &T) -> Ordering) -> Vec<Ordinal<usize>> {
+    rank(items, compare, RankingMethod::Dense)
+}
+
+/// Ranks scores from highest to lowest with standard competition ranking: equal scores
+/// share a place, and the next score skips ahead by the number that tied (1st, 2nd, 2nd,
+/// 4th). The places line up with `scores`, which doesn't need to be sorted.
+/// ```rust
+/// use ordinal_type::{rank_desc, Ordinal};
+///
+/// let scores = [70, 95, 80, 95];
+/// let ranks = rank_desc(&scores);
+/// assert_eq!(ranks, [Ordinal(4), Ordinal(1), Ordinal(3), Ordinal(1)]);
+///
+/// // Mark shared places by counting them.
+/// let shown: Vec<String> = ranks
+///     .iter()
+///     .map(|rank| match ranks.iter().filter(|other| *other == rank).count() {
+///         1 => rank.to_string(),
+///         _ => format!("T-{}", rank),
+///     })
+///     .collect();
+/// assert_eq!(shown, ["4th", "T-1st", "3rd", "T-1st"]);
+/// ```
+pub fn rank_desc<T: Ord>(scores: &[T]) -> Vec<Ordinal<usize>> {
+    competition(scores, |a, b| b.cmp(a))
+}
+
+/// Ranks scores from lowest to highest, like times in a race, with standard competition
+/// ranking. See [`rank_desc`].
+/// ```rust
+/// use ordinal_type::{rank_asc, Ordinal};
+///
+/// let times = [9.58, 9.69, 9.69, 9.79].map(|time: f64| (time * 100.0) as u32);
+/// assert_eq!(rank_asc(&times), [Ordinal(1), Ordinal(2), Ordinal(2), Ordinal(4)]);
+/// ```
+pub fn rank_asc<T: Ord>(scores: &[T]) -> Vec<Ordinal<usize>> {
+    competition(scores, T::cmp)
+}
+
+/// Ranks items by the key `key` extracts, lowest key first, with standard competition
+/// ranking. Wrap the key in [`Reverse`](core::cmp::Reverse) to rank the highest first.
+/// See [`rank_desc`].
+/// ```rust
+/// use ordinal_type::{rank_by_key, Ordinal};
+/// use std::cmp::Reverse;
+///
+/// let players = [("ann", 12), ("bob", 30), ("cy", 12)];
+/// let ranks = rank_by_key(&players, |(_, points)| Reverse(*points));
+/// assert_eq!(ranks, [Ordinal(2), Ordinal(1), Ordinal(2)]);
+/// ```
+pub fn rank_by_key<T, K: Ord>(items: &[T], mut key: impl FnMut(&T) -> K) -> Vec<Ordinal<usize>> {
+    competition(items, |a, b| key(a).cmp(&key(b)))
+}
+
+/// Ranks scores from highest to lowest with dense ranking: equal scores share a place,
+/// and the next score takes the next place (1st, 2nd, 2nd, 3rd). The places line up with
+/// `scores`, as for [`rank_desc`].
+/// ```rust
+/// use ordinal_type::{rank_dense_desc, Ordinal};
+///
+/// let scores = [70, 95, 80, 95];
+/// assert_eq!(rank_dense_desc(&scores), [Ordinal(3), Ordinal(1), Ordinal(2), Ordinal(1)]);
+/// ```
+pub fn rank_dense_desc<T: Ord>(scores: &[T]) -> Vec<Ordinal<usize>> {
+    dense(scores, |a, b| b.cmp(a))
+}
+
+/// Ranks scores from lowest to highest with dense ranking. See [`rank_dense_desc`].
+pub fn rank_dense_asc<T: Ord>(scores: &[T]) -> Vec<Ordinal<usize>> {
+    dense(scores, T::cmp)
+}
+
+/// Ranks items by the key `key` extracts, lowest key first, with dense ranking.
+/// See [`rank_by_key`] and [`rank_dense_desc`].
+pub fn rank_dense_by_key<T, K: Ord>(
+    items: &[T],
+    mut key: impl FnMut(&T) -> K,
+) -> Vec<Ordinal<usize>> {
+    dense(items, |a, b| key(a).cmp(&key(b)))
+}
+
+/// Ranks scores from highest to lowest with the given method, so the method can be
+/// picked at runtime. For other orders, rank [`Reverse`](core::cmp::Reverse) scores
+/// or keys.
+/// ```rust
+/// use ordinal_type::{rank_with, Ordinal, RankingMethod};
+///
+/// let scores = [9, 7, 7, 5];
+/// assert_eq!(rank_with(&scores, RankingMethod::Competition)[3], Ordinal(4));
+/// assert_eq!(rank_with(&scores, RankingMethod::Dense)[3], Ordinal(3));
+/// assert_eq!(rank_with(&scores, RankingMethod::ModifiedCompetition)[1], Ordinal(3));
+/// ```
+pub fn rank_with<T: Ord>(scores: &[T], method: RankingMethod) -> Vec<Ordinal<usize>> {
+    rank(scores, |a, b| b.cmp(a), method)
+}
+
+/// Ranks scores from highest to lowest with fractional ranking: equal scores share the
+/// average of the places they cover (1, 2.5, 2.5, 4). The places line up with `scores`,
+/// as for [`rank_desc`].
+pub fn rank_fractional<T: Ord>(scores: &[T]) -> Vec<FractionalRank> {
+    let mut ranks = vec![FractionalRank { doubled: 0 }; scores.len()];
+    for_each_tie(
+        scores,
+        |a, b| b.cmp(a),
+        |tied, start| {
+            // The average of `start + 1` through `start + tied.len()`, doubled to stay whole.
+            let doubled = 2 * start + tied.len() + 1;
+            for &i in tied {
+                ranks[i] = FractionalRank { doubled };
+            }
+        },
+    );
+    ranks
+}
+
+/// Which observations equal to the value count towards its percentile rank.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum PercentileMethod {
+    /// The percentage of observations strictly below the value.
+    #[default]
+    Exclusive,
+    /// The percentage of observations below or equal to the value.
+    Inclusive,
+    /// The percentage below, counting half of the observations equal to the value.
+    Midpoint,
+}
+
+/// Error returned when a percentile rank is asked of no observations.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct EmptyData;
+
+impl Display for EmptyData {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("no observations to rank against")
+    }
+}
+
+impl core::error::Error for EmptyData {}
+
+/// Returns the percentile rank of `value` among `data`, rounded to the nearest whole
+/// percent with halves rounding up. Observations that can't be compared with `value`,
+/// like `NaN`, count as above it.
+/// ```rust
+/// use ordinal_type::{percentile_rank, Ordinal, PercentileMethod};
+///
+/// let scores = [55, 61, 70, 70, 88];
+/// assert_eq!(percentile_rank(&scores, &70, PercentileMethod::Exclusive), Ok(Ordinal(40)));
+/// assert_eq!(percentile_rank(&scores, &70, PercentileMethod::Inclusive), Ok(Ordinal(80)));
+/// assert_eq!(percentile_rank(&scores, &70, PercentileMethod::Midpoint), Ok(Ordinal(60)));
+/// ```
+pub fn percentile_rank<T: PartialOrd>(
+    data: &[T],
+    value: &T,
+    method: PercentileMethod,
+) -> Result<Ordinal<u8>, EmptyData> {
+    if data.is_empty() {
+        return Err(EmptyData);
+    }
+    let below = data.iter().filter(|x| *x < value).count() as u128;
+    let equal = data.iter().filter(|x| *x == value).count() as u128;
+    // Counted in halves, so the midpoint stays whole.
+    let halves = match method {
+        PercentileMethod::Exclusive => 2 * below,
+        PercentileMethod::Inclusive => 2 * (below + equal),
+        PercentileMethod::Midpoint => 2 * below + equal,
+    };
+    let n = data.len() as u128;
+    Ok(Ordinal(((100 * halves + n) / (2 * n)) as u8))
+}
+
+/// Returns the percentile rank of `value` among `data` as a label, like `87th percentile`.
+/// See [`percentile_rank`].
+/// ```rust
+/// use ordinal_type::{percentile_label, PercentileMethod};
+///
+/// let data: Vec<u32> = (1..=100).collect();
+/// let label = percentile_label(&data, &88, PercentileMethod::Exclusive).unwrap();
+/// assert_eq!(label, "87th percentile");
+/// ```
+pub fn percentile_label<T: PartialOrd>(
+    data: &[T],
+    value: &T,
+    method: PercentileMethod,
+) -> Result<String, EmptyData> {
+    percentile_rank(data, value, method).map(|rank| format!("{} percentile", rank))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        percentile_label, percentile_rank, rank_asc, rank_by_key, rank_dense_asc,
+        rank_dense_by_key, rank_dense_desc, rank_desc, rank_fractional, rank_with, EmptyData,
+        Ordinal, PercentileMethod, RankingMethod,
+    };
+
+    fn places(ranks: Vec<Ordinal<usize>>) -> Vec<usize> {
+        ranks.into_iter().map(|rank| rank.0).collect()
+    }
+
+    #[test]
+    fn test_rank_no_ties() {
+        assert_eq!(places(rank_desc(&[3, 1, 2])), [1, 3, 2]);
+        assert_eq!(places(rank_asc(&[3, 1, 2])), [3, 1, 2]);
+    }
+
+    #[test]
+    fn test_rank_ties() {
+        assert_eq!(places(rank_desc(&[5, 5, 5])), [1, 1, 1]);
+        assert_eq!(places(rank_desc(&[9, 9, 4, 1])), [1, 1, 3, 4]);
+        assert_eq!(places(rank_desc(&[9, 4, 1, 1])), [1, 2, 3, 3]);
+        assert_eq!(places(rank_desc(&[1, 9, 4, 9, 4, 0])), [5, 1, 3, 1, 3, 6]);
+        assert_eq!(places(rank_asc(&[1, 9, 4, 9, 4, 0])), [2, 5, 3, 5, 3, 1]);
+    }
+
+    #[test]
+    fn test_rank_empty() {
+        assert!(rank_desc::<u8>(&[]).is_empty());
+        assert!(rank_asc::<u8>(&[]).is_empty());
+        assert!(rank_by_key(&[] as &[u8], |x| *x).is_empty());
+    }
+
+    #[test]
+    fn test_rank_by_key() {
+        let words = ["ccc", "a", "bb", "dd"];
+        assert_eq!(places(rank_by_key(&words, |word| word.len())), [4, 1, 2, 2]);
+        assert_eq!(
+            rank_by_key(&words, |word| word.len())
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            ["4th", "1st", "2nd", "2nd"]
+        );
+    }
+
+    #[test]
+    fn test_dense_against_competition() {
+        let scores = [10, 8, 8, 8, 6, 6, 1];
+        assert_eq!(places(rank_desc(&scores)), [1, 2, 2, 2, 5, 5, 7]);
+        assert_eq!(places(rank_dense_desc(&scores)), [1, 2, 2, 2, 3, 3, 4]);
+        assert_eq!(places(rank_asc(&scores)), [7, 4, 4, 4, 2, 2, 1]);
+        assert_eq!(places(rank_dense_asc(&scores)), [4, 3, 3, 3, 2, 2, 1]);
+
+        let method = |method| places(rank_with(&scores, method));
+        assert_eq!(
+            method(RankingMethod::Competition),
+            places(rank_desc(&scores))
+        );
+        assert_eq!(
+            method(RankingMethod::Dense),
+            places(rank_dense_desc(&scores))
+        );
+        assert_eq!(RankingMethod::default(), RankingMethod::Competition);
+    }
+
+    #[test]
+    fn test_dense_edges() {
+        assert!(rank_dense_desc::<u8>(&[]).is_empty());
+        assert_eq!(places(rank_dense_desc(&[2, 2, 2])), [1, 1, 1]);
+        assert_eq!(places(rank_dense_desc(&[1, 2, 3])), [3, 2, 1]);
+    }
+
+    #[test]
+    fn test_equal_elements_are_stable() {
+        // Items with equal keys but different contents keep their input positions.
+        let entries = [("b", 2), ("a", 1), ("c", 2), ("d", 1), ("e", 2)];
+        let competition = rank_by_key(&entries, |(_, key)| *key);
+        let dense = rank_dense_by_key(&entries, |(_, key)| *key);
+        assert_eq!(places(competition), [3, 1, 3, 1, 3]);
+        assert_eq!(places(dense), [2, 1, 2, 1, 2]);
+    }
+
+    #[test]
+    fn test_all_methods() {
+        // The example from the Wikipedia article on ranking: B and C tie.
+        let scores = [4, 3, 3, 1];
+        let method = |method| places(rank_with(&scores, method));
+        assert_eq!(method(RankingMethod::Competition), [1, 2, 2, 4]);
+        assert_eq!(method(RankingMethod::ModifiedCompetition), [1, 3, 3, 4]);
+        assert_eq!(method(RankingMethod::Dense), [1, 2, 2, 3]);
+        let fractional: Vec<f64> = rank_fractional(&scores)
+            .iter()
+            .map(|rank| rank.value())
+            .collect();
+        assert_eq!(fractional, [1.0, 2.5, 2.5, 4.0]);
+
+        let scores = [8, 8, 8, 5, 2, 2];
+        assert_eq!(
+            method_for(&scores, RankingMethod::Competition),
+            [1, 1, 1, 4, 5, 5]
+        );
+        assert_eq!(
+            method_for(&scores, RankingMethod::ModifiedCompetition),
+            [3, 3, 3, 4, 6, 6]
+        );
+        assert_eq!(
+            method_for(&scores, RankingMethod::Dense),
+            [1, 1, 1, 2, 3, 3]
+        );
+        let fractional: Vec<String> = rank_fractional(&scores)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(fractional, ["2nd", "2nd", "2nd", "4th", "5.5th", "5.5th"]);
+    }
+
+    fn method_for(scores: &[i32], method: RankingMethod) -> Vec<usize> {
+        places(rank_with(scores, method))
+    }
+
+    #[test]
+    fn test_fractional_rank() {
+        assert!(rank_fractional::<u8>(&[]).is_empty());
+        let ranks = rank_fractional(&[1, 1]);
+        assert_eq!(ranks[0], ranks[1]);
+        assert!(!ranks[0].is_whole());
+        assert_eq!(ranks[0].to_ordinal(), None);
+        assert_eq!(ranks[0].to_string(), "1.5th");
+        let ranks = rank_fractional(&[3, 2, 1]);
+        assert_eq!(
+            ranks
+                .iter()
+                .map(|rank| rank.to_ordinal().unwrap().0)
+                .collect::<Vec<_>>(),
+            [1, 2, 3]
+        );
+        assert!(ranks[0] < ranks[1]);
+    }
+
+    fn percentile<T: PartialOrd>(data: &[T], value: T, method: PercentileMethod) -> u8 {
+        percentile_rank(data, &value, method).unwrap().0
+    }
+
+    #[test]
+    fn test_percentile_hand_computed() {
+        let data = [10, 20, 30, 40];
+        assert_eq!(percentile(&data, 30, PercentileMethod::Exclusive), 50);
+        assert_eq!(percentile(&data, 30, PercentileMethod::Inclusive), 75);
+        assert_eq!(percentile(&data, 30, PercentileMethod::Midpoint), 63);
+        assert_eq!(percentile(&data, 25, PercentileMethod::Midpoint), 50);
+        let thirds = [1, 2, 3];
+        assert_eq!(percentile(&thirds, 2, PercentileMethod::Exclusive), 33);
+        assert_eq!(percentile(&thirds, 3, PercentileMethod::Exclusive), 67);
+    }
+
+    #[test]
+    fn test_percentile_min_max_and_outside() {
+        let data = [3.5, 1.0, 2.0, 9.0];
+        for method in [
+            PercentileMethod::Exclusive,
+            PercentileMethod::Inclusive,
+            PercentileMethod::Midpoint,
+        ] {
+            assert_eq!(percentile(&data, 0.0, method), 0);
+            assert_eq!(percentile(&data, 10.0, method), 100);
+        }
+        assert_eq!(percentile(&data, 1.0, PercentileMethod::Exclusive), 0);
+        assert_eq!(percentile(&data, 1.0, PercentileMethod::Inclusive), 25);
+        assert_eq!(percentile(&data, 9.0, PercentileMethod::Exclusive), 75);
+        assert_eq!(percentile(&data, 9.0, PercentileMethod::Inclusive), 100);
+        assert_eq!(
+            percentile(&[1.0, f64::NAN], 1.0, PercentileMethod::Inclusive),
+            50
+        );
+    }
+
+    #[test]
+    fn test_percentile_ties() {
+        let data = [5, 5, 5, 5, 5, 5, 5, 5, 1, 9];
+        assert_eq!(percentile(&data, 5, PercentileMethod::Exclusive), 10);
+        assert_eq!(percentile(&data, 5, PercentileMethod::Inclusive), 90);
+        assert_eq!(percentile(&data, 5, PercentileMethod::Midpoint), 50);
+        assert_eq!(percentile(&[7; 3], 7, PercentileMethod::Exclusive), 0);
+        assert_eq!(percentile(&[7; 3], 7, PercentileMethod::Inclusive), 100);
+    }
+
+    #[test]
+    fn test_percentile_empty_and_label() {
+        assert_eq!(
+            percentile_rank::<u8>(&[], &1, PercentileMethod::Inclusive),
+            Err(EmptyData)
+        );
+        assert_eq!(
+            percentile_label::<u8>(&[], &1, PercentileMethod::Inclusive),
+            Err(EmptyData)
+        );
+        assert_eq!(EmptyData.to_string(), "no observations to rank against");
+        let data = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+        assert_eq!(
+            percentile_label(&data, &2, PercentileMethod::Inclusive).unwrap(),
+            "20th percentile"
+        );
+        assert_eq!(
+            percentile_label(&data, &1, PercentileMethod::Inclusive).unwrap(),
+            "10th percentile"
+        );
+        assert_eq!(
+            percentile_label(&[1, 2], &2, PercentileMethod::Inclusive).unwrap(),
+            "100th percentile"
+        );
+        assert_eq!(
+            percentile_label(&[1, 2], &1, PercentileMethod::Exclusive).unwrap(),
+            "0th percentile"
+        );
+    }
+}
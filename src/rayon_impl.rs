@@ -0,0 +1,154 @@
+//! Formatting large slices of ordinals on the rayon thread pool. The output is exactly
+//! what formatting each value with `Display` in order would give.
+
+use crate::locale::english;
+use crate::OrdinalInteger;
+#[cfg(feature = "compact_str")]
+use compact_str::CompactString;
+use core::fmt;
+use rayon::prelude::*;
+
+/// Writes `value` as an ordinal, without moving it into an `Ordinal`.
+fn write_ordinal<T: OrdinalInteger, W: fmt::Write>(value: &T, out: &mut W) {
+    value
+        .write_digits(out)
+        .and_then(|()| out.write_str(english::suffix(value.last_two())))
+        .expect("writing to a string can't fail");
+}
+
+/// Formats every value as an ordinal in parallel, keeping their order.
+/// ```rust
+/// let ordinals = ordinal_type::par_format_all(&[1u32, 2, 3]);
+/// assert_eq!(ordinals, ["1st", "2nd", "3rd"]);
+/// ```
+pub fn par_format_all<T: OrdinalInteger + Sync>(values: &[T]) -> Vec<String> {
+    values
+        .par_iter()
+        .map(|value| {
+            let mut out = String::new();
+            write_ordinal(value, &mut out);
+            out
+        })
+        .collect()
+}
+
+/// Formats every value as an ordinal in parallel, keeping their order, into
+/// `CompactString`s so that short ordinals don't allocate.
+/// ```rust
+/// let ordinals = ordinal_type::par_format_all_compact(&[1u32, 2, 3]);
+/// assert_eq!(ordinals, ["1st", "2nd", "3rd"]);
+/// assert!(ordinals.iter().all(|text| !text.is_heap_allocated()));
+/// ```
+#[cfg(feature = "compact_str")]
+pub fn par_format_all_compact<T: OrdinalInteger + Sync>(values: &[T]) -> Vec<CompactString> {
+    values
+        .par_iter()
+        .map(|value| {
+            let mut out = CompactString::default();
+            write_ordinal(value, &mut out);
+            out
+        })
+        .collect()
+}
+
+/// Formats every value as an ordinal in parallel and joins them with `sep`, in order.
+/// Rayon decides how to split the work; see [`par_format_join_with`] to tune it.
+/// ```rust
+/// let joined = ordinal_type::par_format_join(&[1u32, 2, 3], ", ");
+/// assert_eq!(joined, "1st, 2nd, 3rd");
+/// ```
+pub fn par_format_join<T: OrdinalInteger + Sync>(values: &[T], sep: &str) -> String {
+    par_format_join_with(values, sep, 1)
+}
+
+/// Like [`par_format_join`], but each task formats at least `min_len` values into one
+/// `String`. A larger `min_len` means fewer, bigger pieces to concatenate at the end.
+/// ```rust
+/// let values: Vec<u64> = (1..=4).collect();
+/// let joined = ordinal_type::par_format_join_with(&values, " ", 1024);
+/// assert_eq!(joined, "1st 2nd 3rd 4th");
+/// ```
+pub fn par_format_join_with<T: OrdinalInteger + Sync>(
+    values: &[T],
+    sep: &str,
+    min_len: usize,
+) -> String {
+    let pieces: Vec<String> = values
+        .par_iter()
+        .with_min_len(min_len)
+        .fold(String::new, |mut out, value| {
+            // Every ordinal is at least two characters, so an empty piece has no values yet.
+            if !out.is_empty() {
+                out.push_str(sep);
+            }
+            write_ordinal(value, &mut out);
+            out
+        })
+        .filter(|piece| !piece.is_empty())
+        .collect();
+    pieces.join(sep)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{par_format_all, par_format_join, par_format_join_with, Ordinal};
+    use rand_chacha::rand_core::{RngCore, SeedableRng};
+    use rand_chacha::ChaCha8Rng;
+
+    fn values(count: usize) -> Vec<i64> {
+        let mut rng = ChaCha8Rng::seed_from_u64(7);
+        (0..count)
+            .map(|_| {
+                // Mostly small values, so every suffix and teen shows up.
+                (rng.next_u64() as i64) >> (rng.next_u32() % 60)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_matches_serial() {
+        let values = values(200_000);
+        let serial: Vec<String> = values.iter().map(|&v| Ordinal(v).to_string()).collect();
+        assert_eq!(serial, par_format_all(&values));
+        let joined = serial.join(", ");
+        assert_eq!(joined, par_format_join(&values, ", "));
+        for min_len in [1, 7, 4096, 1 << 20] {
+            assert_eq!(joined, par_format_join_with(&values, ", ", min_len));
+        }
+        assert_eq!(serial.concat(), par_format_join(&values, ""));
+    }
+
+    #[test]
+    fn test_edge_cases() {
+        assert!(par_format_all::<u8>(&[]).is_empty());
+        assert_eq!("", par_format_join::<u8>(&[], ", "));
+        assert_eq!("11th", par_format_join(&[11u8], ", "));
+    }
+
+    #[cfg(feature = "compact_str")]
+    #[test]
+    fn test_compact_matches_serial() {
+        use crate::par_format_all_compact;
+
+        let values = values(10_000);
+        let serial: Vec<String> = values.iter().map(|&v| Ordinal(v).to_string()).collect();
+        let compact = par_format_all_compact(&values);
+        assert_eq!(serial, compact);
+        assert!(compact.iter().all(|text| !text.is_heap_allocated()));
+        assert!(par_format_all_compact::<u8>(&[]).is_empty());
+    }
+
+    #[cfg(feature = "num")]
+    #[test]
+    fn test_big() {
+        use num_bigint::BigInt;
+
+        let values: Vec<BigInt> = (0..500u32).map(|i| BigInt::from(7).pow(i) - 3).collect();
+        let serial: Vec<String> = values
+            .iter()
+            .map(|v| Ordinal(v.clone()).to_string())
+            .collect();
+        assert_eq!(serial, par_format_all(&values));
+        assert_eq!(serial.join("\n"), par_format_join(&values, "\n"));
+    }
+}
@@ -0,0 +1,120 @@
+//! rusqlite `ToSql` and `FromSql` for `Ordinal` over the integers SQLite can hold losslessly.
+//! Reading a value that doesn't fit the width returns `FromSqlError::OutOfRange`,
+//! and NULL only reads into an `Option<Ordinal<_>>`.
+
+use crate::Ordinal;
+use rusqlite::types::{FromSql, FromSqlResult, ToSqlOutput, ValueRef};
+use rusqlite::ToSql;
+
+macro_rules! impl_sql_for_ordinals {
+    ($($t:ty),*) => {
+        $(
+            impl ToSql for Ordinal<$t> {
+                fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+                    self.0.to_sql()
+                }
+            }
+
+            impl FromSql for Ordinal<$t> {
+                fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+                    <$t>::column_result(value).map(Ordinal)
+                }
+            }
+        )*
+    };
+}
+
+impl_sql_for_ordinals!(u8, u16, u32, i8, i16, i32, i64, isize);
+
+#[cfg(test)]
+mod tests {
+    use crate::Ordinal;
+    use rusqlite::types::{FromSql, FromSqlError, Type, ValueRef};
+    use rusqlite::{params, Connection, Error};
+
+    fn connection() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute(
+            "CREATE TABLE standings (name TEXT NOT NULL, place INTEGER)",
+            [],
+        )
+        .unwrap();
+        conn
+    }
+
+    #[test]
+    fn test_rusqlite_round_trip() {
+        let conn = connection();
+        for (name, place) in [("ann", 1i64), ("bob", 22), ("cal", -3), ("dee", i64::MAX)] {
+            conn.execute(
+                "INSERT INTO standings (name, place) VALUES (?1, ?2)",
+                params![name, Ordinal(place)],
+            )
+            .unwrap();
+        }
+
+        let mut stmt = conn
+            .prepare("SELECT place FROM standings ORDER BY place")
+            .unwrap();
+        let places: Vec<Ordinal<i64>> = stmt
+            .query_map([], |row| row.get(0))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(
+            vec![Ordinal(-3), Ordinal(1), Ordinal(22), Ordinal(i64::MAX)],
+            places
+        );
+
+        let place: Ordinal<u8> = conn
+            .query_row(
+                "SELECT place FROM standings WHERE name = 'bob'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!("22nd", place.to_string());
+    }
+
+    #[test]
+    fn test_rusqlite_out_of_range() {
+        let conn = connection();
+        conn.execute(
+            "INSERT INTO standings (name, place) VALUES ('ann', ?1)",
+            [Ordinal(300i32)],
+        )
+        .unwrap();
+        let get = |sql: &str| conn.query_row(sql, [], |row| row.get::<_, Ordinal<u8>>(0));
+
+        assert!(matches!(
+            Ordinal::<u8>::column_result(ValueRef::Integer(300)),
+            Err(FromSqlError::OutOfRange(300))
+        ));
+        // rusqlite reports `FromSqlError::OutOfRange` from a row get as this variant.
+        assert!(matches!(
+            get("SELECT place FROM standings"),
+            Err(Error::IntegralValueOutOfRange(0, 300))
+        ));
+        assert!(matches!(
+            get("SELECT -1"),
+            Err(Error::IntegralValueOutOfRange(0, -1))
+        ));
+    }
+
+    #[test]
+    fn test_rusqlite_null() {
+        let conn = connection();
+        conn.execute(
+            "INSERT INTO standings (name, place) VALUES ('ann', ?1)",
+            [None::<Ordinal<i64>>],
+        )
+        .unwrap();
+        let sql = "SELECT place FROM standings";
+        let place: Option<Ordinal<i64>> = conn.query_row(sql, [], |row| row.get(0)).unwrap();
+        assert_eq!(None, place);
+        assert!(matches!(
+            conn.query_row(sql, [], |row| row.get::<_, Ordinal<i64>>(0)),
+            Err(Error::InvalidColumnType(0, _, Type::Null))
+        ));
+    }
+}
@@ -0,0 +1,78 @@
+//! `Ordinal<T>` has the same JSON schema as `T`, matching its transparent serde impls.
+//!
+//! Fields using [`serde_str`](crate::serde_str) or [`serde_words`](crate::serde_words)
+//! are strings on the wire, so override their schema:
+//!
+//! ```rust
+//! use ordinal_type::Ordinal;
+//! use schemars::JsonSchema;
+//!
+//! #[derive(JsonSchema)]
+//! struct Standing {
+//!     #[schemars(with = "String")]
+//!     place: Ordinal<u8>,
+//! }
+//! ```
+
+use crate::Ordinal;
+use alloc::borrow::Cow;
+use schemars::{JsonSchema, Schema, SchemaGenerator};
+
+impl<T: JsonSchema> JsonSchema for Ordinal<T> {
+    fn inline_schema() -> bool {
+        T::inline_schema()
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        T::schema_name()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        T::schema_id()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        T::json_schema(generator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Ordinal;
+    use schemars::{schema_for, JsonSchema};
+    use serde_json::json;
+
+    #[test]
+    fn test_schema_is_transparent() {
+        assert_eq!(schema_for!(u32), schema_for!(Ordinal<u32>));
+        assert_eq!(schema_for!(i64), schema_for!(Ordinal<i64>));
+        assert_eq!(
+            json!({
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "title": "uint32",
+                "type": "integer",
+                "format": "uint32",
+                "minimum": 0
+            }),
+            schema_for!(Ordinal<u32>).to_value()
+        );
+    }
+
+    #[test]
+    fn test_schema_in_struct() {
+        #[allow(dead_code)]
+        #[derive(JsonSchema)]
+        struct Standing {
+            place: Ordinal<u16>,
+            #[schemars(with = "String")]
+            label: Ordinal<u16>,
+        }
+
+        let schema = schema_for!(Standing).to_value();
+        assert_eq!(
+            json!({ "type": "integer", "format": "uint16", "minimum": 0, "maximum": 65535 }),
+            schema["properties"]["place"]
+        );
+        assert_eq!(json!({ "type": "string" }), schema["properties"]["label"]);
+    }
+}
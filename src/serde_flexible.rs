@@ -0,0 +1,174 @@
+//! Deserializes an `Ordinal` from whichever shape a feed happens to use:
+//! an integer (`3`), a whole float (`3.0`), a numeric string (`"3"`) or an
+//! ordinal string (`"3rd"`). Serializing writes the bare number.
+//!
+//! ```rust
+//! use ordinal_type::Ordinal;
+//! use serde::Deserialize;
+//!
+//! #[derive(Deserialize)]
+//! struct Entry {
+//!     #[serde(with = "ordinal_type::serde_flexible")]
+//!     position: Ordinal<u32>,
+//! }
+//!
+//! for json in [r#"{"position":3}"#, r#"{"position":"3"}"#, r#"{"position":"3rd"}"#] {
+//!     let entry: Entry = serde_json::from_str(json).unwrap();
+//!     assert_eq!(entry.position, Ordinal(3));
+//! }
+//! ```
+//!
+//! Telling these shapes apart needs a self-describing format such as JSON, YAML or
+//! MessagePack. Formats that aren't self-describing, like postcard or bincode, don't
+//! support it and return their own error for every value; use the plain `Ordinal`
+//! impls with them instead.
+
+use crate::integer::is_whole;
+use crate::{FromInteger, Ordinal, OrdinalInteger};
+use core::fmt::{self, Display, Formatter};
+use core::marker::PhantomData;
+use core::str::FromStr;
+use serde::de::{self, Unexpected, Visitor};
+use serde::{Deserializer, Serialize, Serializer};
+
+/// Serializes the ordinal as its bare number.
+pub fn serialize<T, S>(ordinal: &Ordinal<T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Serialize,
+    S: Serializer,
+{
+    ordinal.serialize(serializer)
+}
+
+/// Deserializes the ordinal from an integer, a whole float, a numeric string or an ordinal string.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Ordinal<T>, D::Error>
+where
+    T: OrdinalInteger + FromStr + FromInteger,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_any(FlexibleVisitor(PhantomData))
+}
+
+struct FlexibleVisitor<T>(PhantomData<T>);
+
+impl<T> FlexibleVisitor<T> {
+    fn fit<E: de::Error>(value: Option<T>, shown: impl Display) -> Result<Ordinal<T>, E> {
+        value
+            .map(Ordinal)
+            .ok_or_else(|| E::custom(format_args!("ordinal {} is out of range", shown)))
+    }
+}
+
+impl<T> Visitor<'_> for FlexibleVisitor<T>
+where
+    T: OrdinalInteger + FromStr + FromInteger,
+{
+    type Value = Ordinal<T>;
+
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(r#"an integer or an ordinal string such as "3rd""#)
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        Self::fit(T::from_u128(v.into()), v)
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        Self::fit(T::from_i128(v.into()), v)
+    }
+
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<Self::Value, E> {
+        Self::fit(T::from_u128(v), v)
+    }
+
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<Self::Value, E> {
+        Self::fit(T::from_i128(v), v)
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+        if !is_whole(v) {
+            return Err(E::invalid_value(Unexpected::Float(v), &"a whole number"));
+        }
+        Self::fit(T::from_f64(v), v)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        let text = v.trim();
+        if text.ends_with(|c: char| c.is_ascii_alphabetic()) {
+            return text
+                .parse()
+                .map_err(|error| E::custom(format_args!("invalid ordinal {:?}: {}", v, error)));
+        }
+        if let Ok(value) = text.parse() {
+            return Ok(Ordinal(value));
+        }
+        match text.parse::<f64>() {
+            Ok(float) if !is_whole(float) => Err(E::custom(format_args!(
+                "invalid ordinal {:?}: not a whole number",
+                v
+            ))),
+            Ok(float) => Self::fit(T::from_f64(float), v),
+            Err(_) => Err(E::invalid_value(Unexpected::Str(v), &self)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Ordinal;
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Entry {
+        #[serde(with = "crate::serde_flexible")]
+        position: Ordinal<i32>,
+    }
+
+    fn entry(position: serde_json::Value) -> Result<Entry, serde_json::Error> {
+        serde_json::from_value(json!({ "position": position }))
+    }
+
+    #[test]
+    fn test_serde_flexible() {
+        for position in [
+            json!(3),
+            json!(3.0),
+            json!("3"),
+            json!("3rd"),
+            json!(" 3RD "),
+        ] {
+            assert_eq!(Ordinal(3), entry(position).unwrap().position);
+        }
+        assert_eq!(Ordinal(-1), entry(json!("-1st")).unwrap().position);
+        assert_eq!(Ordinal(-12), entry(json!(-12)).unwrap().position);
+        assert_eq!(Ordinal(7), entry(json!("7.0")).unwrap().position);
+
+        let parsed: Entry = serde_json::from_str(r#"{"position":"22nd"}"#).unwrap();
+        assert_eq!(
+            r#"{"position":22}"#,
+            serde_json::to_string(&parsed).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_serde_flexible_errors() {
+        let message = |position| entry(position).unwrap_err().to_string();
+        assert!(message(json!("3.5")).contains("not a whole number"));
+        assert!(message(json!(3.5)).contains("a whole number"));
+        assert!(message(json!("2st")).contains("wrong suffix `st` in `2st`, expected `nd`"));
+        assert!(message(json!("2st")).contains(r#""2st""#));
+        assert!(message(json!(u64::MAX)).contains("out of range"));
+        assert!(message(json!("third")).contains("invalid number"));
+        assert!(message(json!(null)).contains("ordinal string"));
+    }
+
+    #[test]
+    fn test_serde_flexible_not_self_describing() {
+        let bytes = postcard::to_allocvec(&Entry {
+            position: Ordinal(3),
+        })
+        .unwrap();
+        assert!(postcard::from_bytes::<Entry>(&bytes).is_err());
+    }
+}
@@ -0,0 +1,75 @@
+//! `Ordinal<T>` serializes transparently as its inner number, so `Ordinal(21)` is `21`
+//! in JSON and takes exactly as many bytes as a bare `21` in a binary format.
+
+use crate::Ordinal;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+impl<T: Serialize> Serialize for Ordinal<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Ordinal<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(Ordinal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Ordinal;
+    use num_bigint::{BigInt, BigUint};
+    use serde::{de::DeserializeOwned, Serialize};
+    use std::fmt::Debug;
+
+    fn round_trip<T>(value: T, json: &str)
+    where
+        T: Serialize + DeserializeOwned + PartialEq + Debug + Clone,
+    {
+        let ordinal = Ordinal(value.clone());
+        assert_eq!(json, serde_json::to_string(&ordinal).unwrap());
+        assert_eq!(ordinal, serde_json::from_str(json).unwrap());
+
+        let bytes = postcard::to_allocvec(&ordinal).unwrap();
+        assert_eq!(postcard::to_allocvec(&value).unwrap(), bytes);
+        assert_eq!(ordinal, postcard::from_bytes(&bytes).unwrap());
+    }
+
+    #[test]
+    fn test_serde_round_trip() {
+        round_trip(1u8, "1");
+        round_trip(255u8, "255");
+        round_trip(-13i16, "-13");
+        round_trip(21u32, "21");
+        round_trip(i64::MIN, "-9223372036854775808");
+        round_trip(u128::MAX, "340282366920938463463374607431768211455");
+        round_trip(-102i128, "-102");
+        round_trip(42usize, "42");
+    }
+
+    #[test]
+    fn test_serde_big_int() {
+        let big = BigUint::from(u128::MAX) * 10u8 + 1u8;
+        let ordinal = Ordinal(big.clone());
+        let json = serde_json::to_string(&ordinal).unwrap();
+        assert_eq!(serde_json::to_string(&big).unwrap(), json);
+        assert_eq!(ordinal, serde_json::from_str(&json).unwrap());
+        round_trip(BigUint::from(3u8), "[3]");
+        round_trip(BigInt::from(-7), "[-1,[7]]");
+    }
+
+    #[test]
+    fn test_serde_in_struct() {
+        #[derive(serde::Serialize, serde::Deserialize, PartialEq, Debug)]
+        struct Standing {
+            place: Ordinal<u16>,
+        }
+
+        let standing = Standing { place: Ordinal(3) };
+        let json = serde_json::to_string(&standing).unwrap();
+        assert_eq!(r#"{"place":3}"#, json);
+        assert_eq!(standing, serde_json::from_str(&json).unwrap());
+        assert!(serde_json::from_str::<Standing>(r#"{"place":"3rd"}"#).is_err());
+    }
+}
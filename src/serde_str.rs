@@ -0,0 +1,116 @@
+//! Serializes an `Ordinal` as its formatted string, `"22nd"`, instead of the bare number.
+//!
+//! ```rust
+//! use ordinal_type::Ordinal;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Standing {
+//!     #[serde(with = "ordinal_type::serde_str")]
+//!     place: Ordinal<u8>,
+//! }
+//!
+//! let standing: Standing = serde_json::from_str(r#"{"place":"22nd"}"#).unwrap();
+//! assert_eq!(standing.place, Ordinal(22));
+//! ```
+//!
+//! With the `schemars` feature, add `#[schemars(with = "String")]` to such fields
+//! so their schema is a string rather than the inner integer.
+
+use crate::{Ordinal, OrdinalInteger};
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::str::FromStr;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serializer};
+
+/// Serializes the ordinal through its `Display` form.
+pub fn serialize<T, S>(ordinal: &Ordinal<T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: OrdinalInteger,
+    S: Serializer,
+{
+    serializer.collect_str(ordinal)
+}
+
+/// Deserializes the ordinal through its `FromStr` form.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Ordinal<T>, D::Error>
+where
+    T: OrdinalInteger + FromStr,
+    D: Deserializer<'de>,
+{
+    let text = String::deserialize(deserializer)?;
+    text.parse()
+        .map_err(|error| D::Error::custom(format!("invalid ordinal {:?}: {}", text, error)))
+}
+
+/// The same representation for an `Option<Ordinal<T>>`, with `None` as null.
+pub mod option {
+    use super::*;
+
+    pub fn serialize<T, S>(ordinal: &Option<Ordinal<T>>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: OrdinalInteger,
+        S: Serializer,
+    {
+        match ordinal {
+            Some(ordinal) => serializer.serialize_some(&ordinal.to_string()),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<Ordinal<T>>, D::Error>
+    where
+        T: OrdinalInteger + FromStr,
+        D: Deserializer<'de>,
+    {
+        Option::<String>::deserialize(deserializer)?
+            .map(|text| {
+                text.parse().map_err(|error| {
+                    D::Error::custom(format!("invalid ordinal {:?}: {}", text, error))
+                })
+            })
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Ordinal;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Standing {
+        #[serde(with = "crate::serde_str")]
+        place: Ordinal<u8>,
+        #[serde(with = "crate::serde_str::option", default)]
+        previous: Option<Ordinal<u8>>,
+    }
+
+    #[test]
+    fn test_serde_str() {
+        let json = r#"{"place":"22nd","previous":null}"#;
+        let standing: Standing = serde_json::from_str(json).unwrap();
+        assert_eq!(Ordinal(22), standing.place);
+        assert_eq!(None, standing.previous);
+        assert_eq!(json, serde_json::to_string(&standing).unwrap());
+
+        let json = r#"{"place":"1st","previous":"3rd"}"#;
+        let standing: Standing = serde_json::from_str(json).unwrap();
+        assert_eq!(Some(Ordinal(3)), standing.previous);
+        assert_eq!(json, serde_json::to_string(&standing).unwrap());
+
+        let standing: Standing = serde_json::from_str(r#"{"place":"2nd"}"#).unwrap();
+        assert_eq!(None, standing.previous);
+    }
+
+    #[test]
+    fn test_serde_str_errors() {
+        let error = serde_json::from_str::<Standing>(r#"{"place":"2st"}"#).unwrap_err();
+        assert!(error.to_string().contains(r#""2st""#), "{}", error);
+        let error =
+            serde_json::from_str::<Standing>(r#"{"place":"1st","previous":"300th"}"#).unwrap_err();
+        assert!(error.to_string().contains(r#""300th""#), "{}", error);
+        assert!(serde_json::from_str::<Standing>(r#"{"place":22}"#).is_err());
+    }
+}
@@ -0,0 +1,132 @@
+//! Serializes an `Ordinal` as its English words, `"twenty-second"`, instead of the bare number.
+//!
+//! ```rust
+//! use ordinal_type::Ordinal;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Standing {
+//!     #[serde(with = "ordinal_type::serde_words")]
+//!     place: Ordinal<u8>,
+//! }
+//!
+//! let standing: Standing = serde_json::from_str(r#"{"place":"twenty-second"}"#).unwrap();
+//! assert_eq!(standing.place, Ordinal(22));
+//! ```
+//!
+//! As with [`serde_str`](crate::serde_str), override the schema of such fields
+//! with `#[schemars(with = "String")]`.
+
+use crate::{FromInteger, Ordinal, OrdinalInteger};
+use alloc::format;
+use alloc::string::String;
+use serde::{de, ser, Deserialize, Deserializer, Serializer};
+
+/// Serializes the ordinal through [`Ordinal::to_words`].
+/// Fails for values that have no English words, such as negative ones.
+pub fn serialize<T, S>(ordinal: &Ordinal<T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: OrdinalInteger,
+    S: Serializer,
+{
+    let words = ordinal.to_words().map_err(|_| {
+        ser::Error::custom(format_args!("ordinal {} has no English words", ordinal))
+    })?;
+    serializer.serialize_str(&words)
+}
+
+/// Deserializes the ordinal through [`Ordinal::from_words`].
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Ordinal<T>, D::Error>
+where
+    T: FromInteger,
+    D: Deserializer<'de>,
+{
+    let text = String::deserialize(deserializer)?;
+    Ordinal::from_words(&text)
+        .map_err(|error| de::Error::custom(format!("invalid ordinal {:?}: {}", text, error)))
+}
+
+/// The same representation for an `Option<Ordinal<T>>`, with `None` as null.
+pub mod option {
+    use super::*;
+
+    pub fn serialize<T, S>(ordinal: &Option<Ordinal<T>>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: OrdinalInteger,
+        S: Serializer,
+    {
+        match ordinal {
+            Some(ordinal) => serializer.serialize_some(&Words(ordinal)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<Ordinal<T>>, D::Error>
+    where
+        T: FromInteger,
+        D: Deserializer<'de>,
+    {
+        Option::<String>::deserialize(deserializer)?
+            .map(|text| {
+                Ordinal::from_words(&text).map_err(|error| {
+                    de::Error::custom(format!("invalid ordinal {:?}: {}", text, error))
+                })
+            })
+            .transpose()
+    }
+
+    struct Words<'a, T>(&'a Ordinal<T>);
+
+    impl<T> serde::Serialize for Words<'_, T>
+    where
+        T: OrdinalInteger,
+    {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            super::serialize(self.0, serializer)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Ordinal;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Standing {
+        #[serde(with = "crate::serde_words")]
+        place: Ordinal<u8>,
+        #[serde(with = "crate::serde_words::option", default)]
+        previous: Option<Ordinal<u8>>,
+    }
+
+    #[test]
+    fn test_serde_words() {
+        let json = r#"{"place":"twenty-second","previous":null}"#;
+        let standing: Standing = serde_json::from_str(json).unwrap();
+        assert_eq!(Ordinal(22), standing.place);
+        assert_eq!(json, serde_json::to_string(&standing).unwrap());
+
+        let json = r#"{"place":"one hundred first","previous":"third"}"#;
+        let standing: Standing = serde_json::from_str(json).unwrap();
+        assert_eq!(Ordinal(101), standing.place);
+        assert_eq!(Some(Ordinal(3)), standing.previous);
+        assert_eq!(json, serde_json::to_string(&standing).unwrap());
+    }
+
+    #[test]
+    fn test_serde_words_errors() {
+        let error = serde_json::from_str::<Standing>(r#"{"place":"twenty-two"}"#).unwrap_err();
+        assert!(error.to_string().contains(r#""twenty-two""#), "{}", error);
+        let error =
+            serde_json::from_str::<Standing>(r#"{"place":"two hundred fifty-sixth"}"#).unwrap_err();
+        assert!(error.to_string().contains("out of range"), "{}", error);
+
+        #[derive(Serialize)]
+        struct Signed {
+            #[serde(with = "crate::serde_words")]
+            place: Ordinal<i8>,
+        }
+        assert!(serde_json::to_string(&Signed { place: Ordinal(-1) }).is_err());
+    }
+}
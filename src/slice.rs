@@ -0,0 +1,65 @@
+//! Zero-copy views between slices of integers and slices of ordinals, relying on
+//! `Ordinal<T>` being `#[repr(transparent)]`.
+
+use crate::Ordinal;
+
+impl<T> Ordinal<T> {
+    /// Views a slice of integers as a slice of ordinals, without copying.
+    /// ```rust
+    /// use ordinal_type::Ordinal;
+    ///
+    /// let ranks: &[u32] = &[3, 1, 2];
+    /// let ordinals = Ordinal::wrap_slice(ranks);
+    /// assert_eq!(ordinals[0], Ordinal(3));
+    /// assert_eq!(ordinals[1].to_string(), "1st");
+    /// ```
+    pub fn wrap_slice(slice: &[T]) -> &[Ordinal<T>] {
+        // SAFETY: `Ordinal<T>` is `repr(transparent)` over `T`, so both slices have the
+        // same layout, and the lifetime is carried over.
+        unsafe { core::slice::from_raw_parts(slice.as_ptr().cast(), slice.len()) }
+    }
+
+    /// Views a slice of ordinals as a slice of their integers, without copying.
+    /// ```rust
+    /// use ordinal_type::Ordinal;
+    ///
+    /// let ordinals = [Ordinal(1u8), Ordinal(2)];
+    /// assert_eq!(Ordinal::peel_slice(&ordinals), &[1, 2]);
+    /// ```
+    pub fn peel_slice(slice: &[Ordinal<T>]) -> &[T] {
+        // SAFETY: as in `wrap_slice`.
+        unsafe { core::slice::from_raw_parts(slice.as_ptr().cast(), slice.len()) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Ordinal;
+    use std::mem::{align_of, size_of};
+
+    macro_rules! assert_same_layout {
+        ($($t:ty),*) => {
+            $(
+                const _: () = assert!(size_of::<Ordinal<$t>>() == size_of::<$t>());
+                const _: () = assert!(align_of::<Ordinal<$t>>() == align_of::<$t>());
+            )*
+        };
+    }
+
+    assert_same_layout!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+    assert_same_layout!(String, [u8; 3], ());
+
+    #[test]
+    fn test_wrap_and_peel_slices() {
+        let ranks: Vec<u32> = (0..1000).collect();
+        let ordinals = Ordinal::wrap_slice(&ranks);
+        assert_eq!(ranks.len(), ordinals.len());
+        assert_eq!(ranks.as_ptr() as usize, ordinals.as_ptr() as usize);
+        for (rank, ordinal) in ranks.iter().zip(ordinals) {
+            assert_eq!(*rank, ordinal.0);
+        }
+        assert_eq!("22nd", ordinals[22].to_string());
+        assert_eq!(&ranks[..], Ordinal::peel_slice(ordinals));
+        assert!(Ordinal::<i64>::wrap_slice(&[]).is_empty());
+    }
+}
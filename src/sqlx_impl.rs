@@ -0,0 +1,96 @@
+//! sqlx `Type`, `Encode` and `Decode` for `Ordinal<T>`, delegating to `T` so an
+//! `Ordinal<i64>` column has exactly the type info of a bare `i64`. The integer
+//! widths available are whichever the database driver supports for `T`.
+
+use crate::Ordinal;
+use sqlx::encode::IsNull;
+use sqlx::error::BoxDynError;
+use sqlx::{Database, Decode, Encode, Type};
+
+impl<DB: Database, T: Type<DB>> Type<DB> for Ordinal<T> {
+    fn type_info() -> DB::TypeInfo {
+        T::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        T::compatible(ty)
+    }
+}
+
+impl<'q, DB: Database, T: Encode<'q, DB>> Encode<'q, DB> for Ordinal<T> {
+    fn encode_by_ref(&self, buf: &mut DB::ArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        self.0.encode_by_ref(buf)
+    }
+
+    fn produces(&self) -> Option<DB::TypeInfo> {
+        self.0.produces()
+    }
+
+    fn size_hint(&self) -> usize {
+        self.0.size_hint()
+    }
+}
+
+impl<'r, DB: Database, T: Decode<'r, DB>> Decode<'r, DB> for Ordinal<T> {
+    fn decode(value: DB::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        T::decode(value).map(Ordinal)
+    }
+}
+
+#[cfg(all(test, feature = "sqlx-sqlite"))]
+mod tests {
+    use crate::Ordinal;
+    use sqlx::sqlite::{Sqlite, SqliteConnection};
+    use sqlx::{Connection, FromRow, Type};
+
+    #[test]
+    fn test_sqlx_type_info() {
+        assert_eq!(
+            <i64 as Type<Sqlite>>::type_info(),
+            <Ordinal<i64> as Type<Sqlite>>::type_info()
+        );
+        assert_eq!(
+            <i32 as Type<Sqlite>>::type_info(),
+            <Ordinal<i32> as Type<Sqlite>>::type_info()
+        );
+    }
+
+    #[derive(FromRow, PartialEq, Debug)]
+    struct Standing {
+        name: String,
+        place: Ordinal<i64>,
+    }
+
+    #[tokio::test]
+    async fn test_sqlx_sqlite_round_trip() {
+        let mut conn = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+        sqlx::query("CREATE TABLE standings (name TEXT NOT NULL, place INTEGER NOT NULL)")
+            .execute(&mut conn)
+            .await
+            .unwrap();
+        for (name, place) in [("ann", 1), ("bob", 22), ("cal", -3)] {
+            sqlx::query("INSERT INTO standings (name, place) VALUES (?, ?)")
+                .bind(name)
+                .bind(Ordinal(place))
+                .execute(&mut conn)
+                .await
+                .unwrap();
+        }
+
+        let standings: Vec<Standing> =
+            sqlx::query_as("SELECT name, place FROM standings ORDER BY place")
+                .fetch_all(&mut conn)
+                .await
+                .unwrap();
+        let places: Vec<_> = standings.iter().map(|s| s.place).collect();
+        assert_eq!(vec![Ordinal(-3), Ordinal(1), Ordinal(22)], places);
+        assert_eq!("bob", standings[2].name);
+
+        let place: Ordinal<i32> = sqlx::query_scalar("SELECT place FROM standings WHERE name = ?")
+            .bind("bob")
+            .fetch_one(&mut conn)
+            .await
+            .unwrap();
+        assert_eq!("22nd", place.to_string());
+    }
+}
@@ -0,0 +1,138 @@
+//! The English ordinals from `0th` to `100th`, built at compile time so small values
+//! can be handed out as `&'static str`.
+
+use crate::locale::english;
+use crate::{Ordinal, OrdinalInteger};
+use alloc::borrow::Cow;
+use alloc::string::ToString;
+
+/// The number of entries, for the values from 0 to 100.
+const LEN: usize = 101;
+
+/// The longest entry, `100th`.
+const MAX_LEN: usize = 5;
+
+/// Writes `n` and its suffix into a fixed buffer, returning the buffer and the length.
+const fn entry(n: usize) -> ([u8; MAX_LEN], usize) {
+    let mut bytes = [0; MAX_LEN];
+    let mut len = 0;
+    if n >= 100 {
+        bytes[len] = b'0' + (n / 100) as u8;
+        len += 1;
+    }
+    if n >= 10 {
+        bytes[len] = b'0' + (n / 10 % 10) as u8;
+        len += 1;
+    }
+    bytes[len] = b'0' + (n % 10) as u8;
+    len += 1;
+    let suffix = english::suffix((n % 100) as u8).as_bytes();
+    let mut i = 0;
+    while i < suffix.len() {
+        bytes[len] = suffix[i];
+        len += 1;
+        i += 1;
+    }
+    (bytes, len)
+}
+
+static ENTRIES: [([u8; MAX_LEN], usize); LEN] = {
+    let mut entries = [([0; MAX_LEN], 0); LEN];
+    let mut n = 0;
+    while n < LEN {
+        entries[n] = entry(n);
+        n += 1;
+    }
+    entries
+};
+
+static TABLE: [&str; LEN] = {
+    let mut table = [""; LEN];
+    let mut n = 0;
+    while n < LEN {
+        let (bytes, len) = &ENTRIES[n];
+        table[n] = match core::str::from_utf8(bytes.split_at(*len).0) {
+            Ok(text) => text,
+            Err(_) => panic!("ordinal table entries are ASCII"),
+        };
+        n += 1;
+    }
+    table
+};
+
+impl<T: OrdinalInteger> Ordinal<T> {
+    /// Returns the ordinal as a `&'static str` for values from 0 to 100, and `None` for
+    /// the rest. The strings are built at compile time, so this never allocates.
+    /// ```rust
+    /// use ordinal_type::Ordinal;
+    ///
+    /// assert_eq!(Ordinal(22u8).as_static_str(), Some("22nd"));
+    /// assert_eq!(Ordinal(101).as_static_str(), None);
+    /// assert_eq!(Ordinal(-1).as_static_str(), None);
+    /// ```
+    pub fn as_static_str(&self) -> Option<&'static str> {
+        let value = self.0.to_u128_checked()?;
+        TABLE.get(usize::try_from(value).ok()?).copied()
+    }
+
+    /// Returns the ordinal as text, borrowed from the static table for values from 0 to
+    /// 100 and allocated for the rest.
+    /// ```rust
+    /// use ordinal_type::Ordinal;
+    /// use std::borrow::Cow;
+    ///
+    /// assert!(matches!(Ordinal(3).to_cow(), Cow::Borrowed("3rd")));
+    /// assert!(matches!(Ordinal(1001).to_cow(), Cow::Owned(text) if text == "1001st"));
+    /// ```
+    pub fn to_cow(&self) -> Cow<'static, str> {
+        match self.as_static_str() {
+            Some(text) => Cow::Borrowed(text),
+            None => Cow::Owned(self.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Ordinal;
+    use std::borrow::Cow;
+
+    #[test]
+    fn test_table_matches_display() {
+        for n in 0u8..=100 {
+            let ordinal = Ordinal(n);
+            assert_eq!(Some(ordinal.to_string().as_str()), ordinal.as_static_str());
+            assert_eq!(
+                Some(ordinal.to_string().as_str()),
+                Ordinal(n as i64).as_static_str()
+            );
+            assert!(matches!(ordinal.to_cow(), Cow::Borrowed(text) if text == ordinal.to_string()));
+        }
+    }
+
+    #[test]
+    fn test_out_of_table() {
+        assert_eq!(None, Ordinal(101).as_static_str());
+        assert_eq!(None, Ordinal(-1).as_static_str());
+        assert_eq!(None, Ordinal(i128::MIN).as_static_str());
+        assert_eq!(None, Ordinal(u128::MAX).as_static_str());
+        assert_eq!(
+            Cow::<str>::Owned("101st".to_string()),
+            Ordinal(101).to_cow()
+        );
+        assert!(matches!(Ordinal(-1).to_cow(), Cow::Owned(text) if text == "-1st"));
+    }
+
+    #[cfg(feature = "num")]
+    #[test]
+    fn test_table_big() {
+        use num_bigint::{BigInt, BigUint};
+
+        assert_eq!(Some("42nd"), Ordinal(BigUint::from(42u8)).as_static_str());
+        assert_eq!(None, Ordinal(BigInt::from(-42)).as_static_str());
+        assert_eq!(
+            None,
+            Ordinal(BigUint::from(u128::MAX) + 1u8).as_static_str()
+        );
+    }
+}
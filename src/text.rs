@@ -0,0 +1,691 @@
+//! Finding and rewriting ordinals in running text.
+
+use crate::display::superscript;
+use crate::locale::english;
+use crate::Ordinal;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// Which numbers [`ordinalize_text`] rewrites.
+/// ```rust
+/// use ordinal_type::OrdinalizeOptions;
+///
+/// let options = OrdinalizeOptions::new().after_words(["in", "the"]).max(100);
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct OrdinalizeOptions {
+    after_words: Vec<String>,
+    max: Option<u128>,
+}
+
+impl OrdinalizeOptions {
+    /// Returns the default options, which rewrite every standalone number.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only rewrites numbers whose preceding word is one of `words`, compared
+    /// case-insensitively. Every number qualifies if this is empty, the default.
+    pub fn after_words<I, S>(mut self, words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.after_words = words.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Only rewrites numbers up to `max`, inclusive. Unbounded by default.
+    pub fn max(mut self, max: u128) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    fn accepts(&self, text: &str, start: usize, value: Option<u128>) -> bool {
+        // Numbers too long for a `u128` are past any maximum.
+        if self
+            .max
+            .is_some_and(|max| value.is_none_or(|value| value > max))
+        {
+            return false;
+        }
+        if self.after_words.is_empty() {
+            return true;
+        }
+        let before = text[..start].trim_end_matches(char::is_whitespace);
+        if before.len() == start {
+            return false;
+        }
+        let word_start = before
+            .char_indices()
+            .rev()
+            .take_while(|(_, c)| c.is_alphabetic())
+            .last()
+            .map_or(before.len(), |(i, _)| i);
+        let word = &before[word_start..];
+        self.after_words
+            .iter()
+            .any(|after| after.to_lowercase() == word.to_lowercase())
+    }
+}
+
+/// Whether `c` continues a word, so a number next to it is part of a larger token.
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// A run of ASCII digits in `text` that stands on its own as a whole number.
+pub(crate) struct NumberToken {
+    pub(crate) start: usize,
+    pub(crate) end: usize,
+}
+
+/// Finds the standalone whole numbers in `text`, with a closure deciding where each token
+/// ends so callers can look at what follows the digits.
+///
+/// A number is standalone if it isn't glued to a letter, digit or `_` before it, and isn't
+/// part of a decimal or grouped number like `3.5` or `1,000`. What may follow it is up
+/// to `follows`, which gets the text after the digits and returns whether the number counts.
+pub(crate) fn numbers<'a>(
+    text: &'a str,
+    follows: impl Fn(&'a str) -> bool + 'a,
+) -> impl Iterator<Item = NumberToken> + 'a {
+    let bytes = text.as_bytes();
+    let mut i = 0;
+    core::iter::from_fn(move || {
+        while i < bytes.len() {
+            if !bytes[i].is_ascii_digit() {
+                i += 1;
+                continue;
+            }
+            let start = i;
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+            let mut end = i;
+            // Swallow decimal and grouping separators so the whole run is skipped.
+            let mut compound = false;
+            while end + 1 < bytes.len()
+                && matches!(bytes[end], b'.' | b',')
+                && bytes[end + 1].is_ascii_digit()
+            {
+                compound = true;
+                end += 1;
+                while end < bytes.len() && bytes[end].is_ascii_digit() {
+                    end += 1;
+                }
+            }
+            let glued_before = text[..start].chars().next_back().is_some_and(|c| {
+                is_word_char(c) || (matches!(c, '.' | ',') && ends_with_digit(&text[..start - 1]))
+            });
+            i = end;
+            if compound || glued_before || !follows(&text[end..]) {
+                continue;
+            }
+            return Some(NumberToken { start, end });
+        }
+        None
+    })
+}
+
+fn ends_with_digit(text: &str) -> bool {
+    text.as_bytes().last().is_some_and(u8::is_ascii_digit)
+}
+
+/// Appends English ordinal suffixes to the standalone whole numbers in `input`,
+/// leaving every other byte as it was.
+///
+/// Numbers inside larger tokens (`v2`, `3px`), decimals and grouped numbers (`3.5`,
+/// `1,000`), and numbers that already carry a suffix (`3rd`) are left alone.
+/// ```rust
+/// use ordinal_type::{ordinalize_text, OrdinalizeOptions};
+///
+/// let options = OrdinalizeOptions::new();
+/// assert_eq!(
+///     ordinalize_text("You finished in 3 place, v2 scored 3.5", &options),
+///     "You finished in 3rd place, v2 scored 3.5"
+/// );
+///
+/// let options = OrdinalizeOptions::new().after_words(["in"]);
+/// assert_eq!(
+///     ordinalize_text("In 1 of 4 runs", &options),
+///     "In 1st of 4 runs"
+/// );
+/// ```
+pub fn ordinalize_text(input: &str, options: &OrdinalizeOptions) -> String {
+    let follows = |rest: &str| !rest.chars().next().is_some_and(is_word_char);
+    let mut out = String::with_capacity(input.len() + input.len() / 4);
+    let mut copied = 0;
+    for token in numbers(input, follows) {
+        let digits = &input[token.start..token.end];
+        if !options.accepts(input, token.start, digits.parse().ok()) {
+            continue;
+        }
+        let last_two = digits[digits.len().saturating_sub(2)..]
+            .parse()
+            .unwrap_or(0);
+        out.push_str(&input[copied..token.end]);
+        out.push_str(english::suffix(last_two));
+        copied = token.end;
+    }
+    out.push_str(&input[copied..]);
+    out
+}
+
+/// How [`strip_ordinals_with`] treats suffixes that don't match their number.
+/// ```rust
+/// use ordinal_type::StripOptions;
+///
+/// let options = StripOptions::new().lenient(true);
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct StripOptions {
+    lenient: bool,
+}
+
+impl StripOptions {
+    /// Returns the default options, which only strip suffixes that match their number.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether any of `st`, `nd`, `rd` and `th` is stripped, even when it's the
+    /// wrong one for the number, like in `2st`. Defaults to `false`.
+    pub fn lenient(mut self, lenient: bool) -> Self {
+        self.lenient = lenient;
+        self
+    }
+}
+
+/// How an ordinal found by [`find_ordinals`] is written.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[non_exhaustive]
+pub enum OrdinalForm {
+    /// A number and its suffix: `21st`.
+    Digits,
+    /// A number and its suffix in superscript letters: `21ˢᵗ`.
+    Superscript,
+    /// Spelled out in English: `twenty-first`.
+    Words,
+}
+
+/// An ordinal found in text by [`find_ordinals`].
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct OrdinalMatch<'a> {
+    text: &'a str,
+    range: Range<usize>,
+    digits_end: usize,
+    ordinal: Ordinal<i128>,
+    form: OrdinalForm,
+}
+
+impl<'a> OrdinalMatch<'a> {
+    /// Returns the byte range of the match in the searched text.
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    /// Returns the matched text, like `21st`.
+    pub fn as_str(&self) -> &'a str {
+        &self.text[self.range.clone()]
+    }
+
+    /// Returns the number part of the match with its sign, like `21` or `-1`. Empty for a
+    /// match in words.
+    pub fn digits(&self) -> &'a str {
+        &self.text[self.range.start..self.digits_end]
+    }
+
+    /// Returns the suffix part of the match, like `st` or `ˢᵗ`. Empty for a match in words.
+    pub fn suffix(&self) -> &'a str {
+        match self.form {
+            OrdinalForm::Words => "",
+            _ => &self.text[self.digits_end..self.range.end],
+        }
+    }
+
+    /// Returns the parsed ordinal.
+    pub fn ordinal(&self) -> Ordinal<i128> {
+        self.ordinal
+    }
+
+    /// Returns how the ordinal is written.
+    pub fn form(&self) -> OrdinalForm {
+        self.form
+    }
+}
+
+const SUFFIXES: [&str; 4] = ["st", "nd", "rd", "th"];
+
+/// Returns the suffix `rest` starts with, plain or in superscript, in ASCII with its
+/// length and form, if it ends the word.
+fn leading_suffix(rest: &str) -> Option<(&'static str, usize, OrdinalForm)> {
+    let plain = SUFFIXES.iter().find_map(|suffix| {
+        rest.get(..2)
+            .filter(|start| start.eq_ignore_ascii_case(suffix))
+            .map(|_| (*suffix, 2, OrdinalForm::Digits))
+    });
+    let found = plain.or_else(|| {
+        SUFFIXES
+            .iter()
+            .map(|suffix| (*suffix, superscript(suffix)))
+            .find(|(_, raised)| rest.starts_with(raised))
+            .map(|(suffix, raised)| (suffix, raised.len(), OrdinalForm::Superscript))
+    })?;
+    let ends_word = !rest[found.1..].chars().next().is_some_and(is_word_char);
+    ends_word.then_some(found)
+}
+
+/// A number and the suffix after it, found by [`suffixed_numbers`].
+struct SuffixedNumber {
+    range: Range<usize>,
+    digits_end: usize,
+    form: OrdinalForm,
+}
+
+/// Finds the numbers followed by a suffix, checking it against the number unless `lenient`.
+/// The suffix follows from the last two digits, so numbers of any length are found.
+fn suffixed_numbers(text: &str, lenient: bool) -> impl Iterator<Item = SuffixedNumber> + '_ {
+    let follows = |rest| leading_suffix(rest).is_some();
+    numbers(text, follows).filter_map(move |token| {
+        let digits = &text[token.start..token.end];
+        let (suffix, len, form) = leading_suffix(&text[token.end..])?;
+        let last_two = digits[digits.len().saturating_sub(2)..].parse().ok()?;
+        if !lenient && !suffix.eq_ignore_ascii_case(english::suffix(last_two)) {
+            return None;
+        }
+        Some(SuffixedNumber {
+            range: token.start..token.end + len,
+            digits_end: token.end,
+            form,
+        })
+    })
+}
+
+/// Finds the spelled-out English ordinals in `text`, taking the longest one at each word.
+fn spelled_ordinals(text: &str) -> impl Iterator<Item = OrdinalMatch<'_>> {
+    let mut i = 0;
+    core::iter::from_fn(move || {
+        while let Some(start) = next_word(text, i) {
+            if !english::is_number_word(&text[start.0..start.1]) {
+                i = start.1;
+                continue;
+            }
+            // The run of number words from `start`, each separated by one space or hyphen.
+            let mut words = vec![start];
+            while let Some(&(_, end)) = words.last() {
+                let Some(next) = next_word(text, end) else {
+                    break;
+                };
+                let joined = next.0 == end + 1 && matches!(text.as_bytes()[end], b' ' | b'-');
+                if !joined || !english::is_number_word(&text[next.0..next.1]) {
+                    break;
+                }
+                words.push(next);
+            }
+            let found = (0..words.len()).rev().find_map(|last| {
+                let end = words[last].1;
+                let is_and =
+                    |(start, end): (usize, usize)| text[start..end].eq_ignore_ascii_case("and");
+                if is_and(words[0]) || is_and(words[last]) {
+                    return None;
+                }
+                let n = english::parse_words(&text[start.0..end])?;
+                Some((end, i128::try_from(n).ok()?))
+            });
+            match found {
+                Some((end, n)) => {
+                    i = end;
+                    return Some(OrdinalMatch {
+                        text,
+                        range: start.0..end,
+                        digits_end: start.0,
+                        ordinal: Ordinal(n),
+                        form: OrdinalForm::Words,
+                    });
+                }
+                None => i = start.1,
+            }
+        }
+        None
+    })
+}
+
+/// Returns the byte range of the next whole alphabetic word at or after `from`. Letters
+/// glued to a digit or `_`, like `3rd` or `x_first`, don't make a word.
+fn next_word(text: &str, from: usize) -> Option<(usize, usize)> {
+    let mut i = from;
+    loop {
+        let start = i + text[i..].find(char::is_alphabetic)?;
+        let end = text[start..]
+            .find(|c: char| !c.is_alphabetic())
+            .map_or(text.len(), |len| start + len);
+        i = end;
+        let glued_before = text[..start].chars().next_back().is_some_and(is_word_char);
+        let glued_after = text[end..].chars().next().is_some_and(is_word_char);
+        if !glued_before && !glued_after {
+            return Some((start, end));
+        }
+    }
+}
+
+/// Finds the ordinals in `text`, in order: numbers with an English suffix, plain or in
+/// superscript, and spelled-out English ordinals.
+///
+/// A number has to stand on its own, as for [`ordinalize_text`]: not glued to a letter,
+/// digit or `_` before it, and not part of a decimal or grouped number. Its suffix has
+/// to match it, in any case, and end the word. So `23rdparty`, `v3rd`, `1.5th` and `2st`
+/// aren't matched, while `USB 3rd` and both of `21st-22nd` are. A `-` right before the
+/// number is its sign, as with `FromStr`, unless it follows a letter, digit or `_`: `-1st`
+/// is `Ordinal(-1)`, while in `21st-22nd` the `-` joins two matches.
+///
+/// Spelled-out ordinals are whole words, joined by single spaces or hyphens, that
+/// [`Ordinal::from_words`] accepts: `twenty-first`, `One hundred and first`. Where
+/// candidates overlap, the longest wins, so `twenty first` is one match rather than
+/// `first` alone. A spelled-out word matches whatever its meaning, as in `a second`.
+/// ```rust
+/// use ordinal_type::{find_ordinals, Ordinal, OrdinalForm};
+///
+/// let text = "He was 3rd, she was 21ST.";
+/// let found: Vec<_> = find_ordinals(text).map(|m| (m.range(), m.ordinal())).collect();
+/// assert_eq!(found, [(7..10, Ordinal(3)), (20..24, Ordinal(21))]);
+///
+/// let text = "the 2ⁿᵈ and twenty-third";
+/// let found: Vec<_> = find_ordinals(text).map(|m| (m.as_str(), m.form())).collect();
+/// assert_eq!(
+///     found,
+///     [("2ⁿᵈ", OrdinalForm::Superscript), ("twenty-third", OrdinalForm::Words)]
+/// );
+/// ```
+pub fn find_ordinals(text: &str) -> impl Iterator<Item = OrdinalMatch<'_>> {
+    let mut suffixed = suffixed_numbers(text, false)
+        .filter_map(|found| {
+            let signed = text[..found.range.start]
+                .strip_suffix('-')
+                .is_some_and(|before| !before.chars().next_back().is_some_and(is_word_char));
+            let start = found.range.start - usize::from(signed);
+            Some(OrdinalMatch {
+                text,
+                ordinal: Ordinal(text[start..found.digits_end].parse().ok()?),
+                range: start..found.range.end,
+                digits_end: found.digits_end,
+                form: found.form,
+            })
+        })
+        .peekable();
+    let mut spelled = spelled_ordinals(text).peekable();
+    let mut end = 0;
+    core::iter::from_fn(move || loop {
+        // Take the earlier match, or the longer one if both start at the same place.
+        let key = |m: &OrdinalMatch| (m.range.start, usize::MAX - m.range.end);
+        let found = match (suffixed.peek(), spelled.peek()) {
+            (Some(a), Some(b)) if key(b) < key(a) => spelled.next(),
+            (Some(_), _) => suffixed.next(),
+            (None, _) => spelled.next(),
+        }?;
+        if found.range.start >= end {
+            end = found.range.end;
+            return Some(found);
+        }
+    })
+}
+
+/// Removes the suffixes of the ordinals in `input`, plain or in superscript, leaving the
+/// numbers and every other byte as they were. Only suffixes that match their number are
+/// removed, and numbers of any length are handled, so this undoes [`ordinalize_text`].
+/// ```rust
+/// use ordinal_type::strip_ordinals;
+///
+/// assert_eq!(strip_ordinals("he finished 3rd on June 21st"), "he finished 3 on June 21");
+/// assert_eq!(strip_ordinals("the 2ⁿᵈ time"), "the 2 time");
+/// ```
+pub fn strip_ordinals(input: &str) -> String {
+    strip_ordinals_with(input, &StripOptions::default())
+}
+
+/// Removes the suffixes of the ordinals in `input` like [`strip_ordinals`], with options.
+/// ```rust
+/// use ordinal_type::{strip_ordinals_with, StripOptions};
+///
+/// let options = StripOptions::new().lenient(true);
+/// assert_eq!(strip_ordinals_with("the 2st time", &options), "the 2 time");
+/// ```
+pub fn strip_ordinals_with(input: &str, options: &StripOptions) -> String {
+    let mut out = String::with_capacity(input.len());
+    let mut copied = 0;
+    for found in suffixed_numbers(input, options.lenient) {
+        out.push_str(&input[copied..found.digits_end]);
+        copied = found.range.end;
+    }
+    out.push_str(&input[copied..]);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        find_ordinals, ordinalize_text, strip_ordinals, strip_ordinals_with, Ordinal, OrdinalForm,
+        OrdinalizeOptions, StripOptions,
+    };
+
+    fn ordinalize(text: &str) -> String {
+        ordinalize_text(text, &OrdinalizeOptions::new())
+    }
+
+    #[test]
+    fn test_ordinalize_standalone_numbers() {
+        assert_eq!(
+            ordinalize("1 2 3 4 11 12 13 21 112"),
+            "1st 2nd 3rd 4th 11th 12th 13th 21st 112th"
+        );
+        assert_eq!(ordinalize("3"), "3rd");
+        assert_eq!(ordinalize("(2), [22]; 3!"), "(2nd), [22nd]; 3rd!");
+        assert_eq!(ordinalize("in 3."), "in 3rd.");
+        assert_eq!(ordinalize("-1 place"), "-1st place");
+    }
+
+    #[test]
+    fn test_ordinalize_leaves_other_tokens() {
+        assert_eq!(ordinalize("v2 and 3px"), "v2 and 3px");
+        assert_eq!(
+            ordinalize("3.5 and 1,000 and 1.2.3"),
+            "3.5 and 1,000 and 1.2.3"
+        );
+        assert_eq!(ordinalize("3rd and 21st"), "3rd and 21st");
+        assert_eq!(ordinalize("a_1 and 2_b"), "a_1 and 2_b");
+        assert_eq!(ordinalize("no numbers here"), "no numbers here");
+        assert_eq!(ordinalize(""), "");
+    }
+
+    #[test]
+    fn test_ordinalize_preserves_text() {
+        assert_eq!(ordinalize("  2\t\n 3  "), "  2nd\t\n 3rd  ");
+        assert_eq!(
+            ordinalize("Zieleinlauf: 2 — Größe 3 🏁"),
+            "Zieleinlauf: 2nd — Größe 3rd 🏁"
+        );
+        assert_eq!(ordinalize("日本2位"), "日本2位");
+        assert_eq!(ordinalize("é2 2é"), "é2 2é");
+        assert_eq!(ordinalize("«2»"), "«2nd»");
+        let text = "1 is 1.";
+        assert_eq!(ordinalize(&ordinalize(text)), ordinalize(text));
+    }
+
+    #[test]
+    fn test_ordinalize_options() {
+        let options = OrdinalizeOptions::new().after_words(["in", "THE"]);
+        assert_eq!(
+            ordinalize_text("in 3 of 5, the  2 and 7", &options),
+            "in 3rd of 5, the  2nd and 7"
+        );
+        assert_eq!(
+            ordinalize_text("3 at the start", &options),
+            "3 at the start"
+        );
+        assert_eq!(ordinalize_text("in, 3", &options), "in, 3");
+
+        let options = OrdinalizeOptions::new().max(10);
+        assert_eq!(ordinalize_text("9 10 11", &options), "9th 10th 11");
+        let huge = "1".repeat(50);
+        assert_eq!(ordinalize_text(&huge, &options), huge);
+        assert_eq!(ordinalize(&huge), format!("{}th", huge));
+    }
+
+    #[test]
+    fn test_strip_ordinals() {
+        assert_eq!(
+            strip_ordinals("he finished 3rd on June 21st, 2nd in 1922"),
+            "he finished 3 on June 21, 2 in 1922"
+        );
+        assert_eq!(strip_ordinals("1st"), "1");
+        assert_eq!(strip_ordinals("1st2nd"), "1st2nd");
+        assert_eq!(strip_ordinals("(11TH) 12th. 113Th!"), "(11) 12. 113!");
+        assert_eq!(strip_ordinals("21st-22nd"), "21-22");
+        assert_eq!(strip_ordinals("über 2nd — 3rd"), "über 2 — 3");
+        assert_eq!(
+            strip_ordinals("23rdparty v3rd 3.5th 1,000th"),
+            "23rdparty v3rd 3.5th 1,000th"
+        );
+        let text = "1 2 3 one two";
+        assert_eq!(
+            strip_ordinals(&ordinalize_text(text, &OrdinalizeOptions::new())),
+            text
+        );
+    }
+
+    #[test]
+    fn test_strip_superscript_and_long_numbers() {
+        assert_eq!(
+            strip_ordinals("1ˢᵗ, 2ⁿᵈ, 3ʳᵈ, 4ᵗʰ; 2ˢᵗ 2ⁿᵈx"),
+            "1, 2, 3, 4; 2ˢᵗ 2ⁿᵈx"
+        );
+        let lenient = StripOptions::new().lenient(true);
+        assert_eq!(strip_ordinals_with("2ˢᵗ 4ʳᵈ", &lenient), "2 4");
+
+        // Longer than any integer: the suffix only depends on the last two digits.
+        let huge = format!("{}2", "3".repeat(50));
+        assert_eq!(strip_ordinals(&format!("{}nd", huge)), huge);
+        assert_eq!(
+            strip_ordinals(&format!("{}th", huge)),
+            format!("{}th", huge)
+        );
+        let text = format!("the {} and {}1 of 3", huge, "9".repeat(41));
+        assert_eq!(strip_ordinals(&ordinalize(&text)), text);
+        // Found by fuzzing: a number too long for an integer wasn't stripped again.
+        let text = "<1/400000000000000000000000000000000000000000=4";
+        assert_eq!(strip_ordinals(&ordinalize(text)), strip_ordinals(text));
+    }
+
+    #[test]
+    fn test_strip_strict_and_lenient() {
+        let lenient = StripOptions::new().lenient(true);
+        assert_eq!(strip_ordinals("2st 11st 3rd"), "2st 11st 3");
+        assert_eq!(strip_ordinals_with("2st 11st 3rd", &lenient), "2 11 3");
+        assert_eq!(strip_ordinals_with("2xy 2s", &lenient), "2xy 2s");
+    }
+
+    #[test]
+    fn test_find_ordinals() {
+        let text = "1st place, 2nd, and 2st; 4th\n5TH";
+        let found: Vec<_> = find_ordinals(text)
+            .map(|m| (m.as_str(), m.digits(), m.suffix(), m.ordinal()))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                ("1st", "1", "st", Ordinal(1)),
+                ("2nd", "2", "nd", Ordinal(2)),
+                ("4th", "4", "th", Ordinal(4)),
+                ("5TH", "5", "TH", Ordinal(5)),
+            ]
+        );
+        let too_big = format!("{}th", "9".repeat(40));
+        assert_eq!(find_ordinals(&too_big).count(), 0);
+    }
+
+    #[test]
+    fn test_find_ordinals_forms() {
+        let text = "She came 3rd, he came 21ˢᵗ, and I was twenty-first; \
+                    the One Hundred and First try.";
+        let found: Vec<_> = find_ordinals(text)
+            .map(|m| (m.as_str(), m.form(), m.ordinal()))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                ("3rd", OrdinalForm::Digits, Ordinal(3)),
+                ("21ˢᵗ", OrdinalForm::Superscript, Ordinal(21)),
+                ("twenty-first", OrdinalForm::Words, Ordinal(21)),
+                ("One Hundred and First", OrdinalForm::Words, Ordinal(101)),
+            ]
+        );
+        for m in find_ordinals(text) {
+            assert_eq!(&text[m.range()], m.as_str());
+        }
+
+        let superscript = find_ordinals("the 2ⁿᵈ").next().unwrap();
+        assert_eq!((superscript.digits(), superscript.suffix()), ("2", "ⁿᵈ"));
+        let words = find_ordinals("the second").next().unwrap();
+        assert_eq!((words.digits(), words.suffix()), ("", ""));
+    }
+
+    #[test]
+    fn test_find_ordinals_boundaries() {
+        let decoys = "1.5th-ish v1.2.3rd 23rdparty USB3rd 3first x_first 2ⁿᵈx 2ˢᵗ 1,000th \
+                      firstly twenty-one and";
+        assert_eq!(find_ordinals(decoys).count(), 0);
+
+        let found: Vec<_> = find_ordinals("USB 3rd, 21st-22nd, (fifth) 3rd-party")
+            .map(|m| m.as_str())
+            .collect();
+        assert_eq!(found, ["3rd", "21st", "22nd", "fifth", "3rd"]);
+    }
+
+    #[test]
+    fn test_find_ordinals_negative() {
+        let text = "-1st, (-22nd) x-3rd 4-5th --6th";
+        let found: Vec<_> = find_ordinals(text)
+            .map(|m| (m.as_str(), m.digits(), m.ordinal()))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                ("-1st", "-1", Ordinal(-1)),
+                ("-22nd", "-22", Ordinal(-22)),
+                ("3rd", "3", Ordinal(3)),
+                ("5th", "5", Ordinal(5)),
+                ("-6th", "-6", Ordinal(-6)),
+            ]
+        );
+        for m in find_ordinals(text) {
+            assert_eq!(Ok(m.ordinal()), m.as_str().parse());
+        }
+        let min = format!("{}th", i128::MIN);
+        assert_eq!(
+            Some(Ordinal(i128::MIN)),
+            find_ordinals(&min).next().map(|m| m.ordinal())
+        );
+    }
+
+    #[test]
+    fn test_find_ordinals_longest_match() {
+        let found: Vec<_> = find_ordinals("twenty first, one thousand and second, and third")
+            .map(|m| (m.range(), m.ordinal()))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                (0..12, Ordinal(21)),
+                (14..37, Ordinal(1002)),
+                (43..48, Ordinal(3)),
+            ]
+        );
+        // Words only join across a single space or hyphen.
+        let found: Vec<_> = find_ordinals("seventy  second")
+            .map(|m| m.as_str())
+            .collect();
+        assert_eq!(found, ["second"]);
+    }
+}
@@ -0,0 +1,89 @@
+//! `ufmt::uDisplay` for `Ordinal` over the primitive integers, writing the digits with
+//! ufmt's own integer impls and then the suffix, without core::fmt or allocation.
+
+use crate::locale::english;
+use crate::Ordinal;
+use ufmt::{uDisplay, uWrite, Formatter};
+
+macro_rules! impl_udisplay_for_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl uDisplay for Ordinal<$t> {
+                fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+                    self.0.fmt(f)?;
+                    f.write_str(english::suffix((self.0 % 100) as u8))
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_udisplay_for_signed {
+    ($($t:ty),*) => {
+        $(
+            impl uDisplay for Ordinal<$t> {
+                fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+                    self.0.fmt(f)?;
+                    f.write_str(english::suffix((self.0.unsigned_abs() % 100) as u8))
+                }
+            }
+        )*
+    };
+}
+
+impl_udisplay_for_unsigned!(u8, u16, u32, u64, u128, usize);
+impl_udisplay_for_signed!(i8, i16, i32, i64, i128, isize);
+
+#[cfg(test)]
+mod tests {
+    use crate::Ordinal;
+    use std::convert::Infallible;
+    use ufmt::{uDisplay, uWrite, uwrite};
+
+    struct Buffer(String);
+
+    impl uWrite for Buffer {
+        type Error = Infallible;
+
+        fn write_str(&mut self, s: &str) -> Result<(), Infallible> {
+            self.0.push_str(s);
+            Ok(())
+        }
+    }
+
+    fn render(ordinal: &impl uDisplay) -> String {
+        let mut buffer = Buffer(String::new());
+        uwrite!(buffer, "{} place", ordinal).unwrap();
+        buffer.0
+    }
+
+    #[test]
+    fn test_udisplay_matches_display() {
+        for i in -300i32..=300 {
+            assert_eq!(format!("{} place", Ordinal(i)), render(&Ordinal(i)));
+        }
+        for i in [0u8, 1, 2, 3, 11, 12, 13, 111, 255] {
+            assert_eq!(format!("{} place", Ordinal(i)), render(&Ordinal(i)));
+        }
+        assert_eq!(
+            format!("{} place", Ordinal(i8::MIN)),
+            render(&Ordinal(i8::MIN))
+        );
+        assert_eq!(
+            format!("{} place", Ordinal(u64::MAX)),
+            render(&Ordinal(u64::MAX))
+        );
+        assert_eq!(
+            format!("{} place", Ordinal(u128::MAX)),
+            render(&Ordinal(u128::MAX))
+        );
+        assert_eq!(
+            format!("{} place", Ordinal(i128::MIN)),
+            render(&Ordinal(i128::MIN))
+        );
+        assert_eq!(
+            format!("{} place", Ordinal(isize::MAX)),
+            render(&Ordinal(isize::MAX))
+        );
+    }
+}
@@ -0,0 +1,102 @@
+//! utoipa `ToSchema` for `Ordinal<T>`, with the schema of `T`, matching its
+//! transparent serde impls: an `Ordinal<u32>` is `{"type": "integer", "format": "int32"}`.
+//! Derived schemas refer to it as the `Ordinal_u32` component; mark the field
+//! `#[schema(inline)]` to embed the integer schema directly.
+//!
+//! Fields using [`serde_str`](crate::serde_str) or [`serde_words`](crate::serde_words)
+//! are strings on the wire, so override their schema:
+//!
+//! ```rust
+//! use ordinal_type::Ordinal;
+//! use utoipa::ToSchema;
+//!
+//! #[derive(ToSchema)]
+//! struct Standing {
+//!     #[schema(value_type = String, example = "22nd")]
+//!     place: Ordinal<u8>,
+//! }
+//! ```
+
+use crate::Ordinal;
+use alloc::borrow::Cow;
+use utoipa::__dev::ComposeSchema;
+use utoipa::openapi::schema::Schema;
+use utoipa::openapi::RefOr;
+use utoipa::ToSchema;
+
+// utoipa derives `PartialSchema` from `ComposeSchema`, which is also what its derive
+// macros call for generic field types, so this is the impl `Box<T>` has too. Without it,
+// deriving `ToSchema` for a struct with an `Ordinal<u32>` field doesn't compile. The
+// trait is doc-hidden and outside utoipa's semver, hence the pinned minor version.
+impl<T: ComposeSchema> ComposeSchema for Ordinal<T> {
+    fn compose(schemas: Vec<RefOr<Schema>>) -> RefOr<Schema> {
+        match schemas.into_iter().next() {
+            Some(schema) => schema,
+            None => T::compose(Vec::new()),
+        }
+    }
+}
+
+impl<T: ToSchema + ComposeSchema> ToSchema for Ordinal<T> {
+    fn name() -> Cow<'static, str> {
+        Cow::Borrowed("Ordinal")
+    }
+
+    fn schemas(schemas: &mut Vec<(String, RefOr<Schema>)>) {
+        T::schemas(schemas)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Ordinal;
+    use serde_json::{json, Value};
+    use utoipa::{OpenApi, PartialSchema, ToSchema};
+
+    fn to_json(schema: impl serde::Serialize) -> Value {
+        serde_json::to_value(schema).unwrap()
+    }
+
+    #[test]
+    fn test_schema_is_transparent() {
+        assert_eq!(to_json(u32::schema()), to_json(Ordinal::<u32>::schema()));
+        assert_eq!(to_json(i64::schema()), to_json(Ordinal::<i64>::schema()));
+        assert_eq!(
+            json!({ "type": "integer", "format": "int64" }),
+            to_json(Ordinal::<i64>::schema())
+        );
+    }
+
+    #[allow(dead_code)]
+    #[derive(ToSchema)]
+    struct Standing {
+        place: Ordinal<u32>,
+        #[schema(inline)]
+        previous: Ordinal<i64>,
+        #[schema(value_type = String)]
+        label: Ordinal<u32>,
+    }
+
+    #[test]
+    fn test_openapi_document() {
+        #[derive(OpenApi)]
+        #[openapi(components(schemas(Standing)))]
+        struct Api;
+
+        let document = to_json(Api::openapi());
+        let standing = &document["components"]["schemas"]["Standing"];
+        assert_eq!(
+            json!({ "$ref": "#/components/schemas/Ordinal_u32" }),
+            standing["properties"]["place"]
+        );
+        assert_eq!(
+            json!({ "type": "integer", "format": "int32", "minimum": 0 }),
+            document["components"]["schemas"]["Ordinal_u32"]
+        );
+        assert_eq!(
+            json!({ "type": "integer", "format": "int64" }),
+            standing["properties"]["previous"]
+        );
+        assert_eq!(json!({ "type": "string" }), standing["properties"]["label"]);
+    }
+}
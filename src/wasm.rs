@@ -0,0 +1,147 @@
+//! `#[wasm_bindgen]` exports, for formatting ordinals from JavaScript.
+//!
+//! JavaScript numbers are floats, so every function checks that its argument is a whole
+//! number within `Number.MAX_SAFE_INTEGER` and throws an `Error` otherwise, rather than
+//! rounding or truncating it.
+//!
+//! ```js
+//! import { format_ordinal, format_ordinal_locale } from "ordinal_type";
+//!
+//! format_ordinal(22);              // "22nd"
+//! format_ordinal_locale(1, "fr");  // "1er"
+//! format_ordinal(1.5);             // throws Error: 1.5 is not a whole number
+//! ```
+
+use crate::{Locale, Ordinal};
+use wasm_bindgen::prelude::*;
+
+/// `Number.MAX_SAFE_INTEGER`, the largest integer a JavaScript number holds exactly.
+const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_991.0;
+
+/// Returns `n` as an integer, or a message if it's fractional or not exact.
+fn to_integer(n: f64) -> Result<i64, String> {
+    if n.is_nan() || n.fract() != 0.0 {
+        Err(format!("{} is not a whole number", n))
+    } else if n.abs() > MAX_SAFE_INTEGER {
+        Err(format!("{} is outside the safe integer range", n))
+    } else {
+        Ok(n as i64)
+    }
+}
+
+fn suffix(n: f64) -> Result<String, String> {
+    Ok(Ordinal(to_integer(n)?).suffix().to_string())
+}
+
+fn format(n: f64) -> Result<String, String> {
+    Ok(Ordinal(to_integer(n)?).to_string())
+}
+
+fn format_locale(n: f64, locale: &str) -> Result<String, String> {
+    let ordinal = Ordinal(to_integer(n)?);
+    let locale =
+        Locale::from_tag(locale).ok_or_else(|| format!("unsupported locale `{}`", locale))?;
+    Ok(ordinal.to_string_with_locale(locale))
+}
+
+/// Returns the English suffix of `n`, like `nd` for 22.
+#[wasm_bindgen]
+pub fn ordinal_suffix(n: f64) -> Result<String, JsError> {
+    suffix(n).map_err(|message| JsError::new(&message))
+}
+
+/// Formats `n` as an English ordinal, like `22nd`.
+#[wasm_bindgen]
+pub fn format_ordinal(n: f64) -> Result<String, JsError> {
+    format(n).map_err(|message| JsError::new(&message))
+}
+
+/// Formats `n` as an ordinal in the locale of a BCP 47 tag like `fr` or `pt-BR`.
+/// See [`Locale::from_tag`].
+#[wasm_bindgen]
+pub fn format_ordinal_locale(n: f64, locale: &str) -> Result<String, JsError> {
+    format_locale(n, locale).map_err(|message| JsError::new(&message))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format, format_locale, suffix};
+
+    #[test]
+    fn test_whole_numbers() {
+        assert_eq!(Ok("nd".to_string()), suffix(22.0));
+        assert_eq!(Ok("th".to_string()), suffix(-0.0));
+        assert_eq!(Ok("22nd".to_string()), format(22.0));
+        assert_eq!(Ok("-11th".to_string()), format(-11.0));
+        assert_eq!(
+            Ok("9007199254740991st".to_string()),
+            format(9_007_199_254_740_991.0)
+        );
+        assert_eq!(
+            Ok("-9007199254740991st".to_string()),
+            format(-9_007_199_254_740_991.0)
+        );
+        assert_eq!(Ok("1er".to_string()), format_locale(1.0, "fr"));
+        assert_eq!(Ok("2.º".to_string()), format_locale(2.0, "pt-BR"));
+    }
+
+    #[test]
+    fn test_rejected_numbers() {
+        for n in [1.5, -0.25, f64::NAN] {
+            assert_eq!(Err(format!("{} is not a whole number", n)), format(n));
+        }
+        for n in [9_007_199_254_740_992.0, -9_007_199_254_740_992.0, 1e300] {
+            assert_eq!(
+                Err(format!("{} is outside the safe integer range", n)),
+                suffix(n)
+            );
+        }
+        for n in [f64::INFINITY, f64::NEG_INFINITY] {
+            assert!(format(n).is_err());
+        }
+        assert_eq!(
+            Err("unsupported locale `xx-YY`".to_string()),
+            format_locale(1.0, "xx-YY")
+        );
+        assert!(format_locale(0.5, "en").is_err());
+    }
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod wasm_tests {
+    use super::{format_ordinal, format_ordinal_locale, ordinal_suffix};
+    use wasm_bindgen::JsValue;
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    /// Returns the message of the JavaScript `Error` thrown.
+    fn message(error: wasm_bindgen::JsError) -> String {
+        let value = JsValue::from(error);
+        js_sys::Reflect::get(&value, &JsValue::from_str("message"))
+            .unwrap()
+            .as_string()
+            .unwrap()
+    }
+
+    #[wasm_bindgen_test]
+    fn test_js_values() {
+        assert_eq!("rd", ordinal_suffix(23.0).unwrap());
+        assert_eq!("112th", format_ordinal(112.0).unwrap());
+        assert_eq!("1er", format_ordinal_locale(1.0, "fr-FR").unwrap());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_js_errors() {
+        assert_eq!(
+            "1.5 is not a whole number",
+            message(format_ordinal(1.5).unwrap_err())
+        );
+        assert_eq!(
+            "9007199254740992 is outside the safe integer range",
+            message(ordinal_suffix(9_007_199_254_740_992.0).unwrap_err())
+        );
+        assert_eq!(
+            "unsupported locale `xx`",
+            message(format_ordinal_locale(1.0, "xx").unwrap_err())
+        );
+    }
+}
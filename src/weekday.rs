@@ -0,0 +1,213 @@
+//! Computing and describing the nth weekday of a month, as in
+//! "the 2nd Monday of each month" or "the 3rd Tuesday of March 2025".
+//!
+//! ```rust
+//! use chrono::{NaiveDate, Weekday};
+//! use ordinal_type::weekday::{self, Occurrence};
+//! use ordinal_type::Ordinal;
+//!
+//! let date = weekday::nth_weekday_of_month(2025, 3, Weekday::Tue, Ordinal(3)).unwrap();
+//! assert_eq!(date, NaiveDate::from_ymd_opt(2025, 3, 18).unwrap());
+//! assert_eq!(weekday::weekday_occurrence(date), Ordinal(3));
+//! assert_eq!(weekday::describe(date), "3rd Tuesday of March 2025");
+//! assert_eq!(Occurrence::Last.describe(Weekday::Fri), "last Friday");
+//! ```
+
+use crate::Ordinal;
+use chrono::{Datelike, Month, NaiveDate, Weekday};
+use core::fmt::{self, Display, Formatter};
+
+/// Which occurrence of a weekday within its month.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Occurrence {
+    /// The nth occurrence, counting from the 1st.
+    Nth(Ordinal<u8>),
+    /// The last occurrence, whether that's the 4th or the 5th.
+    Last,
+}
+
+impl Occurrence {
+    /// Returns the date of this occurrence of `weekday` in the given month,
+    /// or `None` if the month doesn't have it.
+    pub fn date(self, year: i32, month: u32, weekday: Weekday) -> Option<NaiveDate> {
+        match self {
+            Occurrence::Nth(n) => nth_weekday_of_month(year, month, weekday, n),
+            Occurrence::Last => last_weekday_of_month(year, month, weekday),
+        }
+    }
+
+    /// Describes the occurrence of `weekday` in English: `2nd Monday`, `last Friday`.
+    pub fn describe(self, weekday: Weekday) -> String {
+        format!("{} {}", self, weekday_name(weekday))
+    }
+}
+
+impl Display for Occurrence {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Occurrence::Nth(n) => n.fmt(f),
+            Occurrence::Last => f.write_str("last"),
+        }
+    }
+}
+
+/// Returns the date of the nth `weekday` in the given month, or `None` if the month
+/// has fewer than `n` of them, `n` is zero, or the month is invalid.
+pub fn nth_weekday_of_month(
+    year: i32,
+    month: u32,
+    weekday: Weekday,
+    n: Ordinal<u8>,
+) -> Option<NaiveDate> {
+    let first = NaiveDate::from_ymd_opt(year, month, 1)?;
+    let offset = (7 + weekday.num_days_from_monday() - first.weekday().num_days_from_monday()) % 7;
+    let weeks = u32::from(n.0.checked_sub(1)?);
+    let day = 1 + offset + 7 * weeks;
+    NaiveDate::from_ymd_opt(year, month, day)
+}
+
+/// Returns the date of the last `weekday` in the given month, or `None` if the month is invalid.
+pub fn last_weekday_of_month(year: i32, month: u32, weekday: Weekday) -> Option<NaiveDate> {
+    // Found directly rather than as the day before the next month, which doesn't exist
+    // after the last supported year.
+    let last = (28..=31)
+        .rev()
+        .find_map(|day| NaiveDate::from_ymd_opt(year, month, day))?;
+    let offset = (7 + last.weekday().num_days_from_monday() - weekday.num_days_from_monday()) % 7;
+    NaiveDate::from_ymd_opt(year, month, last.day() - offset)
+}
+
+/// Returns which occurrence of its weekday `date` is within its month.
+pub fn weekday_occurrence(date: NaiveDate) -> Ordinal<u8> {
+    Ordinal(((date.day() - 1) / 7 + 1) as u8)
+}
+
+/// Returns whether `date` is the last occurrence of its weekday within its month.
+pub fn is_last_weekday_occurrence(date: NaiveDate) -> bool {
+    date.checked_add_days(chrono::Days::new(7))
+        .is_none_or(|next| next.month() != date.month())
+}
+
+/// Describes `date` in English by its weekday occurrence: `3rd Tuesday of March 2025`.
+pub fn describe(date: NaiveDate) -> String {
+    let month = Month::try_from(date.month() as u8).expect("chrono months are 1 to 12");
+    format!(
+        "{} of {} {}",
+        Occurrence::Nth(weekday_occurrence(date)).describe(date.weekday()),
+        month.name(),
+        date.year()
+    )
+}
+
+fn weekday_name(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "Monday",
+        Weekday::Tue => "Tuesday",
+        Weekday::Wed => "Wednesday",
+        Weekday::Thu => "Thursday",
+        Weekday::Fri => "Friday",
+        Weekday::Sat => "Saturday",
+        Weekday::Sun => "Sunday",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::weekday::{self, Occurrence};
+    use crate::Ordinal;
+    use chrono::{Datelike, NaiveDate, Weekday};
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_nth_weekday_of_month() {
+        // March 2025 starts on a Saturday and has five Saturdays, Sundays and Mondays.
+        let nth = |weekday, n| weekday::nth_weekday_of_month(2025, 3, weekday, Ordinal(n));
+        assert_eq!(Some(date(2025, 3, 1)), nth(Weekday::Sat, 1));
+        assert_eq!(Some(date(2025, 3, 29)), nth(Weekday::Sat, 5));
+        assert_eq!(Some(date(2025, 3, 31)), nth(Weekday::Mon, 5));
+        assert_eq!(Some(date(2025, 3, 4)), nth(Weekday::Tue, 1));
+        assert_eq!(Some(date(2025, 3, 25)), nth(Weekday::Tue, 4));
+        assert_eq!(None, nth(Weekday::Tue, 5));
+        assert_eq!(None, nth(Weekday::Tue, 0));
+        assert_eq!(None, nth(Weekday::Tue, 255));
+
+        // February 2026 has exactly four of every weekday.
+        for weekday in [Weekday::Mon, Weekday::Thu, Weekday::Sun] {
+            let fourth = weekday::nth_weekday_of_month(2026, 2, weekday, Ordinal(4));
+            assert_eq!(fourth, Occurrence::Last.date(2026, 2, weekday));
+            assert_eq!(
+                None,
+                weekday::nth_weekday_of_month(2026, 2, weekday, Ordinal(5))
+            );
+        }
+        assert_eq!(
+            None,
+            weekday::nth_weekday_of_month(2025, 13, Weekday::Mon, Ordinal(1))
+        );
+    }
+
+    #[test]
+    fn test_last_weekday_of_month() {
+        assert_eq!(
+            Some(date(2025, 3, 31)),
+            Occurrence::Last.date(2025, 3, Weekday::Mon)
+        );
+        assert_eq!(
+            Some(date(2025, 3, 25)),
+            Occurrence::Last.date(2025, 3, Weekday::Tue)
+        );
+        assert_eq!(
+            Some(date(2024, 2, 29)),
+            Occurrence::Last.date(2024, 2, Weekday::Thu)
+        );
+        assert_eq!(
+            Some(date(2025, 12, 28)),
+            Occurrence::Last.date(2025, 12, Weekday::Sun)
+        );
+
+        let max = NaiveDate::MAX;
+        for weekday in [Weekday::Mon, Weekday::Thu, Weekday::Sun] {
+            let last = weekday::last_weekday_of_month(max.year(), 12, weekday).unwrap();
+            assert_eq!(weekday, last.weekday());
+            assert!(max.signed_duration_since(last).num_days() < 7);
+        }
+        assert!(weekday::last_weekday_of_month(max.year() + 1, 1, Weekday::Mon).is_none());
+    }
+
+    #[test]
+    fn test_weekday_occurrence() {
+        for day in 1..=31 {
+            let date = date(2025, 3, day);
+            let occurrence = weekday::weekday_occurrence(date);
+            assert_eq!(
+                Some(date),
+                weekday::nth_weekday_of_month(2025, 3, date.weekday(), occurrence)
+            );
+            assert_eq!(day > 24, weekday::is_last_weekday_occurrence(date));
+        }
+    }
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(
+            "3rd Tuesday of March 2025",
+            weekday::describe(date(2025, 3, 18))
+        );
+        assert_eq!(
+            "1st Saturday of March 2025",
+            weekday::describe(date(2025, 3, 1))
+        );
+        assert_eq!(
+            "5th Monday of March 2025",
+            weekday::describe(date(2025, 3, 31))
+        );
+        assert_eq!(
+            "2nd Monday",
+            Occurrence::Nth(Ordinal(2)).describe(Weekday::Mon)
+        );
+        assert_eq!("last Sunday", Occurrence::Last.describe(Weekday::Sun));
+    }
+}